@@ -0,0 +1,176 @@
+use crate::{normalized, CellLike, Life, NEIGHBOR_OFFSETS};
+
+/// Steps a [Life] by only invoking the closure on cells next to the changes of the generation before.
+///
+/// A cell whose own state and neighbors stayed the same since the last step can't change either, as long as the closure returns the same cell for the same cell and neighbors. An ActiveCells remembers which cells changed, and the next step only evaluates them and their neighbors, keeping every other cell as it is. The first step evaluates the whole board.
+///
+/// This is opt-in because it is only correct for such closures: closures depending on the position or on outside state should use [`Life::play`] instead. The Life must not be edited between steps, unless the edited cells are [`touch`][ActiveCells::touch]ed or [`reset`][ActiveCells::reset] is called.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut life = Life::<32, 32>::default();
+/// for x in 7..10 {
+///     life.set(x, 7, Cell::alive());
+/// }
+/// let mut expected = life;
+///
+/// let mut active = ActiveCells::new(32, 32);
+/// let mut calls = 0;
+///
+/// life.play_incremental(&mut active, |this, others, _, _| {
+///     calls += 1;
+///     Rule::CONWAY.apply(this, others)
+/// });
+/// assert_eq!(calls, 32 * 32);
+///
+/// // the blinker turned, only the cells around its 4 changed cells are evaluated again
+/// active.play_for(&mut life, 3, |this, others, _, _| {
+///     calls += 1;
+///     Rule::CONWAY.apply(this, others)
+/// });
+/// expected.play_rule_for(4, &Rule::CONWAY);
+///
+/// assert_eq!(calls, 32 * 32 + 3 * 21);
+/// assert_eq!(life, expected);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ActiveCells {
+    width: usize,
+    height: usize,
+    /// The indices of the cells to evaluate in the next step, row by row.
+    pending: Vec<usize>,
+    /// Whether each cell is in `pending`.
+    marked: Vec<bool>,
+}
+
+impl ActiveCells {
+    /// Creates an ActiveCells for a board of `width` × `height` cells, where the first step evaluates every cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells overflows a `usize`.
+    #[track_caller]
+    pub fn new(width: usize, height: usize) -> Self {
+        let len = width.checked_mul(height).expect("the board is too large");
+
+        Self {
+            width,
+            height,
+            pending: (0..len).collect(),
+            marked: vec![true; len],
+        }
+    }
+
+    /// Returns the number of cells the next step evaluates.
+    #[inline]
+    pub fn active(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Makes the next step evaluate the cell at `x` and `y` and its neighbors, for example after editing it.
+    ///
+    /// The boundary mode of the Life decides which cells are the neighbors of cells on the edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` or `y` is out of bounds.
+    #[track_caller]
+    pub fn touch<const HEIGHT: usize, const WIDTH: usize, C>(&mut self, life: &Life<HEIGHT, WIDTH, C>, x: usize, y: usize) {
+        assert!(x < self.width && y < self.height, "the cell is out of bounds");
+
+        self.mark(x, y);
+
+        for (dx, dy) in NEIGHBOR_OFFSETS {
+            if let (Some(x), Some(y)) = (life.boundary.resolve(x as isize + dx, WIDTH), life.boundary.resolve(y as isize + dy, HEIGHT)) {
+                self.mark(x, y);
+            }
+        }
+    }
+
+    /// Makes the next step evaluate every cell again.
+    pub fn reset(&mut self) {
+        self.pending = (0..self.width * self.height).collect();
+        self.marked.fill(true);
+    }
+
+    fn mark(&mut self, x: usize, y: usize) {
+        let i = y * self.width + x;
+
+        if !self.marked[i] {
+            self.marked[i] = true;
+            self.pending.push(i);
+        }
+    }
+
+    /// Panics if `pending` and `marked` don't hold the same cells exactly once.
+    #[cfg(feature = "strict")]
+    fn check_invariants(&self) {
+        assert_eq!(self.marked.len(), self.width * self.height, "the marks don't match the size of the ActiveCells");
+        assert_eq!(self.pending.len(), self.marked.iter().filter(|&&marked| marked).count(), "a pending cell is unmarked or pending twice");
+        assert!(self.pending.iter().all(|&i| self.marked[i]), "a pending cell is unmarked");
+    }
+
+    /// Invokes the given closure on each active cell in the Life, like [`Life::play`].
+    ///
+    /// The closure is invoked on the active cells row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Life doesn't have the size of the ActiveCells.
+    #[track_caller]
+    pub fn play<const HEIGHT: usize, const WIDTH: usize, C: CellLike + PartialEq>(&mut self, life: &mut Life<HEIGHT, WIDTH, C>, mut f: impl FnMut(C, [C; 8], usize, usize) -> C) {
+        assert!(WIDTH == self.width && HEIGHT == self.height, "the Life doesn't match the size of the ActiveCells");
+
+        #[cfg(feature = "strict")]
+        self.check_invariants();
+
+        let mut active = std::mem::take(&mut self.pending);
+        active.sort_unstable();
+
+        let mut changes = Vec::new();
+
+        for &i in &active {
+            let (x, y) = (i % WIDTH, i / WIDTH);
+            // x and y are within the board
+            let cell = normalized(life.aliveness, |this, others| f(this, others, x, y), life.cells[y][x], unsafe { life.get_surrounding(x, y) });
+
+            if cell != life.cells[y][x] {
+                changes.push((x, y, cell));
+            }
+            self.marked[i] = false;
+        }
+
+        for (x, y, cell) in changes {
+            life.cells[y][x] = cell;
+            self.touch(life, x, y);
+        }
+    }
+
+    /// Invokes [`ActiveCells::play`] `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Life doesn't have the size of the ActiveCells.
+    #[track_caller]
+    #[inline]
+    pub fn play_for<const HEIGHT: usize, const WIDTH: usize, C: CellLike + PartialEq>(&mut self, life: &mut Life<HEIGHT, WIDTH, C>, n: u32, mut f: impl FnMut(C, [C; 8], usize, usize) -> C) {
+        for _ in 0..n {
+            self.play(life, &mut f);
+        }
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike + PartialEq> Life<HEIGHT, WIDTH, C> {
+    /// Invokes the given closure on the cells next to the changes of the last step, like [`ActiveCells::play`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Life doesn't have the size of `active`.
+    #[track_caller]
+    #[inline]
+    pub fn play_incremental(&mut self, active: &mut ActiveCells, f: impl FnMut(C, [C; 8], usize, usize) -> C) {
+        active.play(self, f);
+    }
+}
@@ -0,0 +1,427 @@
+//! Measuring how boards evolve.
+//!
+//! Exploring rule space means running thousands of rules and throwing away the uninteresting ones. [`growth_rate`] runs a board for a while and fits a straight line through its population, so rules that blow up or die out can be discarded automatically.
+//!
+//! During long unattended runs, an [AnomalyDetector] watches the population and records the generations where something interesting happens, like a sudden spike or the board settling down.
+//!
+//! To judge whether a modified rule or another backend reproduces a reference run, [`similarity`] compares two boards cell by cell.
+//!
+//! # Examples
+//!
+//! ```
+//! use your_game_of_life::*;
+//! use your_game_of_life::analysis::growth_rate;
+//!
+//! let mut soup = Life::<32, 32>::default();
+//! for (x, y) in [(15, 15), (16, 15), (14, 16), (15, 16), (15, 17)] {
+//!     soup.set(x, y, Cell::alive());
+//! }
+//!
+//! // where nothing ever dies and a single neighbor is enough for a birth, the soup spreads quickly
+//! let growth = growth_rate(soup, &"B13/S012345678".parse().unwrap(), 10);
+//! assert!(growth.is_exploding(10));
+//!
+//! // the R-pentomino only grows slowly in Conway's Game of Life
+//! assert!(!growth_rate(soup, &Rule::CONWAY, 10).is_exploding(1));
+//!
+//! // under Seeds, a single living cell dies at once
+//! let mut single = Life::<8, 8>::default();
+//! single.set(4, 4, Cell::alive());
+//! assert!(growth_rate(single, &Rule::SEEDS, 10).dies_out());
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::{Aliveness, Cell, DynLife, Grid, Rect, Rule};
+
+/// The population of a board over time, as returned by [`growth_rate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Growth {
+    /// The number of living [Cells][Cell] in each generation, starting with the initial board.
+    pub populations: Vec<usize>,
+}
+
+impl Growth {
+    /// Returns the slope of the least squares fit through the populations in living [Cells][Cell] per 1000 generations, rounded to the nearest integer.
+    ///
+    /// Returns 0 if there are fewer than 2 generations.
+    pub fn slope_per_mille(&self) -> i64 {
+        let n = self.populations.len() as i128;
+
+        if n < 2 {
+            return 0;
+        }
+
+        let (mut sum_x, mut sum_y, mut sum_xx, mut sum_xy) = (0i128, 0i128, 0i128, 0i128);
+
+        for (x, &y) in self.populations.iter().enumerate() {
+            let (x, y) = (x as i128, y as i128);
+            sum_x += x;
+            sum_y += y;
+            sum_xx += x * x;
+            sum_xy += x * y;
+        }
+
+        let numerator = 1000 * (n * sum_xy - sum_x * sum_y);
+        let denominator = n * sum_xx - sum_x * sum_x;
+
+        // rounds half away from zero, the denominator is always positive
+        let rounded = (2 * numerator + numerator.signum() * denominator) / (2 * denominator);
+        rounded as i64
+    }
+
+    /// Returns the slope of the least squares fit through the populations in living [Cells][Cell] per generation.
+    ///
+    /// Returns 0 if there are fewer than 2 generations.
+    #[cfg(feature = "float")]
+    pub fn slope(&self) -> f64 {
+        let n = self.populations.len() as f64;
+
+        if n < 2.0 {
+            return 0.0;
+        }
+
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = self.populations.iter().sum::<usize>() as f64 / n;
+        let (covariance, variance) = self.populations.iter().enumerate().fold((0.0, 0.0), |(covariance, variance), (x, &y)| {
+            let dx = x as f64 - mean_x;
+            (covariance + dx * (y as f64 - mean_y), variance + dx * dx)
+        });
+
+        covariance / variance
+    }
+
+    /// Returns true if the population grows by more than `threshold` living [Cells][Cell] per generation on average.
+    #[inline]
+    pub fn is_exploding(&self, threshold: u32) -> bool {
+        self.slope_per_mille() > threshold as i64 * 1000
+    }
+
+    /// Returns true if no living [Cells][Cell] are left in the last generation.
+    #[inline]
+    pub fn dies_out(&self) -> bool {
+        self.populations.last() == Some(&0)
+    }
+
+    /// Runs an [AnomalyDetector] with the given `window` and `threshold` over the populations and returns the anomalies it found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    #[track_caller]
+    pub fn anomalies(&self, window: usize, threshold: u32) -> Vec<Anomaly> {
+        let mut detector = AnomalyDetector::new(window, threshold);
+
+        for &population in &self.populations {
+            detector.push(population);
+        }
+
+        detector.anomalies
+    }
+}
+
+/// Runs a copy of the board by `rule` for `window` generations and records its population.
+///
+/// The board can be a [Life][crate::Life] or a [DynLife]. Its [`boundary`][crate::Life#structfield.boundary] mode and [`aliveness`][crate::Life#structfield.aliveness] are kept, and the population counts the [Cells][Cell] alive according to the latter.
+pub fn growth_rate(life: impl Into<DynLife>, rule: &Rule, window: u32) -> Growth {
+    let mut life = life.into();
+    let population = |life: &DynLife| life.cells().iter().filter(|&&cell: &&Cell| life.aliveness.is_alive(cell)).count();
+
+    let mut populations = Vec::with_capacity(window as usize + 1);
+    populations.push(population(&life));
+
+    for _ in 0..window {
+        life.play_rule(rule);
+        populations.push(population(&life));
+    }
+
+    Growth { populations }
+}
+
+/// How similar two boards of the same size are, as returned by [`similarity`].
+///
+/// The [Display][fmt::Display] implementation writes a short report of all metrics.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// use your_game_of_life::analysis::similarity;
+///
+/// let a = Life::<2, 2>::from([[Cell::red(), Cell::dead()], [Cell::blue(), Cell::dead()]]);
+/// let b = Life::<2, 2>::from([[Cell::red(), Cell::red()], [Cell::dead(), Cell::dead()]]);
+/// let report = similarity(&a, &b);
+///
+/// assert_eq!(report.hamming, 2);
+/// assert_eq!(report.squared_errors, [255 * 255, 0, 255 * 255]);
+/// assert_eq!(report.iou_per_mille(), 333);
+/// assert_eq!(report.to_string(), "4 cells compared\nhamming distance: 2 (500 per mille)\nmean squared error: r 16256, g 0, b 16256\nlive cell iou: 333 per mille");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Similarity {
+    /// The number of compared [Cells][Cell].
+    pub cells: usize,
+    /// The number of [Cells][Cell] that are alive on one board and dead on the other.
+    pub hamming: usize,
+    /// The sums of the squared differences of the red, green and blue values of all [Cells][Cell].
+    pub squared_errors: [u64; 3],
+    /// The number of [Cells][Cell] that are alive on both boards.
+    pub intersection: usize,
+    /// The number of [Cells][Cell] that are alive on either board.
+    pub union: usize,
+}
+
+impl Similarity {
+    /// Returns the share of [Cells][Cell] with a different aliveness in thousandths, rounded down, or 0 if no [Cells][Cell] were compared.
+    #[inline]
+    pub const fn hamming_per_mille(&self) -> u32 {
+        match self.cells {
+            0 => 0,
+            cells => (self.hamming as u128 * 1000 / cells as u128) as u32,
+        }
+    }
+
+    /// Returns the mean squared error of the red, green and blue values, rounded down, or 0 if no [Cells][Cell] were compared.
+    #[inline]
+    pub const fn mse(&self) -> [u64; 3] {
+        let [r, g, b] = self.squared_errors;
+
+        match self.cells as u64 {
+            0 => [0; 3],
+            cells => [r / cells, g / cells, b / cells],
+        }
+    }
+
+    /// Returns the intersection over union of the living [Cells][Cell] in thousandths, rounded down.
+    ///
+    /// Two boards without any living [Cells][Cell] are identical, so this returns 1000 for them.
+    #[inline]
+    pub const fn iou_per_mille(&self) -> u32 {
+        match self.union {
+            0 => 1000,
+            union => (self.intersection as u128 * 1000 / union as u128) as u32,
+        }
+    }
+
+    /// Returns the intersection over union of the living [Cells][Cell] between 0 and 1, where two boards without any living [Cells][Cell] have 1.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn iou(&self) -> f64 {
+        match self.union {
+            0 => 1.0,
+            union => self.intersection as f64 / union as f64,
+        }
+    }
+
+    /// Returns true if both boards have exactly the same [Cells][Cell].
+    #[inline]
+    pub const fn is_identical(&self) -> bool {
+        let [r, g, b] = self.squared_errors;
+        self.hamming == 0 && r == 0 && g == 0 && b == 0
+    }
+}
+
+impl fmt::Display for Similarity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b] = self.mse();
+
+        writeln!(f, "{} cells compared", self.cells)?;
+        writeln!(f, "hamming distance: {} ({} per mille)", self.hamming, self.hamming_per_mille())?;
+        writeln!(f, "mean squared error: r {r}, g {g}, b {b}")?;
+        write!(f, "live cell iou: {} per mille", self.iou_per_mille())
+    }
+}
+
+/// Compares two boards of the same size cell by cell, with [Cells][Cell] living if [`Cell::is_alive`] returns true.
+///
+/// The boards can be any [Grid], like a [Life][crate::Life] and a [DynLife].
+///
+/// # Panics
+///
+/// Panics if the boards don't have the same size.
+#[track_caller]
+#[inline]
+pub fn similarity(a: &impl Grid, b: &impl Grid) -> Similarity {
+    similarity_by(a, b, Aliveness::NonBlack)
+}
+
+/// Compares two boards of the same size cell by cell like [`similarity`], with [Cells][Cell] living if they are alive according to `aliveness`.
+///
+/// # Panics
+///
+/// Panics if the boards don't have the same size.
+#[track_caller]
+pub fn similarity_by(a: &impl Grid, b: &impl Grid, aliveness: Aliveness) -> Similarity {
+    assert!(a.width() == b.width() && a.height() == b.height(), "the boards don't have the same size");
+
+    let mut similarity = Similarity {
+        cells: a.width() * a.height(),
+        ..Similarity::default()
+    };
+
+    for point in Rect::of_size(a.width(), a.height()).points() {
+        let (x, y) = (a.cell(point), b.cell(point));
+        let (alive_x, alive_y) = (aliveness.is_alive(x), aliveness.is_alive(y));
+
+        similarity.hamming += (alive_x != alive_y) as usize;
+        similarity.intersection += (alive_x && alive_y) as usize;
+        similarity.union += (alive_x || alive_y) as usize;
+
+        for (error, (x, y)) in similarity.squared_errors.iter_mut().zip([(x.r, y.r), (x.g, y.g), (x.b, y.b)]) {
+            *error += (x.abs_diff(y) as u64).pow(2);
+        }
+    }
+
+    similarity
+}
+
+/// What kind of [Anomaly] an [AnomalyDetector] found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnomalyKind {
+    /// The population suddenly grew far above its recent average.
+    Spike,
+    /// The population suddenly fell far below its recent average.
+    Crash,
+    /// The population became steady after changing, for example because the board turned into still lifes and oscillators.
+    Settled,
+    /// The population started changing again after being steady.
+    Unsettled,
+}
+
+/// A generation where the population behaved unusually, as found by an [AnomalyDetector].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Anomaly {
+    /// The number of the generation, starting at 0 for the first population pushed.
+    pub generation: u64,
+    /// What happened.
+    pub kind: AnomalyKind,
+    /// The population in the generation.
+    pub population: usize,
+    /// The average population over the window before the generation, rounded down.
+    pub average: usize,
+}
+
+/// Flags sudden changes in the population of a board during a long run.
+///
+/// The detector is fed the population of each generation with [`push`][AnomalyDetector::push] and compares it to the average of the `window` generations before it. A population more than `threshold` thousandths above the average is a [`Spike`][AnomalyKind::Spike], one more than `threshold` thousandths below it a [`Crash`][AnomalyKind::Crash]. The window is steady while all of its populations are within `threshold` thousandths of its average, and the detector reports when it becomes steady or stops being steady.
+///
+/// Nothing is reported until the window is full, and a run of generations far from the average reports each of them.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::analysis::{AnomalyDetector, AnomalyKind};
+///
+/// let mut detector = AnomalyDetector::new(3, 500);
+///
+/// for population in [10, 12, 11, 10, 40, 11, 10, 12, 11] {
+///     detector.push(population);
+/// }
+///
+/// let found: Vec<_> = detector.anomalies().iter().map(|anomaly| (anomaly.generation, anomaly.kind)).collect();
+///
+/// // the spike unsettles the window until it has left it again
+/// assert_eq!(found, [(4, AnomalyKind::Spike), (4, AnomalyKind::Unsettled), (7, AnomalyKind::Settled)]);
+/// assert_eq!(detector.push(0)[0].kind, AnomalyKind::Crash);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnomalyDetector {
+    window: usize,
+    threshold: u32,
+    generation: u64,
+    history: VecDeque<usize>,
+    steady: Option<bool>,
+    anomalies: Vec<Anomaly>,
+}
+
+impl AnomalyDetector {
+    /// Creates an AnomalyDetector comparing each population to the average of the `window` populations before it, with deviations of more than `threshold` thousandths of the average being anomalies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    #[track_caller]
+    pub fn new(window: usize, threshold: u32) -> Self {
+        assert!(window != 0, "the window must contain at least 1 generation");
+
+        Self {
+            window,
+            threshold,
+            generation: 0,
+            history: VecDeque::with_capacity(window),
+            steady: None,
+            anomalies: Vec::new(),
+        }
+    }
+
+    /// Returns the number of populations pushed so far.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the anomalies found so far, oldest first.
+    #[inline]
+    pub fn anomalies(&self) -> &[Anomaly] {
+        &self.anomalies
+    }
+
+    /// Returns the average of the populations in the window, rounded down.
+    fn average(&self) -> usize {
+        match self.history.len() {
+            0 => 0,
+            len => (self.history.iter().map(|&population| population as u128).sum::<u128>() / len as u128) as usize,
+        }
+    }
+
+    /// Returns true if `population` deviates from `average` by more than the threshold.
+    fn deviates(&self, population: usize, average: usize) -> bool {
+        population.abs_diff(average) as u128 * 1000 > average as u128 * self.threshold as u128
+    }
+
+    /// Records the population of the next generation and returns the anomalies found in it.
+    ///
+    /// A generation has at most two anomalies: a spike or crash, followed by the window becoming steady or unsteady.
+    pub fn push(&mut self, population: usize) -> &[Anomaly] {
+        let generation = self.generation;
+        let found = self.anomalies.len();
+        let average = self.average();
+        let full = self.history.len() == self.window;
+
+        let jump = match full && self.deviates(population, average) {
+            true if population > average => Some(AnomalyKind::Spike),
+            true => Some(AnomalyKind::Crash),
+            false => None,
+        };
+
+        if full {
+            self.history.pop_front();
+        }
+        self.history.push_back(population);
+        self.generation += 1;
+
+        let steady = match self.history.len() == self.window {
+            true => {
+                let average = self.average();
+                Some(self.history.iter().all(|&population| !self.deviates(population, average)))
+            }
+            false => None,
+        };
+        let change = match (self.steady, steady) {
+            (Some(false), Some(true)) => Some(AnomalyKind::Settled),
+            (Some(true), Some(false)) => Some(AnomalyKind::Unsettled),
+            _ => None,
+        };
+        self.steady = steady;
+
+        self.anomalies.extend(jump.into_iter().chain(change).map(|kind| Anomaly {
+            generation,
+            kind,
+            population,
+            average,
+        }));
+
+        &self.anomalies[found..]
+    }
+}
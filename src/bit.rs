@@ -0,0 +1,246 @@
+use crate::{BoundaryMode, Cell, Grid, Life, Point, Rule};
+
+/// A board of living and dead [Cells][Cell] packed into one bit per [Cell].
+///
+/// A [Life] stores 3 bytes per [Cell] and counts the neighbors of each [Cell] one by one. A BitLife stores every row as [u64] words and steps by a [Rule] with bitwise adders, counting the neighbors of 64 [Cells][Cell] at once, which is much faster for large boards where colors don't matter. Convert it from and to a [Life] to visualize it.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut life = Life::<80, 80>::default();
+/// for (x, y) in [(64, 40), (65, 40), (63, 41), (64, 41), (64, 42)] {
+///     life.set(x, y, Cell::alive());
+/// }
+/// life.boundary = BoundaryMode::Wrap;
+///
+/// let mut bits = BitLife::from(&life);
+/// bits.play_rule_for(100, &Rule::CONWAY);
+/// life.play_rule_for(100, &Rule::CONWAY);
+///
+/// assert_eq!(Life::from(&bits), life);
+/// assert_eq!(bits.population(), life.iter().filter(|cell| cell.is_alive()).count());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitLife<const HEIGHT: usize, const WIDTH: usize> {
+    /// The rows, each as [`ROW_WORDS`][BitLife::ROW_WORDS] words where the bit `x % 64` of the word `x / 64` is the [Cell] in column `x`. Bits beyond the last column are always 0.
+    words: Vec<u64>,
+    /// Decides which [Cells][Cell] count as the neighbors beyond the edges. The color of a [`Constant`][BoundaryMode::Constant] [Cell] only matters through [`Cell::is_alive`].
+    pub boundary: BoundaryMode,
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Default for BitLife<HEIGHT, WIDTH> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> BitLife<HEIGHT, WIDTH> {
+    /// The number of [u64] words per row.
+    const ROW_WORDS: usize = WIDTH.div_ceil(u64::BITS as usize);
+
+    /// The bits of the last word of a row that are columns of the board.
+    const LAST_MASK: u64 = match WIDTH % u64::BITS as usize {
+        0 => !0,
+        bits => (1 << bits) - 1,
+    };
+
+    /// Creates a BitLife of dead [Cells][Cell].
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            words: vec![0; HEIGHT * Self::ROW_WORDS],
+            boundary: BoundaryMode::default(),
+        }
+    }
+
+    #[track_caller]
+    #[inline]
+    fn position(x: usize, y: usize) -> (usize, u64) {
+        assert!(x < WIDTH && y < HEIGHT, "index ({x}, {y}) out of bounds for a board of {WIDTH}x{HEIGHT} cells");
+        (y * Self::ROW_WORDS + x / u64::BITS as usize, 1 << (x % u64::BITS as usize))
+    }
+
+    /// Returns true if the [Cell] at the given index is living.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        let (word, bit) = Self::position(x, y);
+        self.words[word] & bit != 0
+    }
+
+    /// Makes the [Cell] at the given index living or dead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, alive: bool) {
+        let (word, bit) = Self::position(x, y);
+
+        match alive {
+            true => self.words[word] |= bit,
+            false => self.words[word] &= !bit,
+        }
+    }
+
+    /// Returns the number of living [Cells][Cell].
+    #[inline]
+    pub fn population(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns the words of `row` shifted by one column, so each bit holds the [Cell] to the left (west) or to the right (east) of it.
+    fn shifted(&self, row: &[u64], outside: bool) -> (Vec<u64>, Vec<u64>) {
+        let last = Self::ROW_WORDS - 1;
+        let top = (WIDTH - 1) % u64::BITS as usize;
+        let bit = |x: usize| row[x / u64::BITS as usize] >> (x % u64::BITS as usize) & 1 != 0;
+
+        let (left, right) = match self.boundary {
+            BoundaryMode::Constant(_) => (outside, outside),
+            BoundaryMode::Wrap => (bit(WIDTH - 1), bit(0)),
+            BoundaryMode::Mirror => (bit(0), bit(WIDTH - 1)),
+        };
+
+        let mut west: Vec<_> = (0..=last).map(|i| row[i] << 1 | if i == 0 { left as u64 } else { row[i - 1] >> 63 }).collect();
+        let mut east: Vec<_> = (0..=last).map(|i| row[i] >> 1 | if i == last { 0 } else { row[i + 1] << 63 }).collect();
+
+        west[last] &= Self::LAST_MASK;
+        east[last] = east[last] & !(1 << top) | (right as u64) << top;
+
+        (west, east)
+    }
+
+    /// Panics if the words don't match the size of the board or a bit beyond the last column is set.
+    #[cfg(feature = "strict")]
+    fn check_invariants(&self) {
+        assert_eq!(self.words.len(), HEIGHT * Self::ROW_WORDS, "the words don't match the size of the BitLife");
+
+        for row in self.words.chunks_exact(Self::ROW_WORDS) {
+            assert_eq!(row[Self::ROW_WORDS - 1] & !Self::LAST_MASK, 0, "a bit beyond the last column is set");
+        }
+    }
+
+    /// Steps the BitLife by `rule`, giving the same result as [`Life::play_rule`] for the [Life] it converts to.
+    pub fn play_rule(&mut self, rule: &Rule) {
+        if WIDTH == 0 || HEIGHT == 0 {
+            return;
+        }
+
+        #[cfg(feature = "strict")]
+        self.check_invariants();
+
+        let words = Self::ROW_WORDS;
+        let outside = match self.boundary {
+            BoundaryMode::Constant(cell) => cell.is_alive(),
+            _ => false,
+        };
+
+        // each row and the row of outside cells, as the row itself and shifted west and east
+        let mut outside_row = vec![if outside { !0 } else { 0 }; words];
+        outside_row[words - 1] &= Self::LAST_MASK;
+
+        let rows: Vec<_> = self.words.chunks_exact(words).chain([outside_row.as_slice()]).map(|row| {
+            let (west, east) = self.shifted(row, outside);
+            (row.to_vec(), west, east)
+        }).collect();
+
+        let row = |y: isize| &rows[self.boundary.resolve(y, HEIGHT).unwrap_or(HEIGHT)];
+        let born: Vec<u8> = (0..=8).filter(|&neighbors| rule.is_born(neighbors)).collect();
+        let survive: Vec<u8> = (0..=8).filter(|&neighbors| rule.survives(neighbors)).collect();
+
+        for (y, this) in rows[..HEIGHT].iter().enumerate() {
+            let (above, below) = (row(y as isize - 1), row(y as isize + 1));
+
+            for i in 0..words {
+                // a 4 bit counter per cell, one word per bit
+                let mut count = [0u64; 4];
+
+                for neighbors in [above.1[i], above.0[i], above.2[i], this.1[i], this.2[i], below.1[i], below.0[i], below.2[i]] {
+                    let mut carry = neighbors;
+
+                    for bit in &mut count {
+                        let next = *bit & carry;
+                        *bit ^= carry;
+                        carry = next;
+                    }
+                }
+
+                let equals = |n: &u8| (0..4).fold(!0, |mask, bit| mask & if n >> bit & 1 == 1 { count[bit] } else { !count[bit] });
+                let born = born.iter().fold(0, |mask, n| mask | equals(n));
+                let survive = survive.iter().fold(0, |mask, n| mask | equals(n));
+
+                let alive = this.0[i];
+                let mask = if i == words - 1 { Self::LAST_MASK } else { !0 };
+
+                self.words[y * words + i] = (alive & survive | !alive & born) & mask;
+            }
+        }
+    }
+
+    /// Steps the BitLife `n` times by `rule`.
+    #[inline]
+    pub fn play_rule_for(&mut self, n: u32, rule: &Rule) {
+        for _ in 0..n {
+            self.play_rule(rule);
+        }
+    }
+}
+
+/// Living [Cells][Cell] are [`Cell::alive`] and dead ones [`Cell::dead`].
+impl<const HEIGHT: usize, const WIDTH: usize> Grid for BitLife<HEIGHT, WIDTH> {
+    #[inline]
+    fn width(&self) -> usize {
+        WIDTH
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        HEIGHT
+    }
+
+    #[inline]
+    fn cell(&self, point: Point) -> Cell {
+        Cell::from(self.get(point.x, point.y))
+    }
+}
+
+/// Converts a [Life] into a BitLife, keeping its [`boundary`][Life#structfield.boundary] mode. [Cells][Cell] are living if they are alive according to its [`aliveness`][Life#structfield.aliveness].
+impl<const HEIGHT: usize, const WIDTH: usize> From<&Life<HEIGHT, WIDTH>> for BitLife<HEIGHT, WIDTH> {
+    fn from(life: &Life<HEIGHT, WIDTH>) -> Self {
+        let mut bits = Self {
+            boundary: life.boundary.by_aliveness(life.aliveness),
+            ..Self::new()
+        };
+
+        for (x, y, &cell) in life.enumerate_cells() {
+            if life.aliveness.is_alive(cell) {
+                bits.set(x, y, true);
+            }
+        }
+
+        bits
+    }
+}
+
+/// Converts a BitLife into a [Life] of living [`Cell::alive`] and dead [`Cell::dead`] [Cells][Cell], keeping its [`boundary`][BitLife#structfield.boundary] mode.
+impl<const HEIGHT: usize, const WIDTH: usize> From<&BitLife<HEIGHT, WIDTH>> for Life<HEIGHT, WIDTH> {
+    fn from(bits: &BitLife<HEIGHT, WIDTH>) -> Self {
+        let mut life = Life {
+            boundary: bits.boundary,
+            ..Life::default()
+        };
+
+        for (x, y, cell) in life.enumerate_cells_mut() {
+            *cell = Cell::from(bits.get(x, y));
+        }
+
+        life
+    }
+}
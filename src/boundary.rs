@@ -0,0 +1,130 @@
+use crate::{Aliveness, Cell};
+
+/// What lies beyond the edges of a [`Life`][super::Life].
+///
+/// Whenever a neighbor of a [Cell] would be out of bounds, the BoundaryMode decides which [Cell] is used instead.
+///
+/// # Examples
+///
+/// A blinker across the left and right edges keeps blinking on a torus:
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut life = Life::<3, 4>::from([
+///     [false, false, false, false],
+///     [true, true, false, true],
+///     [false, false, false, false],
+/// ]);
+/// life.boundary = BoundaryMode::Wrap;
+///
+/// life.play(|this, others, _, _| match others.alive() {
+///     2 => this,
+///     3 => Cell::alive(),
+///     _ => Cell::dead(),
+/// });
+///
+/// assert_eq!(life.cells, Life::<3, 4>::from([
+///     [true, false, false, false],
+///     [true, false, false, false],
+///     [true, false, false, false],
+/// ]).cells);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BoundaryMode {
+    /// Every [Cell] beyond the edges is the given [Cell].
+    Constant(Cell),
+    /// The edges wrap around, so the board is a torus: the neighbor to the left of the leftmost column is in the rightmost column, and the neighbor above the top row is in the bottom row.
+    Wrap,
+    /// The edges reflect the board, so the neighbor to the left of the leftmost column is the [Cell] itself, as if the board was mirrored at each edge.
+    Mirror,
+}
+
+impl Default for BoundaryMode {
+    /// Returns `BoundaryMode::Constant(Cell::default())`.
+    #[inline]
+    fn default() -> Self {
+        Self::Constant(Cell::default())
+    }
+}
+
+impl BoundaryMode {
+    /// Maps a possibly out of bounds `position` on an axis `len` [Cells][Cell] long to the position of the [Cell] it refers to.
+    ///
+    /// Returns `None` if the position refers to the [`Constant`][BoundaryMode::Constant] [Cell], or if `len` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// assert_eq!(BoundaryMode::Wrap.resolve(-1, 5), Some(4));
+    /// assert_eq!(BoundaryMode::Mirror.resolve(-1, 5), Some(0));
+    /// assert_eq!(BoundaryMode::Mirror.resolve(6, 5), Some(3));
+    /// assert_eq!(BoundaryMode::default().resolve(-1, 5), None);
+    /// assert_eq!(BoundaryMode::default().resolve(2, 5), Some(2));
+    /// ```
+    #[inline]
+    pub const fn resolve(self, position: isize, len: usize) -> Option<usize> {
+        if len == 0 {
+            return None;
+        }
+        if position >= 0 && (position as usize) < len {
+            return Some(position as usize);
+        }
+
+        match self {
+            Self::Constant(_) => None,
+            Self::Wrap => Some(position.rem_euclid(len as isize) as usize),
+            Self::Mirror => {
+                let period = 2 * len as isize;
+
+                match position.rem_euclid(period) as usize {
+                    position if position < len => Some(position),
+                    position => Some(period as usize - 1 - position),
+                }
+            }
+        }
+    }
+
+    /// Returns the [`Constant`][BoundaryMode::Constant] [Cell], or [`Cell::default`] for the other modes.
+    ///
+    /// This is the [Cell] used where nothing on the board can be referred to, like when padding a tile that reaches past the board.
+    #[inline]
+    pub const fn outside(self) -> Cell {
+        match self {
+            Self::Constant(cell) => cell,
+            Self::Wrap | Self::Mirror => Cell::black(),
+        }
+    }
+
+    /// Returns the mode for a board of living [`Cell::alive`] and dead [`Cell::dead`] [Cells][Cell] converted from a board with the given [Aliveness], keeping whether a [`Constant`][BoundaryMode::Constant] [Cell] is alive.
+    pub(crate) const fn by_aliveness(self, aliveness: Aliveness) -> Self {
+        match self {
+            Self::Constant(cell) => Self::Constant(match aliveness.is_alive(cell) {
+                true => Cell::alive(),
+                false => Cell::dead(),
+            }),
+            boundary => boundary,
+        }
+    }
+
+    /// Encodes the mode as a tag byte and the [`outside`][BoundaryMode::outside] [Cell], as used by the binary formats of this crate.
+    pub(crate) const fn to_bytes(self) -> [u8; 4] {
+        let (tag, cell) = match self {
+            Self::Constant(cell) => (0, cell),
+            Self::Wrap => (1, Cell::black()),
+            Self::Mirror => (2, Cell::black()),
+        };
+
+        [tag, cell.r, cell.g, cell.b]
+    }
+
+    /// Decodes a mode written by [`to_bytes`][BoundaryMode::to_bytes], returning the unknown tag byte on failure.
+    pub(crate) const fn from_bytes([tag, r, g, b]: [u8; 4]) -> Result<Self, u8> {
+        match tag {
+            0 => Ok(Self::Constant(Cell { r, g, b })),
+            1 => Ok(Self::Wrap),
+            2 => Ok(Self::Mirror),
+            tag => Err(tag),
+        }
+    }
+}
@@ -3,7 +3,10 @@
 /// The Cell type has three fields, representing an RGB structure. There is no added functionality with this structure on its own.
 /// 
 /// You can also treat a Cell as either alive or dead, if you want. In that case, use the respective methods.
+/// 
+/// A Cell is laid out as three bytes in RGB order, so a slice of Cells can be used as packed RGB pixels.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
 pub struct Cell {
     /// Red
     pub r: u8,
@@ -40,6 +43,31 @@ impl Cell {
         self.r != 0 || self.g != 0 || self.b != 0
     }
 
+    /// Mixes this Cell with `other` channel by channel, where a `weight` of 0 returns this Cell and a `weight` of 255 returns `other`.
+    /// 
+    /// This only uses integer arithmetic.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # use your_game_of_life::Cell;
+    /// assert_eq!(Cell::black().blend(Cell::white(), 0), Cell::black());
+    /// assert_eq!(Cell::black().blend(Cell::white(), 255), Cell::white());
+    /// assert_eq!(Cell::red().blend(Cell::blue(), 51), Cell { r: 204, g: 0, b: 51 });
+    /// ```
+    #[inline]
+    pub const fn blend(self, other: Self, weight: u8) -> Self {
+        const fn mix(a: u8, b: u8, weight: u8) -> u8 {
+            ((a as u16 * (255 - weight as u16) + b as u16 * weight as u16 + 127) / 255) as u8
+        }
+
+        Self {
+            r: mix(self.r, other.r, weight),
+            g: mix(self.g, other.g, weight),
+            b: mix(self.b, other.b, weight),
+        }
+    }
+
     /// Creates a Cell whose RGB values are all set to `rgb`.
     #[inline]
     pub const fn all(rgb: u8) -> Self {
@@ -105,6 +133,21 @@ impl Cell {
     }
 }
 
+impl std::fmt::Display for Cell {
+    /// Writes the Cell as a hexadecimal color code, like `#ff0000`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// assert_eq!(Cell::red().to_string(), "#ff0000");
+    /// assert_eq!(Cell { r: 0, g: 0, b: 128 }.to_string(), "#000080");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
 impl From<[u8; 3]> for Cell {
     fn from([r, g, b]: [u8; 3]) -> Self {
         Self {
@@ -146,9 +189,228 @@ impl From<bool> for Cell {
     }
 }
 
+/// Which [Cells][Cell] of a board count as alive, and which color dead [Cells][Cell] have.
+///
+/// By default, only black [Cells][Cell] are dead. A board drawn on a styled background can use the background color for dead [Cells][Cell] instead, without them counting as alive. The [`aliveness`][super::Life#structfield.aliveness] of a board is respected by [`Life::play`][super::Life::play], which passes the [Cells][Cell] to its closure [normalized][Aliveness::normalize], so [`Cell::is_alive`], [`CellNeighbors::alive`] and the [Rules][super::Rule] work on any board. Outside of closures, it can be passed to [`CellNeighbors::alive_by`].
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let navy = Cell { r: 0, g: 0, b: 64 };
+/// let aliveness = Aliveness::Background(navy);
+///
+/// assert!(!aliveness.is_alive(navy));
+/// assert!(aliveness.is_alive(Cell::black()));
+/// assert_eq!(aliveness.dead(), navy);
+/// assert!(!Aliveness::Brightness(64).is_alive(navy));
+/// assert!(Aliveness::NonBlack.is_alive(navy));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Aliveness {
+    /// [Cells][Cell] are alive unless all of their RGB values are 0, like [`Cell::is_alive`]. Dead [Cells][Cell] are black.
+    #[default]
+    NonBlack,
+    /// [Cells][Cell] of exactly the given color are dead, every other [Cell] is alive.
+    Background(Cell),
+    /// [Cells][Cell] are alive if any of their RGB values is greater than the given value. Dead [Cells][Cell] are black.
+    Brightness(u8),
+}
+
+impl Aliveness {
+    /// Returns true if `cell` counts as alive.
+    #[inline]
+    pub const fn is_alive(self, cell: Cell) -> bool {
+        match self {
+            Self::NonBlack => cell.is_alive(),
+            Self::Background(background) => cell.r != background.r || cell.g != background.g || cell.b != background.b,
+            Self::Brightness(threshold) => cell.r > threshold || cell.g > threshold || cell.b > threshold,
+        }
+    }
+
+    /// Returns the color of dead [Cells][Cell].
+    #[inline]
+    pub const fn dead(self) -> Cell {
+        match self {
+            Self::Background(background) => background,
+            Self::NonBlack | Self::Brightness(_) => Cell::dead(),
+        }
+    }
+
+    /// Returns the [Cell] as it's seen on a board of [`NonBlack`][Aliveness::NonBlack] [Cells][Cell], where exactly the black [Cells][Cell] are dead.
+    ///
+    /// For [`Background`][Aliveness::Background], the background is swapped with black, so normalizing a [Cell] twice gives it back. For [`Brightness`][Aliveness::Brightness], dead [Cells][Cell] become black. [`Life::play`][super::Life::play] normalizes the [Cells][Cell] it passes to its closure and the [Cell] the closure returns, so a closure returning [`Cell::dead`] leaves the background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let navy = Cell { r: 0, g: 0, b: 64 };
+    /// let aliveness = Aliveness::Background(navy);
+    ///
+    /// assert_eq!(aliveness.normalize(navy), Cell::dead());
+    /// assert_eq!(aliveness.normalize(Cell::dead()), navy);
+    /// assert_eq!(aliveness.normalize(Cell::red()), Cell::red());
+    /// assert_eq!(Aliveness::Brightness(64).normalize(navy), Cell::dead());
+    /// ```
+    #[inline]
+    pub const fn normalize(self, cell: Cell) -> Cell {
+        match self {
+            Self::NonBlack => cell,
+            Self::Background(_) | Self::Brightness(_) if !self.is_alive(cell) => Cell::dead(),
+            // a living black cell takes the place of the background
+            Self::Background(background) if !cell.is_alive() => background,
+            Self::Background(_) | Self::Brightness(_) => cell,
+        }
+    }
+
+    /// Encodes the policy as a tag byte and its parameters, as used by the binary formats of this crate.
+    pub(crate) const fn to_bytes(self) -> [u8; 4] {
+        match self {
+            Self::NonBlack => [0; 4],
+            Self::Background(cell) => [1, cell.r, cell.g, cell.b],
+            Self::Brightness(threshold) => [2, threshold, 0, 0],
+        }
+    }
+
+    /// Decodes a policy written by [`to_bytes`][Aliveness::to_bytes], returning the unknown tag byte on failure.
+    pub(crate) const fn from_bytes([tag, r, g, b]: [u8; 4]) -> Result<Self, u8> {
+        match tag {
+            0 => Ok(Self::NonBlack),
+            1 => Ok(Self::Background(Cell { r, g, b })),
+            2 => Ok(Self::Brightness(r)),
+            tag => Err(tag),
+        }
+    }
+}
+
+/// A type that can live in a [`Life`][super::Life] instead of the RGB [Cell].
+///
+/// Cells can carry anything that is cheap to copy, like a plain `bool` for speed or an age counter, as long as they can tell whether they are alive for counting neighbors. A Life uses [Cell] unless another CellLike type is given, so `Life<3, 3>` is `Life<3, 3, Cell>`.
+///
+/// The [`From<Cell>`] conversion creates the cell used beyond the edges for a [`Constant`][super::BoundaryMode::Constant] [BoundaryMode][super::BoundaryMode], and the [`Default`] value is what a new Life is filled with.
+///
+/// Exporters and analysis tools, like [`Life::to_snapshot`][super::Life::to_snapshot], [`Life::stats`][super::Life::stats] or the [`Grid`][super::Grid] implementation of a Life, see every cell as the [Cell] returned by [`to_rgb`][CellLike::to_rgb], and readers like [`Life::from_snapshot`][super::Life::from_snapshot] create cells with [`from_rgb`][CellLike::from_rgb], so they work on any CellLike type without converting the board first.
+///
+/// # Examples
+///
+/// A Life of ages, where each living cell counts the generations it survived:
+///
+/// ```
+/// # use your_game_of_life::*;
+/// #[derive(Debug, Clone, Copy, Default, PartialEq)]
+/// struct Age(u8);
+///
+/// impl From<Cell> for Age {
+///     fn from(cell: Cell) -> Self {
+///         Age(cell.is_alive() as u8)
+///     }
+/// }
+///
+/// impl CellLike for Age {
+///     fn is_alive(self) -> bool {
+///         self.0 != 0
+///     }
+///
+///     // older cells are drawn brighter
+///     fn to_rgb(self) -> Cell {
+///         Cell::all(self.0.saturating_mul(50))
+///     }
+/// }
+///
+/// let mut life = Life::<3, 3, Age>::from([[false; 3], [true; 3], [false; 3]].map(|row| row.map(Cell::from)));
+///
+/// life.play(|this, others, _, _| match (this.is_alive(), others.alive()) {
+///     (true, 2 | 3) => Age(this.0.saturating_add(1)),
+///     (false, 3) => Age(1),
+///     _ => Age(0),
+/// });
+///
+/// assert_eq!(life.get(1, 1), Age(2));
+/// assert_eq!(life.get(1, 0), Age(1));
+/// assert_eq!(life.stats().colors[&Cell::all(100)], 1);
+/// ```
+pub trait CellLike: Copy + Default + From<Cell> {
+    /// Returns true if the cell counts as alive, like for [`CellNeighbors::alive`].
+    fn is_alive(self) -> bool;
+
+    /// Returns true if the cell counts as alive on a board with the given [Aliveness], like for [`CellNeighbors::alive_by`].
+    ///
+    /// Since [Aliveness] is about colors, this returns [`is_alive`][CellLike::is_alive] by default.
+    #[inline]
+    fn is_alive_by(self, aliveness: Aliveness) -> bool {
+        let _ = aliveness;
+        self.is_alive()
+    }
+
+    /// Returns the cell as it's seen on a board of [`Aliveness::NonBlack`], like [`Aliveness::normalize`], for passing it to the closure of [`Life::play`][super::Life::play].
+    ///
+    /// Since [Aliveness] is about colors, this returns the cell itself by default.
+    #[inline]
+    fn normalize_by(self, aliveness: Aliveness) -> Self {
+        let _ = aliveness;
+        self
+    }
+
+    /// Returns the [Cell] that stands for the cell when it's exported, drawn or analyzed.
+    ///
+    /// By default, living cells are [`Cell::alive`] and dead ones [`Cell::dead`].
+    #[inline]
+    fn to_rgb(self) -> Cell {
+        Cell::from(self.is_alive())
+    }
+
+    /// Creates the cell that a [Cell] read from a file or another board stands for.
+    ///
+    /// This uses the [`From<Cell>`] conversion by default.
+    #[inline]
+    fn from_rgb(cell: Cell) -> Self {
+        Self::from(cell)
+    }
+}
+
+impl CellLike for Cell {
+    /// Returns false if all of the RGB values are 0, like [`Cell::is_alive`].
+    #[inline]
+    fn is_alive(self) -> bool {
+        Cell::is_alive(self)
+    }
+
+    #[inline]
+    fn is_alive_by(self, aliveness: Aliveness) -> bool {
+        aliveness.is_alive(self)
+    }
+
+    #[inline]
+    fn normalize_by(self, aliveness: Aliveness) -> Self {
+        aliveness.normalize(self)
+    }
+
+    /// Returns the Cell itself.
+    #[inline]
+    fn to_rgb(self) -> Cell {
+        self
+    }
+}
+
+impl From<Cell> for bool {
+    /// Returns whether the [Cell] [is alive][Cell::is_alive].
+    #[inline]
+    fn from(cell: Cell) -> Self {
+        cell.is_alive()
+    }
+}
+
+impl CellLike for bool {
+    #[inline]
+    fn is_alive(self) -> bool {
+        self
+    }
+}
+
 macro_rules! impl_cell_neighbors {
     ($($m:ident $i:literal)*) => {
-        /// Convenience trait for `[Cell; 8]`.
+        /// Convenience trait for `[Cell; 8]`, or the neighbors of any other [CellLike] type.
         /// 
         /// This trait makes indexing a `[Cell; 8]` in specific directions more readable. Also adds the [`alive`][CellNeighbors::alive] method.
         /// 
@@ -164,29 +426,42 @@ macro_rules! impl_cell_neighbors {
         /// let bl = cells.bottom_left();
         /// ```
         pub trait CellNeighbors {
+            /// The type of the neighboring cells.
+            type Cell;
+
             /// Returns the number of neighboring alive [Cells][Cell].
             /// 
-            /// Whether a [Cell] is alive is determined by the [`Cell::alive`] method.
+            /// Whether a [Cell] is alive is determined by the [`CellLike::is_alive`] method. In the closure of [`Life::play`][super::Life::play], the neighbors are [normalized][Aliveness::normalize], so this follows the [`aliveness`][super::Life#structfield.aliveness] of the board.
             fn alive(self) -> u8;
 
+            /// Returns the number of neighboring [Cells][Cell] that are alive according to `aliveness`.
+            fn alive_by(self, aliveness: Aliveness) -> u8;
+
             $(
                 /// Returns the [Cell] in the corresponding direction.
-                fn $m(self) -> Cell;
+                fn $m(self) -> Self::Cell;
             )*
         }
 
-        impl CellNeighbors for [Cell; 8] {
+        impl<C: CellLike> CellNeighbors for [C; 8] {
+            type Cell = C;
+
             fn alive(self) -> u8 {
                 self.into_iter().filter(|cell| cell.is_alive()).count() as u8
             }
 
+            fn alive_by(self, aliveness: Aliveness) -> u8 {
+                self.into_iter().filter(|&cell| cell.is_alive_by(aliveness)).count() as u8
+            }
+
             $(
                 #[doc = "Indexes the array with `["]
                 #[doc = stringify!($i)]
                 #[doc = "]`."]
-                fn $m(self) -> Cell {
+                fn $m(self) -> C {
+                    // the index is always below 8
                     unsafe {
-                        *self.get_unchecked($i)
+                        *super::unchecked(&self, $i)
                     }
                 }
             )*
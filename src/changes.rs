@@ -0,0 +1,145 @@
+use crate::{CellLike, Life};
+
+/// Remembers in which generation each cell of a board last changed.
+///
+/// Renderers can use this for cooling or heat effects, like fading out [Cells][crate::Cell] that died a while ago, without comparing every generation with the one before it themselves. A ChangeTracker steps a [Life] like [`Life::play`] and updates the generations of the cells that changed.
+///
+/// Generations are counted from 1 for the first step played through the ChangeTracker, and 0 means a cell hasn't changed since tracking began.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut life = Life::<3, 3>::from([[false; 3], [true; 3], [false; 3]]);
+/// let mut tracker = ChangeTracker::new(3, 3);
+///
+/// tracker.play_for(&mut life, 2, |this, others, _, _| Rule::CONWAY.apply(this, others));
+///
+/// // the center of the blinker never changes and the ends change every generation
+/// assert_eq!(tracker.generation(), 2);
+/// assert_eq!(tracker.last_changed(1, 1), 0);
+/// assert_eq!(tracker.last_changed(0, 1), 2);
+/// assert_eq!(tracker.age(1, 0), 0);
+/// assert_eq!(tracker.last_changed(0, 0), 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ChangeTracker {
+    width: usize,
+    height: usize,
+    generation: u32,
+    last_changed: Vec<u32>,
+}
+
+impl ChangeTracker {
+    /// Creates a ChangeTracker for a board of `width` × `height` cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells overflows a `usize`.
+    #[track_caller]
+    pub fn new(width: usize, height: usize) -> Self {
+        let len = width.checked_mul(height).expect("the number of cells must fit in a usize");
+
+        Self {
+            width,
+            height,
+            generation: 0,
+            last_changed: vec![0; len],
+        }
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of the last generation that was recorded.
+    #[inline]
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Returns the generation in which the cell at the given index last changed, or 0 if it hasn't changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn last_changed(&self, x: usize, y: usize) -> u32 {
+        assert!(x < self.width && y < self.height, "index ({x}, {y}) out of bounds for a board of {}x{} cells", self.width, self.height);
+        self.last_changed[y * self.width + x]
+    }
+
+    /// Returns the number of generations since the cell at the given index last changed, or since tracking began if it hasn't changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn age(&self, x: usize, y: usize) -> u32 {
+        self.generation - self.last_changed(x, y)
+    }
+
+    /// Returns the generations in which the cells last changed, row by row.
+    #[inline]
+    pub fn as_slice(&self) -> &[u32] {
+        &self.last_changed
+    }
+
+    /// Starts a new generation and records every cell that differs between `before` and `after`, both row by row.
+    ///
+    /// This is what [`play`][ChangeTracker::play] uses, for boards that are stepped some other way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `before` or `after` doesn't have exactly one cell for each cell of the ChangeTracker.
+    #[track_caller]
+    pub fn record<C: PartialEq>(&mut self, before: &[C], after: &[C]) {
+        assert!(before.len() == self.last_changed.len() && after.len() == self.last_changed.len(), "the boards must have {}x{} cells", self.width, self.height);
+
+        self.generation += 1;
+
+        for ((last_changed, before), after) in self.last_changed.iter_mut().zip(before).zip(after) {
+            if before != after {
+                *last_changed = self.generation;
+            }
+        }
+    }
+
+    /// Invokes [`Life::play`] with the given closure and records the cells that changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Life doesn't have the size of the ChangeTracker, before stepping it.
+    #[track_caller]
+    pub fn play<const HEIGHT: usize, const WIDTH: usize, C: CellLike + PartialEq>(&mut self, life: &mut Life<HEIGHT, WIDTH, C>, f: impl FnMut(C, [C; 8], usize, usize) -> C) {
+        assert!(WIDTH == self.width && HEIGHT == self.height, "the boards must have {}x{} cells", self.width, self.height);
+
+        let before = life.cells;
+        life.play(f);
+
+        self.record(before.as_flattened(), life.cells.as_flattened());
+    }
+
+    /// Invokes [`ChangeTracker::play`] `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Life doesn't have the size of the ChangeTracker.
+    #[track_caller]
+    #[inline]
+    pub fn play_for<const HEIGHT: usize, const WIDTH: usize, C: CellLike + PartialEq>(&mut self, life: &mut Life<HEIGHT, WIDTH, C>, n: u32, mut f: impl FnMut(C, [C; 8], usize, usize) -> C) {
+        for _ in 0..n {
+            self.play(life, &mut f);
+        }
+    }
+}
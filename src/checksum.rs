@@ -0,0 +1,52 @@
+use crate::{CellLike, Life};
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = match crc & 1 {
+                1 => 0xEDB8_8320 ^ (crc >> 1),
+                _ => crc >> 1,
+            };
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+};
+
+/// Returns the CRC-32 (IEEE 802.3) checksum of `bytes`.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+/// Returns the 64-bit FNV-1a hash of `bytes`.
+pub(crate) fn fnv1a64(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes.into_iter().fold(0xCBF2_9CE4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3))
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Life<HEIGHT, WIDTH, C> {
+    /// Returns a hash of the size and the [Cells][crate::Cell] of the Life that is the same on every platform and in every version of this crate.
+    ///
+    /// The hash is the 64-bit FNV-1a hash of the width and height as little-endian [u32]s followed by the RGB bytes of the [Cells][crate::Cell] row by row, as returned by [`CellLike::to_rgb`], so it can be reproduced without this crate. The [`boundary`][Life#structfield.boundary] mode and [`aliveness`][Life#structfield.aliveness] aren't part of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// assert_eq!(Life::<1, 1>::default().stable_hash(), 0x5D1E_E66E_3442_9D67);
+    /// assert_ne!(Life::<1, 1>::from([[true]]).stable_hash(), Life::<1, 1>::default().stable_hash());
+    /// ```
+    pub fn stable_hash(&self) -> u64 {
+        let size = (WIDTH as u32).to_le_bytes().into_iter().chain((HEIGHT as u32).to_le_bytes());
+        fnv1a64(size.chain(self.iter().map(|&cell| cell.to_rgb()).flat_map(|cell| [cell.r, cell.g, cell.b])))
+    }
+}
@@ -0,0 +1,471 @@
+//! Storing objects found by searches.
+//!
+//! A [PatternDb] keeps one [Entry] per object with [Metadata] about it, like the rule it lives in, its period and how fast it moves. Objects are stored in their [canonical][Object::canonical] form, so inserting the same object in another orientation or position counts another occurrence instead of adding a new entry. The database is kept in memory and saved to a file with [`PatternDb::save`], which is written to a temporary file first so a crash never leaves a half-written database behind.
+//!
+//! # Examples
+//!
+//! ```
+//! use your_game_of_life::*;
+//! use your_game_of_life::db::*;
+//!
+//! let mut db = PatternDb::new();
+//! let blinker = Object::Small(SmallPattern::from_rows(&["OOO"]).unwrap());
+//! let glider = Object::Small(SmallPattern::from_rows(&[".O.", "..O", "OOO"]).unwrap());
+//!
+//! db.insert(&blinker, Metadata::new("B3/S23", 2));
+//! db.insert(&glider, Metadata { dx: 1, dy: 1, ..Metadata::new("B3/S23", 4) });
+//!
+//! // the same blinker, standing upright
+//! let upright = Object::Small(SmallPattern::from_rows(&["O", "O", "O"]).unwrap());
+//! assert!(!db.insert(&upright, Metadata::new("B3/S23", 2)));
+//! assert_eq!(db.get(&upright).unwrap().occurrences, 2);
+//!
+//! let restored = PatternDb::from_bytes(&db.to_bytes()).unwrap();
+//! assert_eq!(restored.moving().count(), 1);
+//! assert_eq!(restored.with_period(2).count(), 1);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use crate::checksum::crc32;
+use crate::{Cell, DynLife, Object, SmallPattern};
+
+const MAGIC: [u8; 4] = *b"YGLD";
+
+/// The version of the database format written by this version of the crate.
+pub const VERSION: u8 = 1;
+
+/// The oldest version of the database format that can still be read.
+pub const MIN_VERSION: u8 = 1;
+
+/// What is known about an object.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Metadata {
+    /// The rule the object lives in, like `B3/S23`.
+    pub rule: String,
+    /// The number of generations after which the object repeats, 1 for still lifes.
+    pub period: u32,
+    /// The number of columns the object moves to the right per period.
+    pub dx: i32,
+    /// The number of rows the object moves down per period.
+    pub dy: i32,
+    /// Who or what found the object first.
+    pub discoverer: Option<String>,
+    /// When the object was found first, in seconds since the Unix epoch.
+    pub discovered: Option<u64>,
+}
+
+impl Metadata {
+    /// Creates Metadata for an object that doesn't move and has no discovery information.
+    pub fn new(rule: impl Into<String>, period: u32) -> Self {
+        Self {
+            rule: rule.into(),
+            period,
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if the object moves.
+    #[inline]
+    pub const fn is_moving(&self) -> bool {
+        self.dx != 0 || self.dy != 0
+    }
+}
+
+/// One object in a [PatternDb].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Entry {
+    /// The object in its [canonical][Object::canonical] form.
+    pub object: Object,
+    /// What is known about the object, as given when it was first inserted.
+    pub metadata: Metadata,
+    /// How often the object was inserted.
+    pub occurrences: u64,
+}
+
+/// The error returned when a [PatternDb] can't be read or written.
+#[derive(Debug)]
+pub enum DbError {
+    /// Reading or writing the file failed.
+    Io(io::Error),
+    /// The bytes end before the database does.
+    TooShort,
+    /// The bytes don't start with the database signature.
+    BadMagic,
+    /// The database was written by an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// The checksum of the database doesn't match its contents.
+    ChecksumMismatch {
+        /// The checksum stored in the database.
+        expected: u32,
+        /// The checksum of the read contents.
+        actual: u32,
+    },
+    /// An entry couldn't be decoded.
+    Invalid,
+}
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "pattern database i/o failed: {error}"),
+            Self::TooShort => write!(f, "pattern database is truncated"),
+            Self::BadMagic => write!(f, "bytes are not a pattern database"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported pattern database version {version}"),
+            Self::ChecksumMismatch { expected, actual } => write!(f, "pattern database checksum is {actual:08x} but should be {expected:08x}"),
+            Self::Invalid => write!(f, "pattern database contains an invalid entry"),
+        }
+    }
+}
+
+impl std::error::Error for DbError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DbError {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Reads the fields of an encoded database one after another.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DbError> {
+        if self.bytes.len() < len {
+            return Err(DbError::TooShort);
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], DbError> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u32(&mut self) -> Result<u32, DbError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64, DbError> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    fn string(&mut self) -> Result<String, DbError> {
+        let len = self.u32()? as usize;
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|_| DbError::Invalid)
+    }
+
+    fn optional<T>(&mut self, read: impl FnOnce(&mut Self) -> Result<T, DbError>) -> Result<Option<T>, DbError> {
+        match self.array::<1>()? {
+            [0] => Ok(None),
+            [1] => read(self).map(Some),
+            _ => Err(DbError::Invalid),
+        }
+    }
+
+    fn object(&mut self) -> Result<Object, DbError> {
+        match self.array::<1>()? {
+            [0] => {
+                let [width, height] = self.array()?;
+                let bits = self.u64()?;
+                let pattern = SmallPattern::new(width as usize, height as usize).ok_or(DbError::Invalid)?;
+
+                (0..64).filter(|&bit| bits >> bit & 1 == 1).try_fold(pattern, |mut pattern, bit| {
+                    match bit % 8 < pattern.width() && bit / 8 < pattern.height() {
+                        true => pattern.set(bit % 8, bit / 8, true),
+                        false => return Err(DbError::Invalid),
+                    }
+                    Ok(pattern)
+                }).map(Object::Small)
+            }
+            [1] => {
+                let (width, height) = (self.u32()? as usize, self.u32()? as usize);
+                let len = width.checked_mul(height).ok_or(DbError::Invalid)?;
+                let bits = self.take(len.div_ceil(8))?;
+
+                let cells = (0..len).map(|i| Cell::from(bits[i / 8] >> (i % 8) & 1 == 1)).collect();
+                DynLife::from_cells(width, height, cells).map(Object::Large).ok_or(DbError::Invalid)
+            }
+            _ => Err(DbError::Invalid),
+        }
+    }
+}
+
+fn write_string(bytes: &mut Vec<u8>, string: &str) {
+    bytes.extend_from_slice(&(string.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(string.as_bytes());
+}
+
+fn write_object(bytes: &mut Vec<u8>, object: &Object) {
+    match object {
+        Object::Small(pattern) => {
+            bytes.extend_from_slice(&[0, pattern.width() as u8, pattern.height() as u8]);
+            bytes.extend_from_slice(&pattern.bits().to_le_bytes());
+        }
+        Object::Large(life) => {
+            bytes.push(1);
+            bytes.extend_from_slice(&(life.width() as u32).to_le_bytes());
+            bytes.extend_from_slice(&(life.height() as u32).to_le_bytes());
+
+            for cells in life.cells().chunks(8) {
+                bytes.push(cells.iter().enumerate().fold(0, |byte, (i, cell)| byte | (cell.is_alive() as u8) << i));
+            }
+        }
+    }
+}
+
+/// A store of objects with [Metadata] about them, indexed by their [canonical][Object::canonical] form.
+///
+/// See the [module documentation][self] for an example.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PatternDb {
+    entries: Vec<Entry>,
+    index: HashMap<Object, usize>,
+}
+
+impl PatternDb {
+    /// Creates an empty PatternDb.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of objects.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no objects.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds an object with the given [Metadata] and returns true, or counts another occurrence and returns false if it's already known.
+    ///
+    /// The [Metadata] of known objects is left unchanged.
+    pub fn insert(&mut self, object: &Object, metadata: Metadata) -> bool {
+        let object = object.canonical();
+
+        match self.index.get(&object) {
+            Some(&i) => {
+                self.entries[i].occurrences += 1;
+                false
+            }
+            None => {
+                self.index.insert(object.clone(), self.entries.len());
+                self.entries.push(Entry {
+                    object,
+                    metadata,
+                    occurrences: 1,
+                });
+                true
+            }
+        }
+    }
+
+    /// Returns the [Entry] of the object in any orientation or position, if it's known.
+    pub fn get(&self, object: &Object) -> Option<&Entry> {
+        self.index.get(&object.canonical()).map(|&i| &self.entries[i])
+    }
+
+    /// Returns a mutable reference to the [Entry] of the object in any orientation or position, if it's known.
+    pub fn get_mut(&mut self, object: &Object) -> Option<&mut Entry> {
+        self.index.get(&object.canonical()).map(|&i| &mut self.entries[i])
+    }
+
+    /// Returns an iterator over all entries, in the order they were first inserted.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Entry> {
+        self.entries.iter()
+    }
+
+    /// Returns an iterator over the entries for which `predicate` returns true.
+    pub fn query<'a>(&'a self, predicate: impl Fn(&Entry) -> bool + 'a) -> impl Iterator<Item = &'a Entry> {
+        self.entries.iter().filter(move |entry| predicate(entry))
+    }
+
+    /// Returns an iterator over the entries of objects living in `rule`.
+    pub fn with_rule<'a>(&'a self, rule: &'a str) -> impl Iterator<Item = &'a Entry> {
+        self.query(move |entry| entry.metadata.rule == rule)
+    }
+
+    /// Returns an iterator over the entries of objects with the given period.
+    pub fn with_period(&self, period: u32) -> impl Iterator<Item = &Entry> {
+        self.query(move |entry| entry.metadata.period == period)
+    }
+
+    /// Returns an iterator over the entries of moving objects, like spaceships.
+    pub fn moving(&self) -> impl Iterator<Item = &Entry> {
+        self.query(|entry| entry.metadata.is_moving())
+    }
+
+    /// Encodes the database as bytes, ending with a CRC-32 checksum of everything before it.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&(self.entries.len() as u32).to_le_bytes());
+
+        for entry in &self.entries {
+            write_object(&mut bytes, &entry.object);
+            bytes.extend_from_slice(&entry.occurrences.to_le_bytes());
+
+            let metadata = &entry.metadata;
+            write_string(&mut bytes, &metadata.rule);
+            bytes.extend_from_slice(&metadata.period.to_le_bytes());
+            bytes.extend_from_slice(&metadata.dx.to_le_bytes());
+            bytes.extend_from_slice(&metadata.dy.to_le_bytes());
+
+            match &metadata.discoverer {
+                Some(discoverer) => {
+                    bytes.push(1);
+                    write_string(&mut bytes, discoverer);
+                }
+                None => bytes.push(0),
+            }
+            match metadata.discovered {
+                Some(discovered) => {
+                    bytes.push(1);
+                    bytes.extend_from_slice(&discovered.to_le_bytes());
+                }
+                None => bytes.push(0),
+            }
+        }
+
+        bytes.extend_from_slice(&crc32(&bytes).to_le_bytes());
+        bytes
+    }
+
+    /// Decodes a database written by [`PatternDb::to_bytes`], verifying its checksum.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DbError> {
+        version(bytes)?;
+
+        if bytes.len() < MAGIC.len() + 1 + 4 + 4 {
+            return Err(DbError::TooShort);
+        }
+
+        let (body, checksum) = bytes.split_at(bytes.len() - 4);
+        let expected = u32::from_le_bytes(checksum.try_into().unwrap());
+        let actual = crc32(body);
+
+        if expected != actual {
+            return Err(DbError::ChecksumMismatch { expected, actual });
+        }
+
+        let mut reader = Reader { bytes: &body[5..] };
+        let mut db = Self::new();
+
+        for _ in 0..reader.u32()? {
+            let object = reader.object()?;
+            let occurrences = reader.u64()?;
+            let metadata = Metadata {
+                rule: reader.string()?,
+                period: reader.u32()?,
+                dx: reader.u32()? as i32,
+                dy: reader.u32()? as i32,
+                discoverer: reader.optional(Reader::string)?,
+                discovered: reader.optional(Reader::u64)?,
+            };
+
+            // every object is stored once and in its canonical form
+            if object != object.canonical() || db.index.contains_key(&object) {
+                return Err(DbError::Invalid);
+            }
+
+            db.index.insert(object.clone(), db.entries.len());
+            db.entries.push(Entry {
+                object,
+                metadata,
+                occurrences,
+            });
+        }
+
+        match reader.bytes.is_empty() {
+            true => Ok(db),
+            false => Err(DbError::Invalid),
+        }
+    }
+
+    /// Reads a database from a file written by [`PatternDb::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, DbError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Writes the database to a file, replacing it only once the new contents are completely written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), DbError> {
+        let path = path.as_ref();
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+
+        let mut file = std::fs::File::create(&temporary)?;
+        io::Write::write_all(&mut file, &self.to_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&temporary, path)?;
+
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a PatternDb {
+    type Item = &'a Entry;
+    type IntoIter = std::slice::Iter<'a, Entry>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+/// Returns the format version of a database without decoding it, failing if it isn't supported.
+pub fn version(bytes: &[u8]) -> Result<u8, DbError> {
+    if bytes.len() < MAGIC.len() + 1 {
+        return Err(DbError::TooShort);
+    }
+    if bytes[..4] != MAGIC {
+        return Err(DbError::BadMagic);
+    }
+
+    match bytes[4] {
+        version @ MIN_VERSION..=VERSION => Ok(version),
+        version => Err(DbError::UnsupportedVersion(version)),
+    }
+}
+
+/// Rewrites a database of any supported version in the current [VERSION], verifying its checksum and entries.
+///
+/// Only version 1 exists so far, so this currently re-encodes the database unchanged. Calling it on load keeps long-lived databases readable once the format changes.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::db::{self, Metadata, PatternDb};
+///
+/// let mut patterns = PatternDb::new();
+/// patterns.insert(&Object::Small(SmallPattern::from_rows(&["OO", "OO"]).unwrap()), Metadata::new("B3/S23", 1));
+///
+/// let bytes = patterns.to_bytes();
+///
+/// assert_eq!(db::migrate(&bytes).unwrap(), bytes);
+/// assert_eq!(db::version(&bytes).unwrap(), db::VERSION);
+/// assert!(db::migrate(b"YGLD\x63").is_err());
+/// ```
+pub fn migrate(bytes: &[u8]) -> Result<Vec<u8>, DbError> {
+    Ok(PatternDb::from_bytes(bytes)?.to_bytes())
+}
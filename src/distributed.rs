@@ -0,0 +1,388 @@
+//! Running one board across several processes or machines.
+//!
+//! A coordinator splits a [Life] into [TilePackets][TilePacket] with [`split_packets`]. Each packet holds one tile plus a halo of the surrounding [Cells][Cell], the generation it belongs to, and a checksum once it's serialized with [`TilePacket::to_bytes`]. A worker steps a packet with [`TilePacket::step`] for as many generations as the halo is wide, without needing any other tile, and sends it back. The coordinator then puts the tiles back together with [`assemble`] and starts over.
+//!
+//! # Examples
+//!
+//! ```
+//! use your_game_of_life::*;
+//! use your_game_of_life::distributed::*;
+//!
+//! // a blinker crossing the border between two tiles
+//! let mut life = Life::<5, 6>::default();
+//! for x in 1..4 {
+//!     life.set(x, 2, Cell::alive());
+//! }
+//!
+//! let rule = |this: Cell, others: [Cell; 8], _, _| match others.alive() {
+//!     2 => this,
+//!     3 => Cell::alive(),
+//!     _ => Cell::dead(),
+//! };
+//!
+//! let mut expected = life;
+//! expected.play_for(2, rule);
+//!
+//! let packets: Vec<_> = split_packets(&life, 0, 3, 5, 2).iter()
+//!     // send each packet to a worker and back
+//!     .map(|packet| TilePacket::from_bytes(&packet.to_bytes()).unwrap())
+//!     .map(|packet| packet.step(2, rule).unwrap())
+//!     .collect();
+//!
+//! assert_eq!(assemble(&mut life, &packets), Ok(2));
+//! assert_eq!(life, expected);
+//! ```
+
+use std::fmt;
+
+use crate::{Aliveness, BoundaryMode, Cell, Life};
+use crate::checksum::crc32;
+
+const MAGIC: [u8; 4] = *b"YGLT";
+const VERSION: u8 = 2;
+const HEADER_LEN: usize = 4 + 1 + 8 + 4 * 7 + 4 + 4;
+
+/// One tile of a larger board plus a halo of the [Cells][Cell] around it.
+///
+/// The tile covers the [Cells][Cell] from `(x, y)` to `(x + width, y + height)` of the board. The `cells` hold the tile extended by `halo` [Cells][Cell] on every side, row by row, where [Cells][Cell] outside of the board are filled in according to the board's [BoundaryMode].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TilePacket {
+    /// The generation the [Cells][Cell] belong to.
+    pub generation: u64,
+    /// The width of the whole board.
+    pub board_width: usize,
+    /// The height of the whole board.
+    pub board_height: usize,
+    /// The x-position of the tile on the board.
+    pub x: usize,
+    /// The y-position of the tile on the board.
+    pub y: usize,
+    /// The width of the tile, without the halo.
+    pub width: usize,
+    /// The height of the tile, without the halo.
+    pub height: usize,
+    /// The number of [Cells][Cell] the halo extends the tile by on every side.
+    pub halo: usize,
+    /// The [BoundaryMode] of the board.
+    pub boundary: BoundaryMode,
+    /// The [Aliveness] of the board.
+    pub aliveness: Aliveness,
+    /// The [Cells][Cell] of the tile and its halo, row by row.
+    pub cells: Vec<Cell>,
+}
+
+/// The error returned when a [TilePacket] can't be decoded, stepped or assembled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PacketError {
+    /// The bytes end before the packet does.
+    TooShort,
+    /// The bytes don't start with the packet signature.
+    BadMagic,
+    /// The packet was written by an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// The number of [Cells][Cell] doesn't match the size of the tile and its halo.
+    SizeMismatch,
+    /// The packet has an unknown [BoundaryMode].
+    BadBoundary(u8),
+    /// The packet has an unknown [Aliveness].
+    BadAliveness(u8),
+    /// The checksum of the packet doesn't match its contents.
+    ChecksumMismatch {
+        /// The checksum stored in the packet.
+        expected: u32,
+        /// The checksum of the received contents.
+        actual: u32,
+    },
+    /// More generations were requested than the halo is wide.
+    HaloTooSmall {
+        /// The width of the halo.
+        halo: usize,
+        /// The number of requested generations.
+        steps: u32,
+    },
+    /// The packet belongs to a board of a different size.
+    BoardMismatch,
+    /// The packets belong to different generations.
+    GenerationMismatch,
+}
+
+impl fmt::Display for PacketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::TooShort => write!(f, "tile packet is truncated"),
+            Self::BadMagic => write!(f, "bytes are not a tile packet"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported tile packet version {version}"),
+            Self::SizeMismatch => write!(f, "tile packet cells don't match its size"),
+            Self::BadBoundary(mode) => write!(f, "unknown tile packet boundary mode {mode}"),
+            Self::BadAliveness(policy) => write!(f, "unknown tile packet aliveness policy {policy}"),
+            Self::ChecksumMismatch { expected, actual } => write!(f, "tile packet checksum is {actual:08x} but should be {expected:08x}"),
+            Self::HaloTooSmall { halo, steps } => write!(f, "a halo of {halo} cells can't be stepped {steps} times"),
+            Self::BoardMismatch => write!(f, "tile packet belongs to a board of a different size"),
+            Self::GenerationMismatch => write!(f, "tile packets belong to different generations"),
+        }
+    }
+}
+
+impl std::error::Error for PacketError {}
+
+impl TilePacket {
+    /// Returns the width of the tile including its halo.
+    #[inline]
+    pub const fn outer_width(&self) -> usize {
+        self.width.saturating_add(self.halo.saturating_mul(2))
+    }
+
+    /// Returns the height of the tile including its halo.
+    #[inline]
+    pub const fn outer_height(&self) -> usize {
+        self.height.saturating_add(self.halo.saturating_mul(2))
+    }
+
+    /// Returns the position on the board of the [Cell] at `index` in `cells`, or `None` if it refers to the [`Constant`][BoundaryMode::Constant] [Cell].
+    fn board_position(&self, index: usize) -> Option<(usize, usize)> {
+        let x = (self.x + index % self.outer_width()) as isize - self.halo as isize;
+        let y = (self.y + index / self.outer_width()) as isize - self.halo as isize;
+
+        Some((self.boundary.resolve(x, self.board_width)?, self.boundary.resolve(y, self.board_height)?))
+    }
+
+    /// Encodes the packet as bytes, ending with a CRC-32 checksum of everything before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a size or position of the packet doesn't fit into the [u32] it is encoded as.
+    #[track_caller]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.cells.len() * 3 + 4);
+
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.generation.to_le_bytes());
+
+        for value in [self.board_width, self.board_height, self.x, self.y, self.width, self.height, self.halo] {
+            let value = u32::try_from(value).expect("the sizes and positions of a tile packet must fit into a u32");
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&self.boundary.to_bytes());
+        bytes.extend_from_slice(&self.aliveness.to_bytes());
+
+        for cell in &self.cells {
+            bytes.extend_from_slice(&[cell.r, cell.g, cell.b]);
+        }
+
+        bytes.extend_from_slice(&crc32(&bytes).to_le_bytes());
+
+        bytes
+    }
+
+    /// Decodes a packet written by [`TilePacket::to_bytes`], verifying its checksum.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PacketError> {
+        if bytes.len() < HEADER_LEN + 4 {
+            return Err(PacketError::TooShort);
+        }
+        if bytes[..4] != MAGIC {
+            return Err(PacketError::BadMagic);
+        }
+        if bytes[4] != VERSION {
+            return Err(PacketError::UnsupportedVersion(bytes[4]));
+        }
+
+        let (body, checksum) = bytes.split_at(bytes.len() - 4);
+        let expected = u32::from_le_bytes(checksum.try_into().unwrap());
+        let actual = crc32(body);
+
+        if expected != actual {
+            return Err(PacketError::ChecksumMismatch { expected, actual });
+        }
+
+        let word = |i: usize| u32::from_le_bytes(body[13 + i * 4..17 + i * 4].try_into().unwrap()) as usize;
+        let boundary = body[HEADER_LEN - 8..HEADER_LEN - 4].try_into().unwrap();
+        let aliveness = body[HEADER_LEN - 4..HEADER_LEN].try_into().unwrap();
+        let cells = body[HEADER_LEN..].chunks_exact(3).map(|rgb| Cell::from([rgb[0], rgb[1], rgb[2]]));
+
+        let packet = Self {
+            generation: u64::from_le_bytes(body[5..13].try_into().unwrap()),
+            board_width: word(0),
+            board_height: word(1),
+            x: word(2),
+            y: word(3),
+            width: word(4),
+            height: word(5),
+            halo: word(6),
+            boundary: BoundaryMode::from_bytes(boundary).map_err(PacketError::BadBoundary)?,
+            aliveness: Aliveness::from_bytes(aliveness).map_err(PacketError::BadAliveness)?,
+            cells: cells.collect(),
+        };
+
+        match (body.len() - HEADER_LEN).is_multiple_of(3) && packet.outer_width().checked_mul(packet.outer_height()) == Some(packet.cells.len()) {
+            true => Ok(packet),
+            false => Err(PacketError::SizeMismatch),
+        }
+    }
+
+    /// Invokes the closure `steps` times on each [Cell] of the tile, like [`Life::play_for`] would on the whole board, and returns the resulting tile without a halo.
+    ///
+    /// The closure receives the positions of the [Cells][Cell] on the whole board, and the [Cells][Cell] of the halo are stepped as the [Cells][Cell] of the board they refer to. Each generation invalidates the outermost ring of the halo, so `steps` can't be greater than the halo width.
+    ///
+    /// Like [`Life::play`], the [Cells][Cell] are [normalized][Aliveness::normalize] by the packet's [`aliveness`][TilePacket::aliveness] for the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use your_game_of_life::*;
+    /// use your_game_of_life::distributed::*;
+    ///
+    /// // a blinker of black cells on a white board
+    /// let white = Cell::white();
+    /// let mut life = Life::<5, 5>::from([[white; 5]; 5]);
+    /// life.aliveness = Aliveness::Background(white);
+    /// life.boundary = BoundaryMode::Constant(white);
+    /// for x in 1..4 {
+    ///     life.set(x, 2, Cell::black());
+    /// }
+    ///
+    /// let rule = |this: Cell, others: [Cell; 8], _, _| match others.alive() {
+    ///     2 => this,
+    ///     3 => Cell::alive(),
+    ///     _ => Cell::dead(),
+    /// };
+    ///
+    /// let mut expected = life;
+    /// expected.play_for(2, rule);
+    ///
+    /// let packets: Vec<_> = split_packets(&life, 0, 2, 2, 2).iter()
+    ///     .map(|packet| packet.step(2, rule).unwrap())
+    ///     .collect();
+    ///
+    /// assert_eq!(assemble(&mut life, &packets), Ok(2));
+    /// assert_eq!(life, expected);
+    /// ```
+    pub fn step(&self, steps: u32, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) -> Result<Self, PacketError> {
+        if steps as usize > self.halo {
+            return Err(PacketError::HaloTooSmall {
+                halo: self.halo,
+                steps,
+            });
+        }
+
+        let (width, height) = (self.outer_width(), self.outer_height());
+        let mut cells = self.cells.clone();
+
+        for _ in 0..steps {
+            let previous = cells.clone();
+
+            for (index, cell) in cells.iter_mut().enumerate() {
+                let Some((x, y)) = self.board_position(index) else {
+                    continue;
+                };
+
+                let (tx, ty) = (index % width, index / width);
+                let mut neighbors = [self.boundary.outside(); 8];
+
+                for (neighbor, (dx, dy)) in neighbors.iter_mut().zip(crate::NEIGHBOR_OFFSETS) {
+                    if let (Some(nx), Some(ny)) = (tx.checked_add_signed(dx), ty.checked_add_signed(dy)) {
+                        if nx < width && ny < height {
+                            *neighbor = previous[ny * width + nx];
+                        }
+                    }
+                }
+
+                *cell = crate::normalized(self.aliveness, |this, others| f(this, others, x, y), previous[index], neighbors);
+            }
+        }
+
+        let mut core = Vec::with_capacity(self.width * self.height);
+
+        for row in cells.chunks_exact(width.max(1)).skip(self.halo).take(self.height) {
+            core.extend_from_slice(&row[self.halo..self.halo + self.width]);
+        }
+
+        Ok(Self {
+            generation: self.generation + steps as u64,
+            halo: 0,
+            cells: core,
+            ..*self
+        })
+    }
+}
+
+/// Splits a board into [TilePackets][TilePacket] of at most `tile_width` × `tile_height` [Cells][Cell], each with a halo `halo` [Cells][Cell] wide.
+///
+/// Tiles on the right and bottom edges are cut off at the edge of the board.
+///
+/// # Panics
+///
+/// Panics if `tile_width` or `tile_height` is 0.
+#[track_caller]
+pub fn split_packets<const HEIGHT: usize, const WIDTH: usize>(life: &Life<HEIGHT, WIDTH>, generation: u64, tile_width: usize, tile_height: usize, halo: usize) -> Vec<TilePacket> {
+    assert!(tile_width != 0 && tile_height != 0, "tiles must not be empty");
+
+    let mut packets = Vec::new();
+
+    for y in (0..HEIGHT).step_by(tile_height) {
+        for x in (0..WIDTH).step_by(tile_width) {
+            let (width, height) = (tile_width.min(WIDTH - x), tile_height.min(HEIGHT - y));
+            let mut cells = Vec::with_capacity((width + 2 * halo) * (height + 2 * halo));
+
+            for ty in 0..height + 2 * halo {
+                for tx in 0..width + 2 * halo {
+                    let bx = life.boundary.resolve((x + tx) as isize - halo as isize, WIDTH);
+                    let by = life.boundary.resolve((y + ty) as isize - halo as isize, HEIGHT);
+
+                    cells.push(match (bx, by) {
+                        (Some(bx), Some(by)) => life.cells[by][bx],
+                        _ => life.boundary.outside(),
+                    });
+                }
+            }
+
+            packets.push(TilePacket {
+                generation,
+                board_width: WIDTH,
+                board_height: HEIGHT,
+                x,
+                y,
+                width,
+                height,
+                halo,
+                boundary: life.boundary,
+                aliveness: life.aliveness,
+                cells,
+            });
+        }
+    }
+
+    packets
+}
+
+/// Copies the tiles of the packets back onto the board and returns the generation they belong to.
+///
+/// The halos of the packets are ignored. Nothing is copied if the packets belong to a board of a different size or to different generations. If there are no packets, the board is left unchanged and 0 is returned.
+pub fn assemble<const HEIGHT: usize, const WIDTH: usize>(life: &mut Life<HEIGHT, WIDTH>, packets: &[TilePacket]) -> Result<u64, PacketError> {
+    let generation = packets.first().map_or(0, |packet| packet.generation);
+
+    for packet in packets {
+        let fits = |start: usize, len: usize, size| start.checked_add(len).is_some_and(|end| end <= size);
+
+        if packet.board_width != WIDTH || packet.board_height != HEIGHT || !fits(packet.x, packet.width, WIDTH) || !fits(packet.y, packet.height, HEIGHT) {
+            return Err(PacketError::BoardMismatch);
+        }
+        if packet.outer_width().checked_mul(packet.outer_height()) != Some(packet.cells.len()) {
+            return Err(PacketError::SizeMismatch);
+        }
+        if packet.generation != generation {
+            return Err(PacketError::GenerationMismatch);
+        }
+    }
+
+    for packet in packets {
+        let rows = packet.cells.chunks_exact(packet.outer_width().max(1)).skip(packet.halo);
+
+        for (row, source) in life.cells[packet.y..packet.y + packet.height].iter_mut().zip(rows) {
+            row[packet.x..packet.x + packet.width].copy_from_slice(&source[packet.halo..packet.halo + packet.width]);
+        }
+    }
+
+    Ok(generation)
+}
@@ -0,0 +1,509 @@
+use std::fmt;
+
+use crate::pattern::{Parsed, PatternError};
+use crate::{Aliveness, BoundaryMode, Cell, GridPool, Life, Rule, NEIGHBOR_OFFSETS};
+
+/// 2D grid of [Cells][Cell] with a size chosen at runtime.
+///
+/// This works like [Life], but the [Cells][Cell] live on the heap, so the board can be sized from user input or a loaded file and can be far larger than the stack allows.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let (width, height) = (5, 5);
+///
+/// let mut life = DynLife::new(width, height);
+/// for x in 1..4 {
+///     life.set(x, 2, Cell::alive());
+/// }
+///
+/// life.play(|this, others, _, _| match others.alive() {
+///     2 => this,
+///     3 => Cell::alive(),
+///     _ => Cell::dead(),
+/// });
+///
+/// assert!((1..4).all(|y| life.get(2, y).is_alive()));
+/// assert!(!life.get(1, 2).is_alive());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynLife {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    /// Decides which [Cell] is added in the `[Cell; 8]` array on invoking the closure for [`play`][DynLife::play] and [`play_for`][DynLife::play_for] when the neighboring [Cell] would have been out of bounds.
+    pub boundary: BoundaryMode,
+    /// Decides which [Cells][Cell] count as alive and which color dead [Cells][Cell] get for [`play_rule`][DynLife::play_rule], like [`Life::aliveness`][Life#structfield.aliveness].
+    pub aliveness: Aliveness,
+}
+
+/// The error returned when converting a board into a [Life] of a different size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeMismatch {
+    /// The width of the converted board.
+    pub width: usize,
+    /// The height of the converted board.
+    pub height: usize,
+}
+
+impl fmt::Display for SizeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "board of {}x{} cells doesn't match the size of the Life", self.width, self.height)
+    }
+}
+
+impl std::error::Error for SizeMismatch {}
+
+impl DynLife {
+    /// Creates a DynLife of `width` × `height` [`Cell::default`] [Cells][Cell].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of [Cells][Cell] overflows a `usize`.
+    #[track_caller]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, Cell::default())
+    }
+
+    /// Creates a DynLife of `width` × `height` [Cells][Cell] all set to `fill`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of [Cells][Cell] overflows a `usize`.
+    #[track_caller]
+    pub fn filled(width: usize, height: usize, fill: Cell) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; Self::len_of(width, height)],
+            boundary: BoundaryMode::default(),
+            aliveness: Aliveness::default(),
+        }
+    }
+
+    /// Creates a DynLife of `width` × `height` [`Cell::default`] [Cells][Cell] with its buffer taken from `pool`.
+    ///
+    /// Give the buffer back with [`into_cells`][DynLife::into_cells] and [`GridPool::give`] when done.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of [Cells][Cell] overflows a `usize`.
+    #[track_caller]
+    pub fn new_in(width: usize, height: usize, pool: &mut GridPool) -> Self {
+        Self {
+            width,
+            height,
+            cells: pool.take(Self::len_of(width, height), Cell::default()),
+            boundary: BoundaryMode::default(),
+            aliveness: Aliveness::default(),
+        }
+    }
+
+    /// Creates a DynLife from its [Cells][Cell] stored row by row.
+    ///
+    /// Returns `None` if the number of [Cells][Cell] isn't `width` × `height`.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<Cell>) -> Option<Self> {
+        match width.checked_mul(height) == Some(cells.len()) {
+            true => Some(Self {
+                width,
+                height,
+                cells,
+                boundary: BoundaryMode::default(),
+                aliveness: Aliveness::default(),
+            }),
+            false => None,
+        }
+    }
+
+    #[track_caller]
+    fn len_of(width: usize, height: usize) -> usize {
+        width.checked_mul(height).expect("the number of cells overflows a usize")
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the [Cells][Cell] stored row by row, indexed as `cells[y * width + x]`.
+    #[inline]
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    /// Returns the [Cells][Cell] stored row by row as a mutable slice, indexed as `cells[y * width + x]`.
+    #[inline]
+    pub fn cells_mut(&mut self) -> &mut [Cell] {
+        &mut self.cells
+    }
+
+    /// Returns the buffer holding the [Cells][Cell], stored row by row.
+    #[inline]
+    pub fn into_cells(self) -> Vec<Cell> {
+        self.cells
+    }
+
+    /// Returns an iterator over the rows of [Cells][Cell].
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        // a board without columns still has its rows, they are just empty
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    #[track_caller]
+    #[inline]
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height, "index ({x}, {y}) out of bounds for a board of {}x{} cells", self.width, self.height);
+        y * self.width + x
+    }
+
+    /// Returns the [Cell] at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        self.cells[self.index(x, y)]
+    }
+
+    /// Returns an immutable reference to the [Cell] at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn get_ref(&self, x: usize, y: usize) -> &Cell {
+        &self.cells[self.index(x, y)]
+    }
+
+    /// Returns a mutable reference to the [Cell] at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> &mut Cell {
+        let index = self.index(x, y);
+        &mut self.cells[index]
+    }
+
+    /// Sets the [Cell] at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        *self.get_mut(x, y) = cell;
+    }
+
+    /// Returns the [Cell] at the given index, or `None` if it is out of bounds.
+    #[inline]
+    pub fn try_get(&self, x: usize, y: usize) -> Option<Cell> {
+        match x < self.width && y < self.height {
+            true => Some(self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    /// Returns a mutable reference to the [Cell] at the given index, or `None` if it is out of bounds.
+    #[inline]
+    pub fn try_get_mut(&mut self, x: usize, y: usize) -> Option<&mut Cell> {
+        match x < self.width && y < self.height {
+            true => Some(&mut self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    /// Returns the [Cells][Cell] surrounding the given index, like [`Life::neighbors`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn neighbors(&self, x: usize, y: usize) -> [Cell; 8] {
+        self.index(x, y);
+        self.get_surrounding(x, y)
+    }
+
+    fn get_surrounding(&self, x: usize, y: usize) -> [Cell; 8] {
+        let mut surrounding = [self.boundary.outside(); 8];
+
+        for (neighbor, (dx, dy)) in surrounding.iter_mut().zip(NEIGHBOR_OFFSETS) {
+            let nx = self.boundary.resolve(x as isize + dx, self.width);
+            let ny = self.boundary.resolve(y as isize + dy, self.height);
+
+            if let (Some(nx), Some(ny)) = (nx, ny) {
+                *neighbor = self.cells[ny * self.width + nx];
+            }
+        }
+
+        surrounding
+    }
+
+    /// Invokes the given closure on each [Cell] in the DynLife.
+    ///
+    /// This works exactly like [`Life::play`].
+    pub fn play(&mut self, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        #[cfg(feature = "strict")]
+        assert_eq!(self.cells.len(), self.width * self.height, "the cells don't match the size of the DynLife");
+
+        let mut proto = self.cells.clone();
+
+        for (i, cell) in proto.iter_mut().enumerate() {
+            let (x, y) = (i % self.width, i / self.width);
+            *cell = crate::normalized(self.aliveness, |this, others| f(this, others, x, y), *cell, self.get_surrounding(x, y));
+        }
+
+        self.cells = proto;
+    }
+
+    /// Invokes the given closure `n` times on each [Cell] in the DynLife.
+    ///
+    /// This works exactly like [`Life::play_for`].
+    #[inline]
+    pub fn play_for(&mut self, n: u32, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        for _ in 0..n {
+            self.play(&mut f);
+        }
+    }
+
+    fn from_parsed(pattern: Parsed) -> Result<Self, PatternError> {
+        let too_large = PatternError::TooLarge {
+            width: pattern.width,
+            height: pattern.height,
+        };
+        // the readers already rejected patterns of more than MAX_CELLS cells
+        let len = pattern.width.checked_mul(pattern.height).ok_or(too_large.clone())?;
+
+        let mut cells = Vec::new();
+        cells.try_reserve_exact(len).map_err(|_| too_large)?;
+        cells.resize(len, Cell::default());
+
+        let mut life = Self {
+            width: pattern.width,
+            height: pattern.height,
+            cells,
+            boundary: BoundaryMode::default(),
+            aliveness: Aliveness::default(),
+        };
+
+        for (x, y) in pattern.alive {
+            life.set(x, y, Cell::alive());
+        }
+
+        Ok(life)
+    }
+
+    /// Reads a pattern in the RLE format into a DynLife of the size given in its header, or larger if the pattern doesn't fit into it.
+    ///
+    /// See [`Life::from_rle`] for details. Patterns of more than [`MAX_CELLS`][crate::pattern::MAX_CELLS] [Cells][Cell] are rejected with [`PatternError::TooLarge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// use your_game_of_life::pattern::PatternError;
+    ///
+    /// let glider = DynLife::from_rle("x = 3, y = 3\nbob$2bo$3o!").unwrap();
+    ///
+    /// assert_eq!((glider.width(), glider.height()), (3, 3));
+    /// assert_eq!(glider.to_rle(), "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n");
+    ///
+    /// assert_eq!(DynLife::from_rle("x = 100000, y = 100000\n!"), Err(PatternError::TooLarge { width: 100000, height: 100000 }));
+    /// assert!(DynLife::from_rle("x = 0, y = 0\n99999999999o!").is_err());
+    /// ```
+    pub fn from_rle(rle: &str) -> Result<Self, PatternError> {
+        Parsed::rle(rle, (usize::MAX, usize::MAX)).and_then(Self::from_parsed)
+    }
+
+    /// Writes the DynLife in the RLE format, with `B3/S23` as the rule in the header.
+    pub fn to_rle(&self) -> String {
+        crate::pattern::write_rle(self.width, self.height, "B3/S23", |x, y| self.aliveness.is_alive(self.get(x, y)))
+    }
+
+    /// Reads a pattern copied to the clipboard into a DynLife of the size given in its RLE header, or just large enough to hold it otherwise.
+    ///
+    /// See [`Life::from_clipboard_text`] for details. Like the other readers, patterns of more than [`MAX_CELLS`][crate::pattern::MAX_CELLS] [Cells][Cell] are rejected with [`PatternError::TooLarge`], since the clipboard may hold anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// assert!(DynLife::from_clipboard_text("x = 100000, y = 100000\n!").is_err());
+    /// assert!(DynLife::from_clipboard_text("99999999999o!").is_err());
+    /// ```
+    pub fn from_clipboard_text(text: &str) -> Result<Self, PatternError> {
+        Parsed::clipboard(text, (usize::MAX, usize::MAX)).and_then(Self::from_parsed)
+    }
+
+    /// Writes the DynLife in the RLE format with `rule` in the header, like [`Life::to_clipboard_text`].
+    pub fn to_clipboard_text(&self, rule: &Rule) -> String {
+        crate::pattern::write_rle(self.width, self.height, &rule.to_string(), |x, y| self.aliveness.is_alive(self.get(x, y)))
+    }
+
+    /// Reads a pattern in the plaintext format into a DynLife just large enough to hold it.
+    ///
+    /// See [`Life::from_plaintext`] for details. Patterns of more than [`MAX_CELLS`][crate::pattern::MAX_CELLS] [Cells][Cell] are rejected with [`PatternError::TooLarge`].
+    pub fn from_plaintext(plaintext: &str) -> Result<Self, PatternError> {
+        Parsed::plaintext(plaintext, (usize::MAX, usize::MAX)).and_then(Self::from_parsed)
+    }
+
+    /// Reads a pattern in the Life 1.06 format into a DynLife just large enough to hold it.
+    ///
+    /// See [`Life::from_life106`] for details. Patterns of more than [`MAX_CELLS`][crate::pattern::MAX_CELLS] [Cells][Cell] are rejected with [`PatternError::TooLarge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// // the coordinates are far apart, so the bounding box is huge
+    /// assert!(DynLife::from_life106("#Life 1.06\n0 0\n1000000 1000000\n").is_err());
+    /// ```
+    pub fn from_life106(life106: &str) -> Result<Self, PatternError> {
+        Parsed::life106(life106, (usize::MAX, usize::MAX)).and_then(Self::from_parsed)
+    }
+}
+
+/// Converts any 2D container of [Cells][Cell] into a DynLife, for example a [`Vec<Vec<bool>>`].
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::DynLife;
+/// let life = DynLife::from(vec![vec![true, false], vec![false, true]]);
+/// ```
+impl<T> From<T> for DynLife
+where T: IntoIterator,
+Self: FromIterator<T::Item> {
+    fn from(iter: T) -> Self {
+        iter.into_iter().collect()
+    }
+}
+
+/// Converts any 2D container of [Cells][Cell] into a DynLife, for example a [`Vec<Vec<bool>>`].
+///
+/// The DynLife is as wide as the longest row. Shorter rows are filled up with [`Cell::default`].
+impl<A> FromIterator<A> for DynLife
+where A: IntoIterator,
+A::Item: Into<Cell> {
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let rows: Vec<Vec<Cell>> = iter.into_iter().map(|row| row.into_iter().map(Into::into).collect()).collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut life = Self::new(width, rows.len());
+
+        for (y, row) in rows.into_iter().enumerate() {
+            life.cells[y * width..][..row.len()].copy_from_slice(&row);
+        }
+
+        life
+    }
+}
+
+/// Converts a [Life] into a DynLife of the same size, keeping its [`boundary`][Life#structfield.boundary] mode.
+impl<const HEIGHT: usize, const WIDTH: usize> From<&Life<HEIGHT, WIDTH>> for DynLife {
+    fn from(life: &Life<HEIGHT, WIDTH>) -> Self {
+        Self {
+            width: WIDTH,
+            height: HEIGHT,
+            cells: life.cells.as_flattened().to_vec(),
+            boundary: life.boundary,
+            aliveness: life.aliveness,
+        }
+    }
+}
+
+/// Converts a [Life] into a DynLife of the same size, keeping its [`boundary`][Life#structfield.boundary] mode.
+impl<const HEIGHT: usize, const WIDTH: usize> From<Life<HEIGHT, WIDTH>> for DynLife {
+    #[inline]
+    fn from(life: Life<HEIGHT, WIDTH>) -> Self {
+        Self::from(&life)
+    }
+}
+
+/// Converts a DynLife into a [Life], keeping its [`boundary`][DynLife#structfield.boundary] mode.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let life = Life::<2, 3>::from([[true, false, true], [false, true, false]]);
+/// let dynamic = DynLife::from(life);
+///
+/// assert_eq!(Life::<2, 3>::try_from(&dynamic), Ok(life));
+/// assert!(Life::<3, 2>::try_from(&dynamic).is_err());
+/// ```
+impl<const HEIGHT: usize, const WIDTH: usize> TryFrom<&DynLife> for Life<HEIGHT, WIDTH> {
+    type Error = SizeMismatch;
+
+    fn try_from(life: &DynLife) -> Result<Self, Self::Error> {
+        if life.width != WIDTH || life.height != HEIGHT {
+            return Err(SizeMismatch {
+                width: life.width,
+                height: life.height,
+            });
+        }
+
+        let mut converted = Life {
+            boundary: life.boundary,
+            aliveness: life.aliveness,
+            ..Life::default()
+        };
+        converted.cells.as_flattened_mut().copy_from_slice(&life.cells);
+
+        Ok(converted)
+    }
+}
+
+/// Converts a DynLife into a [Life], keeping its [`boundary`][DynLife#structfield.boundary] mode.
+impl<const HEIGHT: usize, const WIDTH: usize> TryFrom<DynLife> for Life<HEIGHT, WIDTH> {
+    type Error = SizeMismatch;
+
+    #[inline]
+    fn try_from(life: DynLife) -> Result<Self, Self::Error> {
+        Self::try_from(&life)
+    }
+}
+
+/// Indexes the [Cells][Cell] of a DynLife by `(x, y)`.
+///
+/// # Panics
+///
+/// Panics if either the `x` or `y` index is out of bounds.
+impl std::ops::Index<(usize, usize)> for DynLife {
+    type Output = Cell;
+
+    #[track_caller]
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Cell {
+        self.get_ref(x, y)
+    }
+}
+
+/// Mutably indexes the [Cells][Cell] of a DynLife by `(x, y)`.
+///
+/// # Panics
+///
+/// Panics if either the `x` or `y` index is out of bounds.
+impl std::ops::IndexMut<(usize, usize)> for DynLife {
+    #[track_caller]
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Cell {
+        self.get_mut(x, y)
+    }
+}
@@ -0,0 +1,208 @@
+//! Output adapters for LED matrices.
+//!
+//! Hardware displays don't take [Cells][Cell] but byte streams in their own order and format. The functions in this module convert a [Life] into the frame formats of common LED matrices, writing into a caller-provided buffer so no allocation is needed on the hot path, and optionally scaling the brightness on the way.
+
+use std::fmt;
+
+use crate::{Cell, CellLike, Life};
+
+/// The error returned when a buffer can't hold the whole frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    /// The number of bytes the frame needs.
+    pub needed: usize,
+    /// The number of bytes the buffer has.
+    pub len: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer of {} bytes can't hold a frame of {} bytes", self.len, self.needed)
+    }
+}
+
+impl std::error::Error for BufferTooSmall {}
+
+/// Returns the product of `factors`, the size of a frame, or [`usize::MAX`] if it overflows, which no buffer can hold.
+#[inline]
+pub(crate) fn frame_len(factors: &[usize]) -> usize {
+    factors.iter().try_fold(1usize, |len, &factor| len.checked_mul(factor)).unwrap_or(usize::MAX)
+}
+
+#[inline]
+pub(crate) const fn check_len(needed: usize, len: usize) -> Result<(), BufferTooSmall> {
+    match needed <= len {
+        true => Ok(()),
+        false => Err(BufferTooSmall { needed, len }),
+    }
+}
+
+/// Scales a color channel by `brightness`, where 255 leaves it unchanged and 0 turns it off.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::embedded::scale_brightness;
+/// assert_eq!(scale_brightness(200, 255), 200);
+/// assert_eq!(scale_brightness(200, 128), 100);
+/// assert_eq!(scale_brightness(200, 0), 0);
+/// ```
+#[inline]
+pub const fn scale_brightness(value: u8, brightness: u8) -> u8 {
+    ((value as u16 * brightness as u16 + 127) / 255) as u8
+}
+
+/// Returns the number of bytes [`write_ws2812`] writes for a board of the given size.
+#[inline]
+pub const fn ws2812_len(height: usize, width: usize) -> usize {
+    height * width * 3
+}
+
+/// Writes the board as a WS2812 (NeoPixel) frame wired in serpentine order and returns the number of bytes written.
+///
+/// Each LED takes three bytes in GRB order. The first row runs from left to right, the second from right to left, and so on, which is how most WS2812 matrices are wired.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::embedded::*;
+///
+/// let life = Life::<2, 2>::from([
+///     [Cell::red(), Cell::green()],
+///     [Cell::blue(), Cell::white()],
+/// ]);
+///
+/// let mut frame = [0; ws2812_len(2, 2)];
+/// write_ws2812(&life, 255, &mut frame).unwrap();
+///
+/// assert_eq!(frame, [0, 255, 0, 255, 0, 0, 255, 255, 255, 0, 0, 255]);
+/// ```
+pub fn write_ws2812<const HEIGHT: usize, const WIDTH: usize>(life: &Life<HEIGHT, WIDTH>, brightness: u8, buffer: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    let len = ws2812_len(HEIGHT, WIDTH);
+    check_len(len, buffer.len())?;
+
+    let mut pixels = buffer[..len].chunks_exact_mut(3);
+
+    for (y, row) in life.cells.iter().enumerate() {
+        let mut write = |cell: &Cell| {
+            if let Some(pixel) = pixels.next() {
+                pixel.copy_from_slice(&[scale_brightness(cell.g, brightness), scale_brightness(cell.r, brightness), scale_brightness(cell.b, brightness)]);
+            }
+        };
+
+        match y % 2 {
+            0 => row.iter().for_each(&mut write),
+            _ => row.iter().rev().for_each(&mut write),
+        }
+    }
+
+    Ok(len)
+}
+
+/// Returns the number of bytes [`write_hub75`] writes for a board of the given size and number of bit planes.
+#[inline]
+pub const fn hub75_len(height: usize, width: usize, planes: u8) -> usize {
+    height.div_ceil(2) * width * planes as usize
+}
+
+/// Writes the board as HUB75 row-pair bit planes and returns the number of bytes written.
+///
+/// A HUB75 panel shows the rows `y` and `y + HEIGHT.div_ceil(2)` at the same time, so each byte holds one column of such a row pair: bits 0 to 2 are the R1, G1 and B1 lines of the upper row and bits 3 to 5 are the R2, G2 and B2 lines of the lower row. The color depth is produced with binary code modulation over `planes` bit planes (at most 8), with the plane of the most significant bit first. Each plane holds all row pairs from top to bottom, and each row pair all columns from left to right.
+///
+/// On a board with an odd height, the lower row of the last pair is blank.
+///
+/// # Panics
+///
+/// Panics if `planes` is greater than 8.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::embedded::*;
+///
+/// let life = Life::<2, 1>::from([
+///     [Cell::red()],
+///     [Cell { r: 0, g: 0, b: 128 }],
+/// ]);
+///
+/// let mut frame = [0; hub75_len(2, 1, 2)];
+/// write_hub75(&life, 255, 2, &mut frame).unwrap();
+///
+/// // bit 7 is set in both red and blue, bit 6 only in red
+/// assert_eq!(frame, [0b100_001, 0b000_001]);
+/// ```
+#[track_caller]
+pub fn write_hub75<const HEIGHT: usize, const WIDTH: usize>(life: &Life<HEIGHT, WIDTH>, brightness: u8, planes: u8, buffer: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    assert!(planes <= 8, "HUB75 frames can't have more than 8 bit planes");
+
+    let len = hub75_len(HEIGHT, WIDTH, planes);
+    check_len(len, buffer.len())?;
+
+    let pairs = HEIGHT.div_ceil(2);
+    let mut bytes = buffer[..len].iter_mut();
+
+    for plane in 0..planes {
+        let bit = 7 - plane;
+        let lines = |cell: &Cell| {
+            [cell.r, cell.g, cell.b].into_iter().enumerate().fold(0, |lines, (i, channel)| lines | ((scale_brightness(channel, brightness) >> bit) & 1) << i)
+        };
+
+        for y in 0..pairs {
+            for x in 0..WIDTH {
+                let upper = lines(&life.cells[y][x]);
+                let lower = life.cells.get(y + pairs).map_or(0, |row| lines(&row[x]));
+
+                if let Some(byte) = bytes.next() {
+                    *byte = upper | lower << 3;
+                }
+            }
+        }
+    }
+
+    Ok(len)
+}
+
+/// Returns the number of bytes [`Life::step_and_fill`] writes for a board of the given size.
+///
+/// Both formats use three bytes per [Cell], so this is the same as [`ws2812_len`].
+#[inline]
+pub const fn rgb_len(height: usize, width: usize) -> usize {
+    ws2812_len(height, width)
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Life<HEIGHT, WIDTH, C> {
+    /// Invokes [`play`][Life::play] with the given closure and writes the resulting [Cells][Cell] into `buffer`, returning the number of bytes written.
+    ///
+    /// The [Cells][Cell] are written row by row as three bytes in RGB order, as returned by [`CellLike::to_rgb`], without allocating, so the buffer can be handed straight to a DMA-driven display. If the buffer is too small, the Life is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use your_game_of_life::*;
+    /// use your_game_of_life::embedded::*;
+    ///
+    /// let mut life = Life::<1, 2>::default();
+    /// let mut frame = [0; rgb_len(1, 2)];
+    ///
+    /// life.step_and_fill(&mut frame, |_, _, x, _| match x {
+    ///     0 => Cell::red(),
+    ///     _ => Cell::blue(),
+    /// }).unwrap();
+    ///
+    /// assert_eq!(frame, [255, 0, 0, 0, 0, 255]);
+    /// ```
+    pub fn step_and_fill(&mut self, buffer: &mut [u8], f: impl FnMut(C, [C; 8], usize, usize) -> C) -> Result<usize, BufferTooSmall> {
+        let len = rgb_len(HEIGHT, WIDTH);
+        check_len(len, buffer.len())?;
+
+        self.play(f);
+
+        for (pixel, cell) in buffer[..len].chunks_exact_mut(3).zip(self.cells.iter().flatten().map(|&cell| cell.to_rgb())) {
+            pixel.copy_from_slice(&[cell.r, cell.g, cell.b]);
+        }
+
+        Ok(len)
+    }
+}
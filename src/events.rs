@@ -0,0 +1,247 @@
+//! Structured per-generation events.
+//!
+//! Instead of looking at the [Cells][Cell] themselves, applications like generative music often only care about what happened in each generation: how many [Cells][Cell] were born and how many died, and where. An [EventStream] steps a [Life] and reports these counts to an [EventSink], both for the whole board and for each region of a grid laid over it.
+//!
+//! With the `osc` feature enabled, the `OscSink` adapter sends the events as Open Sound Control messages over UDP.
+
+use crate::{Cell, Life};
+
+/// The births and deaths in one region of the board during one generation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RegionEvents {
+    /// The column of the region in the region grid.
+    pub column: usize,
+    /// The row of the region in the region grid.
+    pub row: usize,
+    /// The number of [Cells][Cell] that came alive.
+    pub births: u32,
+    /// The number of [Cells][Cell] that died.
+    pub deaths: u32,
+}
+
+/// The births and deaths during one generation.
+///
+/// A birth is a [Cell] that was dead before the generation and is alive after it, and a death is the opposite, as determined by [`Cell::is_alive`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GenerationEvents {
+    /// The number of the generation, starting at 1 for the first step.
+    pub generation: u64,
+    /// The number of [Cells][Cell] that came alive on the whole board.
+    pub births: u32,
+    /// The number of [Cells][Cell] that died on the whole board.
+    pub deaths: u32,
+    /// The events of each region, row by row.
+    pub regions: Vec<RegionEvents>,
+}
+
+impl GenerationEvents {
+    /// Compares two boards and counts the births and deaths, with the board divided into `columns` × `rows` regions.
+    ///
+    /// [Cells][Cell] are alive according to the [`aliveness`][Life#structfield.aliveness] of their board.
+    ///
+    /// Regions are as equal in size as possible. If the board is smaller than the region grid, some regions are empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` or `rows` is 0.
+    #[track_caller]
+    pub fn between<const HEIGHT: usize, const WIDTH: usize>(before: &Life<HEIGHT, WIDTH>, after: &Life<HEIGHT, WIDTH>, generation: u64, columns: usize, rows: usize) -> Self {
+        assert!(columns != 0 && rows != 0, "the region grid must not be empty");
+
+        let mut events = Self {
+            generation,
+            births: 0,
+            deaths: 0,
+            regions: (0..rows).flat_map(|row| (0..columns).map(move |column| RegionEvents {
+                column,
+                row,
+                births: 0,
+                deaths: 0,
+            })).collect(),
+        };
+
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                let (was, is) = (before.aliveness.is_alive(before.cells[y][x]), after.aliveness.is_alive(after.cells[y][x]));
+
+                if was == is {
+                    continue;
+                }
+
+                let region = &mut events.regions[y * rows / HEIGHT * columns + x * columns / WIDTH];
+
+                if is {
+                    events.births += 1;
+                    region.births += 1;
+                }
+                else {
+                    events.deaths += 1;
+                    region.deaths += 1;
+                }
+            }
+        }
+
+        events
+    }
+}
+
+/// A consumer of [GenerationEvents].
+///
+/// Any `FnMut(&GenerationEvents)` closure is an EventSink.
+pub trait EventSink {
+    /// Receives the events of one generation.
+    fn on_generation(&mut self, events: &GenerationEvents);
+}
+
+impl<F: FnMut(&GenerationEvents)> EventSink for F {
+    #[inline]
+    fn on_generation(&mut self, events: &GenerationEvents) {
+        self(events)
+    }
+}
+
+/// Steps a [Life] and reports the [GenerationEvents] of each step.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::events::*;
+///
+/// let mut life = Life::<4, 4>::default();
+/// life.set(0, 0, Cell::alive());
+///
+/// let mut stream = EventStream::new(2, 2);
+/// let mut log = Vec::new();
+///
+/// // every cell swaps between dead and alive
+/// stream.play_for(&mut life, 2, &mut |events: &GenerationEvents| log.push(events.clone()), |this, _, _, _| {
+///     Cell::from(!this.is_alive())
+/// });
+///
+/// assert_eq!((log[0].generation, log[0].births, log[0].deaths), (1, 15, 1));
+/// assert_eq!((log[0].regions[0].births, log[0].regions[0].deaths), (3, 1));
+/// assert_eq!((log[1].generation, log[1].births, log[1].deaths), (2, 1, 15));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventStream {
+    /// The number of the last generation that was played.
+    pub generation: u64,
+    columns: usize,
+    rows: usize,
+}
+
+impl EventStream {
+    /// Creates an EventStream reporting on a grid of `columns` × `rows` regions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` or `rows` is 0.
+    #[track_caller]
+    pub const fn new(columns: usize, rows: usize) -> Self {
+        assert!(columns != 0 && rows != 0, "the region grid must not be empty");
+
+        Self {
+            generation: 0,
+            columns,
+            rows,
+        }
+    }
+
+    /// Invokes [`Life::play`] with the given closure and reports the events to `sink`.
+    pub fn play<const HEIGHT: usize, const WIDTH: usize>(&mut self, life: &mut Life<HEIGHT, WIDTH>, sink: &mut impl EventSink, f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        let before = *life;
+        life.play(f);
+        self.generation += 1;
+
+        sink.on_generation(&GenerationEvents::between(&before, life, self.generation, self.columns, self.rows));
+    }
+
+    /// Invokes [`EventStream::play`] `n` times.
+    #[inline]
+    pub fn play_for<const HEIGHT: usize, const WIDTH: usize>(&mut self, life: &mut Life<HEIGHT, WIDTH>, n: u32, sink: &mut impl EventSink, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        for _ in 0..n {
+            self.play(life, sink, &mut f);
+        }
+    }
+}
+
+/// Encodes an Open Sound Control message whose arguments are all 32-bit integers.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::events::osc_message;
+/// assert_eq!(osc_message("/a", &[1]), [b'/', b'a', 0, 0, b',', b'i', 0, 0, 0, 0, 0, 1]);
+/// ```
+#[cfg(feature = "osc")]
+pub fn osc_message(address: &str, arguments: &[i32]) -> Vec<u8> {
+    fn push_padded(packet: &mut Vec<u8>, string: &[u8]) {
+        packet.extend_from_slice(string);
+        packet.extend(std::iter::repeat_n(0, 4 - string.len() % 4));
+    }
+
+    let mut packet = Vec::new();
+    push_padded(&mut packet, address.as_bytes());
+
+    let mut tags = vec![b','];
+    tags.extend(std::iter::repeat_n(b'i', arguments.len()));
+    push_padded(&mut packet, &tags);
+
+    for argument in arguments {
+        packet.extend_from_slice(&argument.to_be_bytes());
+    }
+
+    packet
+}
+
+/// An [EventSink] sending each generation as Open Sound Control messages over UDP.
+///
+/// Each generation sends one `/life/generation` message with the arguments `generation births deaths`, followed by one `/life/region` message per region with the arguments `column row births deaths`. Values that don't fit in an `i32` are saturated.
+///
+/// Since [`EventSink::on_generation`] can't fail, the first error that occurs is kept and can be retrieved with [`OscSink::take_error`]. No more messages are sent until it is taken.
+#[cfg(feature = "osc")]
+#[derive(Debug)]
+pub struct OscSink {
+    socket: std::net::UdpSocket,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "osc")]
+impl OscSink {
+    /// Creates an OscSink sending to the address the `socket` is connected to.
+    #[inline]
+    pub const fn new(socket: std::net::UdpSocket) -> Self {
+        Self {
+            socket,
+            error: None,
+        }
+    }
+
+    /// Returns the first error that occurred while sending, if any, and resumes sending.
+    #[inline]
+    pub fn take_error(&mut self) -> Option<std::io::Error> {
+        self.error.take()
+    }
+
+    fn send(&mut self, address: &str, arguments: &[i32]) {
+        if self.error.is_none() {
+            if let Err(error) = self.socket.send(&osc_message(address, arguments)) {
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "osc")]
+impl EventSink for OscSink {
+    fn on_generation(&mut self, events: &GenerationEvents) {
+        let saturate = |value: u64| value.min(i32::MAX as u64) as i32;
+
+        self.send("/life/generation", &[saturate(events.generation), saturate(events.births as u64), saturate(events.deaths as u64)]);
+
+        for region in &events.regions {
+            self.send("/life/region", &[saturate(region.column as u64), saturate(region.row as u64), saturate(region.births as u64), saturate(region.deaths as u64)]);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Entry points for fuzzing the parsers and engines.
+//!
+//! Every function takes arbitrary bytes, like the ones a fuzzer generates, and never panics unless it found a bug: it either rejects the bytes with an error internally or checks that what it read round-trips and steps the same way on every engine. Boards are read as a [Life] of [SIZE] × [SIZE] [Cells][Cell], so no input can make them allocate much memory.
+//!
+//! To fuzz with `cargo fuzz`, call one function per target:
+//!
+//! ```ignore
+//! #![no_main]
+//!
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| your_game_of_life::fuzz::fuzz_rle(data));
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use your_game_of_life::*;
+//! use your_game_of_life::fuzz::*;
+//!
+//! fuzz_rle(b"x = 3, y = 3\nbob$2bo$3o!");
+//! fuzz_plaintext(b".O\nOO\n");
+//! fuzz_snapshot(&Life::<4, 4>::from([[true; 4]; 4]).to_snapshot());
+//! fuzz_diff(&[1, 0, 255, 0, 0]);
+//! fuzz_rule(b"B36/S23");
+//! fuzz_step_roundtrip(&[1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 12, 0, 4, 255, 255, 255, 0, 0, 0, 9, 9, 9]);
+//!
+//! // invalid input is rejected without panicking
+//! fuzz_rle(&[0xff, 0xfe]);
+//! fuzz_snapshot(b"YGLS");
+//! fuzz_diff(&[0x80]);
+//! ```
+
+use std::str;
+
+use crate::history::{decode_diff, encode_diff};
+use crate::rules::{self, ColorPolicy};
+use crate::snapshot;
+use crate::{Aliveness, BitLife, BoundaryMode, Cell, DynLife, Life, Rule};
+
+/// The width and height of the boards read by the entry points.
+pub const SIZE: usize = 16;
+
+/// Reads `data` as an RLE pattern and checks that writing and reading it again gives the same board.
+pub fn fuzz_rle(data: &[u8]) {
+    let Ok(text) = str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(life) = Life::<SIZE, SIZE>::from_rle(text) {
+        assert_eq!(Life::from_rle(&life.to_rle()), Ok(life), "the RLE pattern doesn't round-trip");
+    }
+}
+
+/// Reads `data` as a plaintext pattern and checks that writing and reading it again gives the same board.
+pub fn fuzz_plaintext(data: &[u8]) {
+    let Ok(text) = str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(life) = Life::<SIZE, SIZE>::from_plaintext(text) {
+        assert_eq!(Life::from_plaintext(&life.to_plaintext()), Ok(life), "the plaintext pattern doesn't round-trip");
+    }
+}
+
+/// Restores `data` as a snapshot and checks that it round-trips, migrates, and restores as the same [DynLife].
+pub fn fuzz_snapshot(data: &[u8]) {
+    let dynamic = DynLife::from_snapshot(data);
+
+    if let Ok(dynamic) = &dynamic {
+        assert_eq!(DynLife::from_snapshot(&dynamic.to_snapshot()).as_ref(), Ok(dynamic), "the snapshot doesn't round-trip");
+
+        let migrated = snapshot::migrate(data).expect("a restorable snapshot can't be migrated");
+        assert_eq!(DynLife::from_snapshot(&migrated).as_ref(), Ok(dynamic), "the migrated snapshot differs");
+    }
+
+    if let Ok(life) = Life::<SIZE, SIZE>::from_snapshot(data) {
+        assert_eq!(Life::from_snapshot(&life.to_snapshot()), Ok(life), "the snapshot doesn't round-trip");
+        assert_eq!(dynamic, Ok(DynLife::from(life)), "the snapshot restores differently as a DynLife");
+    }
+}
+
+/// Decodes `data` as the changes of a [history] diff and applies them, checking that encoding them again gives the same changes.
+///
+/// [history]: crate::history
+pub fn fuzz_diff(data: &[u8]) {
+    let Ok(changes) = decode_diff(data, SIZE * SIZE) else {
+        return;
+    };
+
+    assert!(changes.windows(2).all(|pair| pair[0].0 < pair[1].0), "the indices of the changes don't increase");
+    assert_eq!(decode_diff(&encode_diff(&changes), SIZE * SIZE).ok().as_ref(), Some(&changes), "the diff doesn't round-trip");
+
+    let mut life = Life::<SIZE, SIZE>::default();
+    for &(i, cell) in &changes {
+        life.cells.as_flattened_mut()[i] = cell;
+    }
+}
+
+/// Parses `data` as a rulestring and checks that it round-trips and compiles into a closure that agrees with [`Rule::apply`] for every number of neighbors.
+pub fn fuzz_rule(data: &[u8]) {
+    let Ok(Ok(rule)) = str::from_utf8(data).map(str::parse::<Rule>) else {
+        return;
+    };
+
+    assert_eq!(rule.to_string().parse(), Ok(rule), "the rulestring doesn't round-trip");
+
+    let birth: Vec<u8> = (0..=8).filter(|&neighbors| rule.is_born(neighbors)).collect();
+    let survival: Vec<u8> = (0..=8).filter(|&neighbors| rule.survives(neighbors)).collect();
+    let compiled = rules::totalistic(&birth, &survival, ColorPolicy::White);
+
+    for this in [Cell::dead(), Cell::red()] {
+        for neighbors in 0..=8 {
+            let mut others = [Cell::dead(); 8];
+            others[..neighbors].fill(Cell::alive());
+
+            assert_eq!(compiled(this, others, 0, 0), rule.apply(this, others), "the compiled rule differs for {neighbors} neighbors");
+        }
+    }
+}
+
+/// Builds a board from `data`, saves and restores it, and checks that every copy steps the same way.
+///
+/// The bytes are read as the [BoundaryMode] and the [Aliveness] in the snapshot format, the birth and survival counts of a [Rule] as little-endian [u16] bitmasks, the number of generations, and the RGB bytes of the [Cells][Cell] row by row. Missing bytes are zero and invalid settings are replaced by their defaults.
+///
+/// The original board is compared with its snapshot restored as a [Life] and as a [DynLife], a copy read back from RLE with the same settings if its [Cells][Cell] are only [`Cell::alive`] and [`Cell::dead`], and a [BitLife] by aliveness. The BitLife is skipped under [`Aliveness::Brightness(255)`][Aliveness::Brightness], where no [Cell] can be alive, so a Life can't follow the living bits of a rule like `B0`.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::fuzz::*;
+/// // Brightness(255) and B0
+/// fuzz_step_roundtrip(&[0, 0, 0, 0, 2, 255, 0, 0, 1, 0, 0, 0, 1]);
+/// ```
+pub fn fuzz_step_roundtrip(data: &[u8]) {
+    let mut header = [0; 13];
+    let (start, cells) = data.split_at(data.len().min(header.len()));
+    header[..start.len()].copy_from_slice(start);
+
+    let counts = |mask: u16| -> Vec<u8> { (0..=8).filter(|&count| mask >> count & 1 == 1).collect() };
+    let rule = Rule::new(&counts(u16::from_le_bytes([header[8], header[9]])), &counts(u16::from_le_bytes([header[10], header[11]])));
+    let generations = header[12] % 8;
+
+    let mut life = Life::<SIZE, SIZE> {
+        boundary: BoundaryMode::from_bytes(header[..4].try_into().unwrap()).unwrap_or_default(),
+        aliveness: Aliveness::from_bytes(header[4..8].try_into().unwrap()).unwrap_or_default(),
+        ..Life::default()
+    };
+    for (cell, rgb) in life.cells.as_flattened_mut().iter_mut().zip(cells.chunks_exact(3)) {
+        *cell = Cell::from([rgb[0], rgb[1], rgb[2]]);
+    }
+
+    let bytes = life.to_snapshot();
+    let mut restored = Life::<SIZE, SIZE>::from_snapshot(&bytes).expect("the snapshot can't be restored");
+    let mut dynamic = DynLife::from_snapshot(&bytes).expect("the snapshot can't be restored as a DynLife");
+    let mut bits = BitLife::from(&life);
+    assert_eq!(restored, life, "the snapshot doesn't round-trip");
+
+    let representable = life.aliveness != Aliveness::Brightness(u8::MAX);
+    let plain = life.iter().all(|&cell| cell == Cell::alive() || cell == Cell::dead()) && life.aliveness == Aliveness::NonBlack;
+    let mut from_rle = Life::<SIZE, SIZE> {
+        boundary: life.boundary,
+        ..Life::from_rle(&life.to_rle()).expect("the RLE pattern can't be read")
+    };
+
+    for _ in 0..generations {
+        life.play_rule(&rule);
+        restored.play_rule(&rule);
+        dynamic.play_rule(&rule);
+        bits.play_rule(&rule);
+        from_rle.play_rule(&rule);
+    }
+
+    assert_eq!(restored, life, "the restored Life steps differently");
+    assert_eq!(dynamic, DynLife::from(life), "the restored DynLife steps differently");
+    assert!(!representable || bits == BitLife::from(&life), "the BitLife steps differently");
+    assert!(!plain || from_rle == life, "the Life read back from RLE steps differently");
+
+    // play_rule steps through the normalized cells passed to closures, which must agree with the aliveness of the board
+    let mut expected = life;
+    for (x, y, cell) in expected.enumerate_cells_mut() {
+        *cell = rule.apply_by(life.cells[y][x], life.neighbors(x, y), life.aliveness);
+    }
+    life.play_rule(&rule);
+    assert_eq!(life, expected, "play_rule differs from Rule::apply_by");
+}
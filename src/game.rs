@@ -0,0 +1,470 @@
+//! Building blocks for competitive games played on a [`Life`].
+//!
+//! Games built on this crate usually let each player place a limited number of [Cells][crate::Cell] before the board is left to evolve. The helpers in this module implement those shared rules so that a server and its clients can validate moves with exactly the same logic.
+//!
+//! To find out which bot or rule is strongest, [`tournament`] plays many matches between them and reports their win rates and ratings.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{Life, Point};
+
+/// One of the two players of a game, each owning one half of the board.
+///
+/// The board is split along its columns: the [`Left`][Player::Left] player owns the columns `0..WIDTH / 2` and the [`Right`][Player::Right] player owns the columns `WIDTH - WIDTH / 2..WIDTH`. On a board with an odd width, the middle column belongs to neither player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Player {
+    /// The player owning the left half of the board.
+    Left,
+    /// The player owning the right half of the board.
+    Right,
+}
+
+impl Player {
+    /// Returns the other player.
+    #[inline]
+    pub const fn opponent(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Returns true if the column `x` of a board `width` cells wide belongs to this player.
+    #[inline]
+    pub const fn owns(self, x: usize, width: usize) -> bool {
+        match self {
+            Self::Left => x < width / 2,
+            Self::Right => x >= width - width / 2 && x < width,
+        }
+    }
+}
+
+/// The placement constraints checked by [`validate_move`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MoveRules {
+    /// The maximum number of [Cells][crate::Cell] a player may place in one move.
+    pub max_cells: usize,
+    /// Whether a player may only place [Cells][crate::Cell] on their own half of the board.
+    pub own_half_only: bool,
+    /// Whether a player may place a [Cell][crate::Cell] where a living [Cell][crate::Cell] already is.
+    pub allow_on_live: bool,
+}
+
+impl MoveRules {
+    /// Creates rules allowing at most `max_cells` placements per move, on the player's own half only and never on living [Cells][crate::Cell].
+    #[inline]
+    pub const fn new(max_cells: usize) -> Self {
+        Self {
+            max_cells,
+            own_half_only: true,
+            allow_on_live: false,
+        }
+    }
+}
+
+/// A single broken constraint reported by [`validate_move`].
+///
+/// The `index` fields refer to the position of the offending placement in the `cells` slice given to [`validate_move`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Violation {
+    /// The move places more [Cells][crate::Cell] than [`MoveRules::max_cells`] allows.
+    TooManyCells {
+        /// The number of placements in the move.
+        placed: usize,
+        /// The maximum number of placements allowed.
+        max: usize,
+    },
+    /// The placement lies outside of the board.
+    OutOfBounds {
+        /// The index of the placement.
+        index: usize,
+        /// The position of the placement.
+        point: Point,
+    },
+    /// The placement lies outside of the player's own half.
+    OutsideOwnHalf {
+        /// The index of the placement.
+        index: usize,
+        /// The position of the placement.
+        point: Point,
+    },
+    /// The placement lies on a living [Cell][crate::Cell].
+    OnLiveCell {
+        /// The index of the placement.
+        index: usize,
+        /// The position of the placement.
+        point: Point,
+    },
+    /// The placement was already made earlier in the same move.
+    Duplicate {
+        /// The index of the placement.
+        index: usize,
+        /// The position of the placement.
+        point: Point,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::TooManyCells { placed, max } => write!(f, "placed {placed} cells but at most {max} are allowed"),
+            Self::OutOfBounds { index, point } => write!(f, "placement {index} at {point} is out of bounds"),
+            Self::OutsideOwnHalf { index, point } => write!(f, "placement {index} at {point} is outside of the player's half"),
+            Self::OnLiveCell { index, point } => write!(f, "placement {index} at {point} is on a living cell"),
+            Self::Duplicate { index, point } => write!(f, "placement {index} at {point} was already made"),
+        }
+    }
+}
+
+impl std::error::Error for Violation {}
+
+/// Checks whether `player` may place [Cells][crate::Cell] at the given [Points][Point] on `board`.
+///
+/// Every broken constraint is reported, so a client can highlight all offending placements at once. A [Cell][crate::Cell] is considered living according to the [`aliveness`][Life#structfield.aliveness] of the board.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::game::*;
+///
+/// let mut board = Life::<4, 4>::default();
+/// board.set(1, 1, Cell::alive());
+///
+/// let rules = MoveRules::new(2);
+///
+/// assert_eq!(validate_move(&board, Player::Left, &[Point::new(0, 0), Point::new(1, 2)], &rules), Ok(()));
+/// assert_eq!(
+///     validate_move(&board, Player::Left, &[Point::new(1, 1), Point::new(3, 0)], &rules),
+///     Err(vec![
+///         Violation::OnLiveCell { index: 0, point: Point::new(1, 1) },
+///         Violation::OutsideOwnHalf { index: 1, point: Point::new(3, 0) },
+///     ]),
+/// );
+/// ```
+pub fn validate_move<const HEIGHT: usize, const WIDTH: usize>(board: &Life<HEIGHT, WIDTH>, player: Player, cells: &[Point], rules: &MoveRules) -> Result<(), Vec<Violation>> {
+    let mut violations = Vec::new();
+    let mut placed = HashSet::with_capacity(cells.len());
+
+    if cells.len() > rules.max_cells {
+        violations.push(Violation::TooManyCells {
+            placed: cells.len(),
+            max: rules.max_cells,
+        });
+    }
+
+    for (index, &point) in cells.iter().enumerate() {
+        if point.x >= WIDTH || point.y >= HEIGHT {
+            violations.push(Violation::OutOfBounds { index, point });
+            continue;
+        }
+        if !placed.insert(point) {
+            violations.push(Violation::Duplicate { index, point });
+            continue;
+        }
+        if rules.own_half_only && !player.owns(point.x, WIDTH) {
+            violations.push(Violation::OutsideOwnHalf { index, point });
+        }
+        if !rules.allow_on_live && board.aliveness.is_alive(*board.get_ref(point.x, point.y)) {
+            violations.push(Violation::OnLiveCell { index, point });
+        }
+    }
+
+    match violations.is_empty() {
+        true => Ok(()),
+        false => Err(violations),
+    }
+}
+
+/// The result of a single match, seen from the first of the two participants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Outcome {
+    /// The first participant won.
+    FirstWins,
+    /// The second participant won.
+    SecondWins,
+    /// Neither participant won.
+    Draw,
+}
+
+/// The record of one participant after a [`tournament`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Standing {
+    /// The index of the participant in the slice given to [`tournament`].
+    pub participant: usize,
+    /// The number of matches won.
+    pub wins: u32,
+    /// The number of matches lost.
+    pub losses: u32,
+    /// The number of matches drawn.
+    pub draws: u32,
+    /// The ELO rating after all matches, starting from [`INITIAL_RATING`].
+    #[cfg(feature = "float")]
+    pub rating: f64,
+    /// The ELO rating after all matches rounded to whole points, starting from [`INITIAL_RATING`].
+    ///
+    /// This is computed with integers only. The rating is tracked in thousandths of a point and only rounded at the end, so it stays within a point of the floating-point `rating` of the `float` feature instead of ignoring the small changes of close matches.
+    pub rating_points: i32,
+}
+
+/// The ELO rating every participant starts a [`tournament`] with.
+pub const INITIAL_RATING: f64 = 1500.0;
+
+/// How strongly a single match moves the ELO ratings in a [`tournament`].
+pub const RATING_K_FACTOR: f64 = 32.0;
+
+/// [`INITIAL_RATING`] in thousandths of a point.
+const INITIAL_MILLIPOINTS: i64 = INITIAL_RATING as i64 * 1000;
+
+/// [`RATING_K_FACTOR`] as an integer.
+const K_FACTOR: i64 = RATING_K_FACTOR as i64;
+
+/// The expected score in permille of the stronger participant, for rating differences in steps of 25.
+const EXPECTED_PERMILLE: [i32; 33] = [500, 536, 571, 606, 640, 673, 703, 733, 760, 785, 808, 830, 849, 867, 882, 896, 909, 920, 930, 939, 947, 954, 960, 965, 969, 973, 977, 980, 983, 985, 987, 989, 990];
+
+/// Returns the expected score in permille of a participant rated `difference` points above their opponent.
+///
+/// This interpolates the ELO curve `1000 / (1 + 10^(-difference / 400))` from a table, so no floating point is needed.
+const fn expected_permille(difference: i32) -> i32 {
+    let distance = match difference.unsigned_abs() {
+        distance if distance >= 800 => 800,
+        distance => distance as i32,
+    };
+    let (step, rest) = ((distance / 25) as usize, distance % 25);
+    let expected = match step {
+        32 => EXPECTED_PERMILLE[32],
+        _ => EXPECTED_PERMILLE[step] + (EXPECTED_PERMILLE[step + 1] - EXPECTED_PERMILLE[step]) * rest / 25,
+    };
+
+    match difference < 0 {
+        true => 1000 - expected,
+        false => expected,
+    }
+}
+
+impl Standing {
+    /// Returns the number of matches played.
+    #[inline]
+    pub const fn games(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    /// Returns the share of points scored in permille, where a win is worth 1 point and a draw is worth half a point.
+    ///
+    /// Returns 0 if no matches were played.
+    pub const fn win_rate_permille(&self) -> u32 {
+        match self.games() {
+            0 => 0,
+            games => ((2 * self.wins + self.draws) as u64 * 1000 / (2 * games as u64)) as u32,
+        }
+    }
+
+    /// Returns the 95% Wilson score interval of the [`win_rate_permille`][Standing::win_rate_permille] as `(low, high)`, computed with integers only.
+    ///
+    /// Returns `(0, 1000)` if no matches were played.
+    pub fn confidence_interval_permille(&self) -> (u32, u32) {
+        // 1.96² in ten-thousandths
+        const Q: u128 = 38416;
+
+        if self.games() == 0 {
+            return (0, 1000);
+        }
+
+        // twice the points and twice the games, so draws stay integers
+        let (a, m) = ((2 * self.wins + self.draws) as u128, 2 * self.games() as u128);
+        let denominator = 10000 * m + 2 * Q;
+        let center = 1000 * (10000 * a + Q);
+        let margin = (Q * (20000 * a * (m - a) + Q * m) * 1_000_000 / m).isqrt();
+        let round = |numerator: u128| ((numerator + denominator / 2) / denominator) as u32;
+
+        (round(center.saturating_sub(margin)), match round(center + margin) {
+            high if high > 1000 => 1000,
+            high => high,
+        })
+    }
+
+    /// Returns the share of points scored, where a win is worth 1 point and a draw is worth half a point.
+    ///
+    /// Returns 0 if no matches were played.
+    #[cfg(feature = "float")]
+    pub fn win_rate(&self) -> f64 {
+        match self.games() {
+            0 => 0.0,
+            games => (self.wins as f64 + self.draws as f64 / 2.0) / games as f64,
+        }
+    }
+
+    /// Returns the 95% Wilson score interval of the [`win_rate`][Standing::win_rate] as `(low, high)`.
+    ///
+    /// Returns `(0.0, 1.0)` if no matches were played.
+    #[cfg(feature = "float")]
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        const Z: f64 = 1.96;
+
+        let n = self.games() as f64;
+
+        if n == 0.0 {
+            return (0.0, 1.0);
+        }
+
+        let p = self.win_rate();
+        let denominator = 1.0 + Z * Z / n;
+        let center = (p + Z * Z / (2.0 * n)) / denominator;
+        let margin = Z * (p * (1.0 - p) / n + Z * Z / (4.0 * n * n)).sqrt() / denominator;
+
+        ((center - margin).max(0.0), (center + margin).min(1.0))
+    }
+}
+
+/// The results of a [`tournament`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TournamentReport {
+    /// One [Standing] per participant, in the same order as the participants.
+    pub standings: Vec<Standing>,
+    /// The number of matches played in total.
+    pub matches: usize,
+}
+
+impl TournamentReport {
+    /// Returns the [Standings][Standing] sorted from the highest to the lowest [`rating_points`][Standing#structfield.rating_points].
+    pub fn ranking(&self) -> Vec<Standing> {
+        let mut ranking = self.standings.clone();
+        ranking.sort_by_key(|standing| std::cmp::Reverse(standing.rating_points));
+        ranking
+    }
+}
+
+/// Plays every participant against every other participant and reports win rates and ELO ratings.
+///
+/// For each of the `n_rounds` rounds and each seed in `seeds`, every ordered pair of distinct participants plays one match by invoking `play_match(first, second, seed)`, so both participants of a pair get to play either side. Matches run in parallel on all available cores, but the ratings are updated in the fixed schedule order, so the report only depends on the outcomes.
+///
+/// Participants can be anything the closure knows how to pit against each other, like bots placing [Cells][crate::Cell] or competing rules in a multi-team Life.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::game::*;
+///
+/// // participants with a higher strength always win
+/// let report = tournament(&[3, 1, 2], 2, &[0, 1], |a, b, _seed| {
+///     match a.cmp(b) {
+///         std::cmp::Ordering::Greater => Outcome::FirstWins,
+///         std::cmp::Ordering::Less => Outcome::SecondWins,
+///         std::cmp::Ordering::Equal => Outcome::Draw,
+///     }
+/// });
+///
+/// assert_eq!(report.matches, 24);
+/// assert_eq!(report.ranking()[0].participant, 0);
+/// assert_eq!(report.standings[0].win_rate_permille(), 1000);
+/// assert_eq!(report.standings[1].confidence_interval_permille(), (0, 194));
+/// assert_eq!(report.standings.iter().map(|standing| standing.rating_points).collect::<Vec<_>>(), [1669, 1329, 1502]);
+///
+/// // one win followed by draws, whose changes of less than a point each add up
+/// let report = tournament(&[0, 1], 1, &[0, 1, 2, 3, 4, 5, 6, 7], |&first, _, seed| match (first, seed) {
+///     (0, 0) => Outcome::FirstWins,
+///     _ => Outcome::Draw,
+/// });
+/// assert_eq!((report.standings[0].rating_points, report.standings[1].rating_points), (1504, 1496));
+/// ```
+pub fn tournament<P, F>(participants: &[P], n_rounds: u32, seeds: &[u64], play_match: F) -> TournamentReport
+where P: Sync,
+F: Fn(&P, &P, u64) -> Outcome + Sync {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut schedule = Vec::new();
+
+    for _ in 0..n_rounds {
+        for &seed in seeds {
+            for first in 0..participants.len() {
+                for second in (0..participants.len()).filter(|&second| second != first) {
+                    schedule.push((first, second, seed));
+                }
+            }
+        }
+    }
+
+    let next = AtomicUsize::new(0);
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get()).min(schedule.len().max(1));
+    let mut outcomes = vec![Outcome::Draw; schedule.len()];
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads).map(|_| scope.spawn(|| {
+            let mut played = Vec::new();
+
+            loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+
+                let Some(&(first, second, seed)) = schedule.get(index) else {
+                    break played;
+                };
+
+                played.push((index, play_match(&participants[first], &participants[second], seed)));
+            }
+        })).collect();
+
+        for worker in workers {
+            for (index, outcome) in worker.join().unwrap() {
+                outcomes[index] = outcome;
+            }
+        }
+    });
+
+    let mut standings: Vec<_> = (0..participants.len()).map(|participant| Standing {
+        participant,
+        wins: 0,
+        losses: 0,
+        draws: 0,
+        #[cfg(feature = "float")]
+        rating: INITIAL_RATING,
+        rating_points: 0,
+    }).collect();
+    let mut millipoints = vec![INITIAL_MILLIPOINTS; participants.len()];
+
+    for (&(first, second, _), outcome) in schedule.iter().zip(outcomes) {
+        let score = match outcome {
+            Outcome::FirstWins => {
+                standings[first].wins += 1;
+                standings[second].losses += 1;
+                1000
+            }
+            Outcome::SecondWins => {
+                standings[first].losses += 1;
+                standings[second].wins += 1;
+                0
+            }
+            Outcome::Draw => {
+                standings[first].draws += 1;
+                standings[second].draws += 1;
+                500
+            }
+        };
+
+        // beyond 800 points, the expected score doesn't change anymore
+        let difference = ((millipoints[first] - millipoints[second]) / 1000).clamp(-800, 800) as i32;
+        let change = K_FACTOR * (score - expected_permille(difference)) as i64;
+
+        millipoints[first] += change;
+        millipoints[second] -= change;
+
+        #[cfg(feature = "float")]
+        {
+            let expected = 1.0 / (1.0 + 10f64.powf((standings[second].rating - standings[first].rating) / 400.0));
+            let change = RATING_K_FACTOR * (score as f64 / 1000.0 - expected);
+
+            standings[first].rating += change;
+            standings[second].rating -= change;
+        }
+    }
+
+    for (standing, millipoints) in standings.iter_mut().zip(millipoints) {
+        // rounding half up
+        standing.rating_points = (millipoints + 500).div_euclid(1000) as i32;
+    }
+
+    TournamentReport {
+        standings,
+        matches: schedule.len(),
+    }
+}
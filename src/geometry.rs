@@ -0,0 +1,226 @@
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+
+/// The position of a [Cell][crate::Cell] on a board, with `x` counting columns from the left and `y` counting rows from the top.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let point = Point::new(2, 1);
+///
+/// assert_eq!(point.checked_add(Offset::new(-2, 1)), Some(Point::new(0, 2)));
+/// assert_eq!(point.checked_add(Offset::new(-3, 0)), None);
+/// assert_eq!(Point::new(5, 3) - point, Offset::new(3, 2));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    /// The column.
+    pub x: usize,
+    /// The row.
+    pub y: usize,
+}
+
+impl Point {
+    /// The top left corner of a board.
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    /// Creates a Point at column `x` and row `y`.
+    #[inline]
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the Point moved by `offset`, or `None` if it would be left of or above the board.
+    #[inline]
+    pub const fn checked_add(self, offset: Offset) -> Option<Self> {
+        match (self.x.checked_add_signed(offset.dx), self.y.checked_add_signed(offset.dy)) {
+            (Some(x), Some(y)) => Some(Self::new(x, y)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    #[inline]
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    #[inline]
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Offset;
+
+    /// Returns the [Offset] from `other` to this Point.
+    #[inline]
+    fn sub(self, other: Self) -> Offset {
+        Offset::new(self.x as isize - other.x as isize, self.y as isize - other.y as isize)
+    }
+}
+
+/// A distance between two [Points][Point], with `dx` counting columns to the right and `dy` counting rows down.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Offset {
+    /// The number of columns to the right, or to the left if negative.
+    pub dx: isize,
+    /// The number of rows down, or up if negative.
+    pub dy: isize,
+}
+
+impl Offset {
+    /// Creates an Offset of `dx` columns and `dy` rows.
+    #[inline]
+    pub const fn new(dx: isize, dy: isize) -> Self {
+        Self { dx, dy }
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:+}, {:+})", self.dx, self.dy)
+    }
+}
+
+impl From<(isize, isize)> for Offset {
+    #[inline]
+    fn from((dx, dy): (isize, isize)) -> Self {
+        Self::new(dx, dy)
+    }
+}
+
+impl From<Offset> for (isize, isize) {
+    #[inline]
+    fn from(offset: Offset) -> Self {
+        (offset.dx, offset.dy)
+    }
+}
+
+impl Add for Offset {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.dx, -self.dy)
+    }
+}
+
+/// A rectangular area of a board, reaching from its top left corner `(x, y)` to `(x + width, y + height)`, exclusive.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let rect = Rect::new(1, 1, 3, 2);
+///
+/// assert!(rect.contains(Point::new(3, 2)));
+/// assert!(!rect.contains(Point::new(4, 2)));
+/// assert_eq!(rect.intersection(Rect::new(0, 2, 2, 5)), Some(Rect::new(1, 2, 1, 1)));
+/// assert_eq!(rect.points().count(), rect.area());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rect {
+    /// The column of the left edge.
+    pub x: usize,
+    /// The row of the top edge.
+    pub y: usize,
+    /// The number of columns.
+    pub width: usize,
+    /// The number of rows.
+    pub height: usize,
+}
+
+impl Rect {
+    /// Creates a Rect with its top left corner at `(x, y)`.
+    #[inline]
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Creates a Rect covering a whole board of `width` × `height` [Cells][crate::Cell].
+    #[inline]
+    pub const fn of_size(width: usize, height: usize) -> Self {
+        Self::new(0, 0, width, height)
+    }
+
+    /// Returns the top left corner.
+    #[inline]
+    pub const fn top_left(self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    /// Returns the column right of the right edge.
+    #[inline]
+    pub const fn right(self) -> usize {
+        self.x + self.width
+    }
+
+    /// Returns the row below the bottom edge.
+    #[inline]
+    pub const fn bottom(self) -> usize {
+        self.y + self.height
+    }
+
+    /// Returns the number of [Cells][crate::Cell] in the Rect.
+    #[inline]
+    pub const fn area(self) -> usize {
+        self.width * self.height
+    }
+
+    /// Returns true if the Rect contains no [Cells][crate::Cell].
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns true if the Rect contains `point`.
+    #[inline]
+    pub const fn contains(self, point: Point) -> bool {
+        point.x >= self.x && point.x < self.right() && point.y >= self.y && point.y < self.bottom()
+    }
+
+    /// Returns the area covered by both Rects, or `None` if they don't overlap.
+    pub const fn intersection(self, other: Self) -> Option<Self> {
+        let x = if self.x > other.x { self.x } else { other.x };
+        let y = if self.y > other.y { self.y } else { other.y };
+        let right = if self.right() < other.right() { self.right() } else { other.right() };
+        let bottom = if self.bottom() < other.bottom() { self.bottom() } else { other.bottom() };
+
+        match x < right && y < bottom {
+            true => Some(Self::new(x, y, right - x, bottom - y)),
+            false => None,
+        }
+    }
+
+    /// Returns an iterator over the [Points][Point] in the Rect, row by row.
+    pub fn points(self) -> impl Iterator<Item = Point> {
+        (self.y..self.bottom()).flat_map(move |y| (self.x..self.right()).map(move |x| Point::new(x, y)))
+    }
+}
+
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{} at {}", self.width, self.height, self.top_left())
+    }
+}
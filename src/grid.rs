@@ -0,0 +1,147 @@
+use crate::{Cell, CellLike, DynLife, Life, Point, Rect, SmallPattern};
+
+/// Read access to any rectangular board of [Cells][Cell], like a [Life], a [DynLife] or a [SmallPattern].
+///
+/// APIs taking a pattern, like [`Life::stamp`], accept any Grid, so patterns can be combined regardless of how they're stored.
+pub trait Grid {
+    /// Returns the number of columns.
+    fn width(&self) -> usize;
+
+    /// Returns the number of rows.
+    fn height(&self) -> usize;
+
+    /// Returns the [Cell] at `point`.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `point` is out of bounds.
+    fn cell(&self, point: Point) -> Cell;
+
+    /// Returns true if the [Cell] at `point` is alive.
+    ///
+    /// By default, this is [`Cell::is_alive`]. A [Life] and a [DynLife] follow their [`aliveness`][Life#structfield.aliveness] instead.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `point` is out of bounds.
+    #[inline]
+    fn is_alive_at(&self, point: Point) -> bool {
+        self.cell(point).is_alive()
+    }
+
+    /// Returns the smallest [Rect] containing all living [Cells][Cell] according to [`is_alive_at`][Grid::is_alive_at], or `None` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<3, 4>::from([
+    ///     [false, false, false, false],
+    ///     [false, true, false, false],
+    ///     [false, false, true, false],
+    /// ]);
+    ///
+    /// assert_eq!(life.bounding_box(), Some(Rect::new(1, 1, 2, 2)));
+    /// assert_eq!(Life::<3, 4>::default().bounding_box(), None);
+    ///
+    /// // on a white board, the black cells are the living ones
+    /// let mut life = Life::<3, 4>::from([[Cell::white(); 4]; 3]);
+    /// life.aliveness = Aliveness::Background(Cell::white());
+    /// life.set(2, 1, Cell::black());
+    ///
+    /// assert_eq!(life.bounding_box(), Some(Rect::new(2, 1, 1, 1)));
+    /// ```
+    fn bounding_box(&self) -> Option<Rect> {
+        let mut alive = Rect::of_size(self.width(), self.height()).points().filter(|&point| self.is_alive_at(point));
+        let first = alive.next()?;
+
+        let (min_x, max_x, max_y) = alive.fold((first.x, first.x, first.y), |(min_x, max_x, max_y), point| (min_x.min(point.x), max_x.max(point.x), max_y.max(point.y)));
+
+        Some(Rect::new(min_x, first.y, max_x + 1 - min_x, max_y + 1 - first.y))
+    }
+}
+
+/// The [Cells][Cell] of a Life of another [CellLike] type are the ones returned by [`CellLike::to_rgb`].
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Grid for Life<HEIGHT, WIDTH, C> {
+    #[inline]
+    fn width(&self) -> usize {
+        WIDTH
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        HEIGHT
+    }
+
+    #[inline]
+    fn cell(&self, point: Point) -> Cell {
+        self.cells[point.y][point.x].to_rgb()
+    }
+
+    #[inline]
+    fn is_alive_at(&self, point: Point) -> bool {
+        self.cells[point.y][point.x].is_alive_by(self.aliveness)
+    }
+}
+
+impl Grid for DynLife {
+    #[inline]
+    fn width(&self) -> usize {
+        DynLife::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        DynLife::height(self)
+    }
+
+    #[inline]
+    fn cell(&self, point: Point) -> Cell {
+        self.get(point.x, point.y)
+    }
+
+    #[inline]
+    fn is_alive_at(&self, point: Point) -> bool {
+        self.aliveness.is_alive(self.get(point.x, point.y))
+    }
+}
+
+/// Living [Cells][Cell] are [`Cell::alive`] and dead ones [`Cell::dead`].
+impl Grid for SmallPattern {
+    #[inline]
+    fn width(&self) -> usize {
+        SmallPattern::width(*self)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        SmallPattern::height(*self)
+    }
+
+    #[inline]
+    fn cell(&self, point: Point) -> Cell {
+        Cell::from(self.get(point.x, point.y))
+    }
+}
+
+impl<T: Grid + ?Sized> Grid for &T {
+    #[inline]
+    fn width(&self) -> usize {
+        (**self).width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        (**self).height()
+    }
+
+    #[inline]
+    fn cell(&self, point: Point) -> Cell {
+        (**self).cell(point)
+    }
+
+    #[inline]
+    fn is_alive_at(&self, point: Point) -> bool {
+        (**self).is_alive_at(point)
+    }
+}
@@ -0,0 +1,923 @@
+//! Recording runs and coming back to them later.
+//!
+//! A [Recorder] keeps every generation of a [Life] it records, so a run can be replayed or exported afterwards. Only every few generations are stored as whole boards, called keyframes, while the generations in between only store the [Cells][Cell] that changed, so long runs take little memory and any generation is restored from the keyframe before it. Interesting moments can be [bookmarked][Recorder::bookmark] with a label while the run goes on, and looked up by it later.
+//!
+//! # Examples
+//!
+//! ```
+//! use your_game_of_life::*;
+//! use your_game_of_life::history::Recorder;
+//!
+//! let mut life = Life::<8, 8>::default();
+//! for (x, y) in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+//!     life.set(x, y, Cell::alive());
+//! }
+//!
+//! let mut recorder = Recorder::new();
+//! recorder.play_for(&mut life, 4, |this, others, _, _| Rule::CONWAY.apply(this, others));
+//! recorder.bookmark("glider moved once");
+//!
+//! assert_eq!(recorder.len(), 5);
+//! assert_eq!(recorder.labeled("glider moved once"), Some(life));
+//! assert_eq!(recorder.bookmarks()[0].generation, 4);
+//!
+//! // the recorded board can be exported like any other
+//! let bytes = recorder.get(4).unwrap().to_snapshot();
+//! assert_eq!(Life::from_snapshot(&bytes), Ok(life));
+//! ```
+//!
+//! Runs too long to keep in memory are recorded into an [Archive] file instead, which works like a Recorder but only keeps the positions of the generations in memory.
+
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::checksum::crc32;
+use crate::snapshot::SnapshotError;
+use crate::{Cell, Life};
+
+/// A label attached to a recorded generation by [`Recorder::bookmark`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bookmark {
+    /// The number of the labeled generation.
+    pub generation: u64,
+    /// The label.
+    pub label: String,
+}
+
+/// One recorded generation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Frame<const HEIGHT: usize, const WIDTH: usize> {
+    /// The whole board.
+    Keyframe(Life<HEIGHT, WIDTH>),
+    /// The index and new value of every [Cell] that changed since the generation before, row by row.
+    Diff(Vec<(usize, Cell)>),
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Frame<HEIGHT, WIDTH> {
+    /// Returns the frame recording `life` as `generation`, after the generation `latest`.
+    fn next(latest: Option<&Life<HEIGHT, WIDTH>>, life: &Life<HEIGHT, WIDTH>, generation: u64, interval: u64) -> Self {
+        match latest {
+            // a diff only holds cells, so changed settings need a keyframe
+            Some(latest) if !generation.is_multiple_of(interval) && latest.boundary == life.boundary && latest.aliveness == life.aliveness => {
+                let changes = latest.iter().zip(life.iter()).enumerate().filter(|(_, (before, after))| before != after).map(|(i, (_, &after))| (i, after));
+                Self::Diff(changes.collect())
+            }
+            _ => Self::Keyframe(*life),
+        }
+    }
+
+    /// Applies the changes of a diff to `life`.
+    fn apply(changes: &[(usize, Cell)], life: &mut Life<HEIGHT, WIDTH>) {
+        for &(i, cell) in changes {
+            life.cells.as_flattened_mut()[i] = cell;
+        }
+    }
+}
+
+/// Records the generations of a [Life] and labels some of them.
+///
+/// Generations are numbered from 0 for the first recorded board. Every generation whose number is a multiple of the [`interval`][Recorder::interval] is stored as a keyframe, so restoring a generation with [`get`][Recorder::get] applies the changes of at most `interval - 1` generations to a keyframe. Generations whose [`boundary`][Life#structfield.boundary] mode or [`aliveness`][Life#structfield.aliveness] changed are stored as keyframes as well.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::history::Recorder;
+///
+/// let mut life = Life::<16, 16>::default();
+/// for x in 6..9 {
+///     life.set(x, 7, Cell::alive());
+/// }
+/// let mut expected = life;
+///
+/// let mut recorder = Recorder::with_interval(8);
+/// recorder.play_for(&mut life, 1000, |this, others, _, _| Rule::CONWAY.apply(this, others));
+///
+/// expected.play_rule_for(437, &Rule::CONWAY);
+/// assert_eq!(recorder.get(437), Some(expected));
+/// assert_eq!(recorder.get(1000), Some(life));
+/// assert_eq!(recorder.keyframes(), 126);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Recorder<const HEIGHT: usize, const WIDTH: usize> {
+    interval: u64,
+    frames: Vec<Frame<HEIGHT, WIDTH>>,
+    latest: Option<Life<HEIGHT, WIDTH>>,
+    bookmarks: Vec<Bookmark>,
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Default for Recorder<HEIGHT, WIDTH> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Recorder<HEIGHT, WIDTH> {
+    /// The number of generations from one keyframe to the next used by [`Recorder::new`].
+    pub const DEFAULT_INTERVAL: u64 = 64;
+
+    /// Creates a Recorder without any generations, storing a keyframe every [`DEFAULT_INTERVAL`][Recorder::DEFAULT_INTERVAL] generations.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_interval(Self::DEFAULT_INTERVAL)
+    }
+
+    /// Creates a Recorder without any generations, storing a keyframe every `interval` generations.
+    ///
+    /// An interval of 1 stores every generation as a keyframe, which uses the most memory but restores generations the fastest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[track_caller]
+    #[inline]
+    pub const fn with_interval(interval: u64) -> Self {
+        assert!(interval != 0, "the keyframe interval must be at least 1");
+
+        Self {
+            interval,
+            frames: Vec::new(),
+            latest: None,
+            bookmarks: Vec::new(),
+        }
+    }
+
+    /// Returns the number of generations from one keyframe to the next.
+    #[inline]
+    pub const fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Returns the number of generations stored as keyframes.
+    #[inline]
+    pub fn keyframes(&self) -> usize {
+        self.frames.iter().filter(|frame| matches!(frame, Frame::Keyframe(_))).count()
+    }
+
+    /// Returns the number of recorded generations.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if no generations were recorded.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the number of the last recorded generation, or `None` if no generations were recorded.
+    #[inline]
+    pub fn latest(&self) -> Option<u64> {
+        (self.frames.len() as u64).checked_sub(1)
+    }
+
+    /// Records `life` as the next generation and returns its number.
+    pub fn record(&mut self, life: &Life<HEIGHT, WIDTH>) -> u64 {
+        let generation = self.frames.len() as u64;
+
+        self.frames.push(Frame::next(self.latest.as_ref(), life, generation, self.interval));
+        self.latest = Some(*life);
+        generation
+    }
+
+    /// Returns the recorded board of `generation`, or `None` if it wasn't recorded.
+    ///
+    /// The board is restored from the keyframe before it, which takes time proportional to the number of generations since that keyframe.
+    pub fn get(&self, generation: u64) -> Option<Life<HEIGHT, WIDTH>> {
+        let end = usize::try_from(generation).ok().filter(|&generation| generation < self.frames.len())?;
+        let start = self.frames[..=end].iter().rposition(|frame| matches!(frame, Frame::Keyframe(_)))?;
+
+        let Frame::Keyframe(mut life) = self.frames[start] else {
+            unreachable!("the frame is a keyframe");
+        };
+
+        for frame in &self.frames[start + 1..=end] {
+            if let Frame::Diff(changes) = frame {
+                Frame::apply(changes, &mut life);
+            }
+        }
+
+        Some(life)
+    }
+
+    /// Invokes [`Life::play`] with the given closure and records the new generation.
+    ///
+    /// If nothing was recorded yet, the board before the step is recorded first, so it becomes generation 0.
+    pub fn play(&mut self, life: &mut Life<HEIGHT, WIDTH>, f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        if self.is_empty() {
+            self.record(life);
+        }
+
+        life.play(f);
+        self.record(life);
+    }
+
+    /// Invokes [`Recorder::play`] `n` times.
+    #[inline]
+    pub fn play_for(&mut self, life: &mut Life<HEIGHT, WIDTH>, n: u32, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        for _ in 0..n {
+            self.play(life, &mut f);
+        }
+    }
+
+    /// Labels the last recorded generation and returns its number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no generations were recorded.
+    #[track_caller]
+    pub fn bookmark(&mut self, label: impl Into<String>) -> u64 {
+        let generation = self.latest().expect("no generations were recorded");
+        self.bookmark_at(generation, label);
+        generation
+    }
+
+    /// Labels a recorded generation. A generation can have several labels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `generation` wasn't recorded.
+    #[track_caller]
+    pub fn bookmark_at(&mut self, generation: u64, label: impl Into<String>) {
+        assert!(self.latest().is_some_and(|latest| generation <= latest), "generation {generation} wasn't recorded");
+
+        self.bookmarks.push(Bookmark {
+            generation,
+            label: label.into(),
+        });
+    }
+
+    /// Returns the bookmarks in the order they were added.
+    #[inline]
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    /// Returns the first bookmark with `label`, or `None` if there is none.
+    #[inline]
+    pub fn find(&self, label: &str) -> Option<&Bookmark> {
+        self.bookmarks.iter().find(|bookmark| bookmark.label == label)
+    }
+
+    /// Returns the recorded board of the first bookmark with `label`, or `None` if there is none.
+    #[inline]
+    pub fn labeled(&self, label: &str) -> Option<Life<HEIGHT, WIDTH>> {
+        self.get(self.find(label)?.generation)
+    }
+
+    /// Returns a [Manifest] with the [`stable_hash`][Life::stable_hash] of every recorded generation.
+    ///
+    /// The [`rule`][Manifest#structfield.rule] is unknown to the Recorder and left empty.
+    pub fn manifest(&self) -> Manifest {
+        let mut manifest = Manifest::new(WIDTH, HEIGHT);
+        let mut life = Life::default();
+
+        for (generation, frame) in self.frames.iter().enumerate() {
+            match frame {
+                Frame::Keyframe(keyframe) => life = *keyframe,
+                Frame::Diff(changes) => Frame::apply(changes, &mut life),
+            }
+            manifest.frames.push((generation as u64, life.stable_hash()));
+        }
+
+        manifest
+    }
+
+    /// Writes all recorded generations and bookmarks into a new [Archive] at `path`, to continue recording on disk.
+    ///
+    /// The Recorder is left unchanged.
+    pub fn spill_to(&self, path: impl AsRef<Path>) -> Result<Archive<HEIGHT, WIDTH>, ArchiveError> {
+        let mut archive = Archive::create(path, self.interval)?;
+
+        for frame in &self.frames {
+            archive.write_frame(frame)?;
+        }
+        for bookmark in &self.bookmarks {
+            archive.write_bookmark(bookmark.clone())?;
+        }
+
+        archive.latest = self.latest;
+        Ok(archive)
+    }
+}
+
+const MAGIC: [u8; 4] = *b"YGLH";
+const VERSION: u8 = 1;
+const HEADER_LEN: u64 = 4 + 1 + 4 * 2 + 8;
+
+const KEYFRAME: u8 = 0;
+const DIFF: u8 = 1;
+const BOOKMARK: u8 = 2;
+
+/// The error returned when an [Archive] can't be read or written.
+#[derive(Debug)]
+pub enum ArchiveError {
+    /// Reading or writing the file failed.
+    Io(io::Error),
+    /// The file doesn't start with the archive signature.
+    BadMagic,
+    /// The archive was written by an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// The board in the archive doesn't have the size of the [Archive] it's opened as.
+    SizeMismatch {
+        /// The width of the board in the archive.
+        width: usize,
+        /// The height of the board in the archive.
+        height: usize,
+    },
+    /// A keyframe couldn't be restored.
+    Snapshot(SnapshotError),
+    /// A record is truncated, doesn't match its checksum or couldn't be decoded.
+    Invalid,
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "history archive i/o failed: {error}"),
+            Self::BadMagic => write!(f, "file is not a history archive"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported history archive version {version}"),
+            Self::SizeMismatch { width, height } => write!(f, "history archive of {width}x{height} cells doesn't match the board"),
+            Self::Snapshot(error) => write!(f, "history archive keyframe is invalid: {error}"),
+            Self::Invalid => write!(f, "history archive contains an invalid record"),
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Snapshot(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ArchiveError {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<SnapshotError> for ArchiveError {
+    #[inline]
+    fn from(error: SnapshotError) -> Self {
+        Self::Snapshot(error)
+    }
+}
+
+/// Appends `value` to `bytes` as an unsigned LEB128 integer.
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 integer from the start of `bytes` and advances past it.
+fn read_varint(bytes: &mut &[u8]) -> Result<u64, ArchiveError> {
+    let mut value = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first().ok_or(ArchiveError::Invalid)?;
+        *bytes = rest;
+        value |= ((byte & 0x7F) as u64).checked_shl(shift).ok_or(ArchiveError::Invalid)?;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(ArchiveError::Invalid)
+}
+
+/// Encodes the changes of a diff, as the count followed by the gap to the index before and the RGB bytes of every change.
+pub(crate) fn encode_diff(changes: &[(usize, Cell)]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    write_varint(&mut payload, changes.len() as u64);
+
+    // indices increase, so only the gaps between them are stored
+    let mut next = 0;
+    for &(i, cell) in changes {
+        write_varint(&mut payload, (i - next) as u64);
+        payload.extend_from_slice(&[cell.r, cell.g, cell.b]);
+        next = i + 1;
+    }
+
+    payload
+}
+
+/// Decodes the changes of a diff written by [encode_diff] for a board of `len` [Cells][Cell].
+pub(crate) fn decode_diff(mut bytes: &[u8], len: usize) -> Result<Vec<(usize, Cell)>, ArchiveError> {
+    let mut changes = Vec::new();
+    let mut next = 0usize;
+
+    for _ in 0..read_varint(&mut bytes)? {
+        let i = usize::try_from(read_varint(&mut bytes)?).ok().and_then(|gap| next.checked_add(gap)).filter(|&i| i < len).ok_or(ArchiveError::Invalid)?;
+        let (&[r, g, b], rest) = bytes.split_first_chunk().ok_or(ArchiveError::Invalid)?;
+
+        changes.push((i, Cell { r, g, b }));
+        bytes = rest;
+        next = i + 1;
+    }
+
+    match bytes.is_empty() {
+        true => Ok(changes),
+        false => Err(ArchiveError::Invalid),
+    }
+}
+
+/// Where a recorded generation is stored in an [Archive] file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Entry {
+    /// The offset of the payload of the record.
+    offset: u64,
+    /// The length of the payload of the record.
+    len: u32,
+    keyframe: bool,
+}
+
+/// Records the generations of a [Life] into a file, like a [Recorder] that keeps almost nothing in memory.
+///
+/// Keyframes are stored as [snapshots][crate::snapshot] and the generations between them as compressed lists of the changed [Cells][Cell]. Only the position of each generation in the file is kept in memory, and generations are read back from the file by [`get`][Archive::get] when they are needed, so runs can be recorded for hours and scrubbed through afterwards by [opening][Archive::open] the file again.
+///
+/// Every record is written in one piece and followed by a checksum, so a run that was interrupted while recording is detected as [`ArchiveError::Invalid`] on opening it.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::history::Archive;
+///
+/// let path = std::env::temp_dir().join("your_game_of_life_archive_example.yglh");
+///
+/// let mut life = Life::<16, 16>::default();
+/// for x in 6..9 {
+///     life.set(x, 7, Cell::alive());
+/// }
+/// let start = life;
+///
+/// let mut archive = Archive::create(&path, 16).unwrap();
+/// archive.play_for(&mut life, 100, |this, others, _, _| Rule::CONWAY.apply(this, others)).unwrap();
+/// archive.bookmark("done").unwrap();
+/// drop(archive);
+///
+/// let mut archive = Archive::<16, 16>::open(&path).unwrap();
+/// let mut turned = start;
+/// turned.play_rule(&Rule::CONWAY);
+///
+/// assert_eq!(archive.len(), 101);
+/// assert_eq!(archive.get(0).unwrap(), Some(start));
+/// assert_eq!(archive.get(51).unwrap(), Some(turned));
+/// assert_eq!(archive.labeled("done").unwrap(), Some(life));
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Archive<const HEIGHT: usize, const WIDTH: usize> {
+    file: File,
+    interval: u64,
+    entries: Vec<Entry>,
+    latest: Option<Life<HEIGHT, WIDTH>>,
+    bookmarks: Vec<Bookmark>,
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Archive<HEIGHT, WIDTH> {
+    /// Creates an empty Archive at `path`, replacing any file there, storing a keyframe every `interval` generations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[track_caller]
+    pub fn create(path: impl AsRef<Path>, interval: u64) -> Result<Self, ArchiveError> {
+        assert!(interval != 0, "the keyframe interval must be at least 1");
+
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+
+        let mut header = MAGIC.to_vec();
+        header.push(VERSION);
+        header.extend_from_slice(&(WIDTH as u32).to_le_bytes());
+        header.extend_from_slice(&(HEIGHT as u32).to_le_bytes());
+        header.extend_from_slice(&interval.to_le_bytes());
+        file.write_all(&header)?;
+
+        Ok(Self {
+            file,
+            interval,
+            entries: Vec::new(),
+            latest: None,
+            bookmarks: Vec::new(),
+        })
+    }
+
+    /// Opens an Archive written by [`Archive::create`] to read its generations and record more of them.
+    ///
+    /// This reads the headers of all records, but none of the boards except the last one.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ArchiveError> {
+        let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+        let len = file.metadata()?.len();
+
+        let mut header = [0; HEADER_LEN as usize];
+        file.read_exact(&mut header).map_err(|_| ArchiveError::Invalid)?;
+
+        if header[..4] != MAGIC {
+            return Err(ArchiveError::BadMagic);
+        }
+        if header[4] != VERSION {
+            return Err(ArchiveError::UnsupportedVersion(header[4]));
+        }
+
+        let width = u32::from_le_bytes(header[5..9].try_into().unwrap()) as usize;
+        let height = u32::from_le_bytes(header[9..13].try_into().unwrap()) as usize;
+        let interval = u64::from_le_bytes(header[13..21].try_into().unwrap());
+
+        if width != WIDTH || height != HEIGHT {
+            return Err(ArchiveError::SizeMismatch { width, height });
+        }
+        if interval == 0 {
+            return Err(ArchiveError::Invalid);
+        }
+
+        let mut archive = Self {
+            file,
+            interval,
+            entries: Vec::new(),
+            latest: None,
+            bookmarks: Vec::new(),
+        };
+
+        let mut offset = HEADER_LEN;
+
+        while offset < len {
+            let mut record = [0; 5];
+            archive.file.read_exact(&mut record).map_err(|_| ArchiveError::Invalid)?;
+
+            let [tag, len @ ..] = record;
+            let entry = Entry {
+                offset: offset + 5,
+                len: u32::from_le_bytes(len),
+                keyframe: tag == KEYFRAME,
+            };
+
+            match tag {
+                KEYFRAME | DIFF => {
+                    // the first generation is always a keyframe
+                    if archive.entries.is_empty() && !entry.keyframe {
+                        return Err(ArchiveError::Invalid);
+                    }
+                    archive.entries.push(entry);
+                    archive.file.seek(SeekFrom::Current(entry.len as i64 + 4))?;
+                }
+                BOOKMARK => {
+                    let payload = archive.read(entry)?;
+                    let (generation, label) = payload.split_first_chunk::<8>().ok_or(ArchiveError::Invalid)?;
+
+                    archive.bookmarks.push(Bookmark {
+                        generation: u64::from_le_bytes(*generation),
+                        label: String::from_utf8(label.to_vec()).map_err(|_| ArchiveError::Invalid)?,
+                    });
+                }
+                _ => return Err(ArchiveError::Invalid),
+            }
+
+            offset = entry.offset + entry.len as u64 + 4;
+        }
+
+        if offset != len {
+            return Err(ArchiveError::Invalid);
+        }
+
+        archive.latest = match archive.latest() {
+            Some(latest) => archive.get(latest)?,
+            None => None,
+        };
+
+        Ok(archive)
+    }
+
+    /// Returns the number of generations from one keyframe to the next.
+    #[inline]
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Returns the number of recorded generations.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no generations were recorded.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of the last recorded generation, or `None` if no generations were recorded.
+    #[inline]
+    pub fn latest(&self) -> Option<u64> {
+        (self.entries.len() as u64).checked_sub(1)
+    }
+
+    /// Reads the payload of a record and verifies its checksum.
+    fn read(&mut self, entry: Entry) -> Result<Vec<u8>, ArchiveError> {
+        let mut payload = vec![0; entry.len as usize + 4];
+
+        self.file.seek(SeekFrom::Start(entry.offset))?;
+        self.file.read_exact(&mut payload).map_err(|_| ArchiveError::Invalid)?;
+
+        let checksum = payload.split_off(entry.len as usize);
+        match crc32(&payload).to_le_bytes() == *checksum {
+            true => Ok(payload),
+            false => Err(ArchiveError::Invalid),
+        }
+    }
+
+    /// Appends a record to the end of the file and returns where its payload is.
+    fn write(&mut self, tag: u8, payload: &[u8]) -> Result<Entry, ArchiveError> {
+        let len = u32::try_from(payload.len()).map_err(|_| ArchiveError::Invalid)?;
+
+        let mut record = Vec::with_capacity(payload.len() + 9);
+        record.push(tag);
+        record.extend_from_slice(&len.to_le_bytes());
+        record.extend_from_slice(payload);
+        record.extend_from_slice(&crc32(payload).to_le_bytes());
+
+        let offset = self.file.seek(SeekFrom::End(0))?;
+        self.file.write_all(&record)?;
+
+        Ok(Entry {
+            offset: offset + 5,
+            len,
+            keyframe: tag == KEYFRAME,
+        })
+    }
+
+    fn write_frame(&mut self, frame: &Frame<HEIGHT, WIDTH>) -> Result<(), ArchiveError> {
+        let entry = match frame {
+            Frame::Keyframe(life) => self.write(KEYFRAME, &life.to_snapshot())?,
+            Frame::Diff(changes) => self.write(DIFF, &encode_diff(changes))?,
+        };
+
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    fn write_bookmark(&mut self, bookmark: Bookmark) -> Result<(), ArchiveError> {
+        let mut payload = bookmark.generation.to_le_bytes().to_vec();
+        payload.extend_from_slice(bookmark.label.as_bytes());
+
+        self.write(BOOKMARK, &payload)?;
+        self.bookmarks.push(bookmark);
+        Ok(())
+    }
+
+    /// Records `life` as the next generation and returns its number.
+    pub fn record(&mut self, life: &Life<HEIGHT, WIDTH>) -> Result<u64, ArchiveError> {
+        let generation = self.entries.len() as u64;
+
+        self.write_frame(&Frame::next(self.latest.as_ref(), life, generation, self.interval))?;
+        self.latest = Some(*life);
+
+        Ok(generation)
+    }
+
+    /// Returns the recorded board of `generation`, or `None` if it wasn't recorded.
+    ///
+    /// The board is read from the keyframe before it and the changes since, like for [`Recorder::get`].
+    pub fn get(&mut self, generation: u64) -> Result<Option<Life<HEIGHT, WIDTH>>, ArchiveError> {
+        let Some(end) = usize::try_from(generation).ok().filter(|&generation| generation < self.entries.len()) else {
+            return Ok(None);
+        };
+        let start = self.entries[..=end].iter().rposition(|entry| entry.keyframe).ok_or(ArchiveError::Invalid)?;
+
+        let mut life = Life::default();
+
+        for i in start..=end {
+            self.read_frame(i, &mut life)?;
+        }
+
+        Ok(Some(life))
+    }
+
+    /// Reads the frame of the generation `i` and applies it to `life`.
+    fn read_frame(&mut self, i: usize, life: &mut Life<HEIGHT, WIDTH>) -> Result<(), ArchiveError> {
+        let entry = self.entries[i];
+        let payload = self.read(entry)?;
+
+        if entry.keyframe {
+            *life = Life::from_snapshot(&payload)?;
+            return Ok(());
+        }
+
+        Frame::apply(&decode_diff(&payload, HEIGHT * WIDTH)?, life);
+        Ok(())
+    }
+
+    /// Returns a [Manifest] with the [`stable_hash`][Life::stable_hash] of every recorded generation, like [`Recorder::manifest`].
+    ///
+    /// This reads the whole file once.
+    pub fn manifest(&mut self) -> Result<Manifest, ArchiveError> {
+        let mut manifest = Manifest::new(WIDTH, HEIGHT);
+        let mut life = Life::default();
+
+        for i in 0..self.entries.len() {
+            self.read_frame(i, &mut life)?;
+            manifest.frames.push((i as u64, life.stable_hash()));
+        }
+
+        Ok(manifest)
+    }
+
+    /// Invokes [`Life::play`] with the given closure and records the new generation, like [`Recorder::play`].
+    pub fn play(&mut self, life: &mut Life<HEIGHT, WIDTH>, f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) -> Result<(), ArchiveError> {
+        if self.is_empty() {
+            self.record(life)?;
+        }
+
+        life.play(f);
+        self.record(life)?;
+        Ok(())
+    }
+
+    /// Invokes [`Archive::play`] `n` times.
+    pub fn play_for(&mut self, life: &mut Life<HEIGHT, WIDTH>, n: u32, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) -> Result<(), ArchiveError> {
+        for _ in 0..n {
+            self.play(life, &mut f)?;
+        }
+        Ok(())
+    }
+
+    /// Labels the last recorded generation and returns its number, like [`Recorder::bookmark`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no generations were recorded.
+    #[track_caller]
+    pub fn bookmark(&mut self, label: impl Into<String>) -> Result<u64, ArchiveError> {
+        let generation = self.latest().expect("no generations were recorded");
+        self.bookmark_at(generation, label)?;
+        Ok(generation)
+    }
+
+    /// Labels a recorded generation. A generation can have several labels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `generation` wasn't recorded.
+    #[track_caller]
+    pub fn bookmark_at(&mut self, generation: u64, label: impl Into<String>) -> Result<(), ArchiveError> {
+        assert!(self.latest().is_some_and(|latest| generation <= latest), "generation {generation} wasn't recorded");
+
+        self.write_bookmark(Bookmark {
+            generation,
+            label: label.into(),
+        })
+    }
+
+    /// Returns the bookmarks in the order they were added.
+    #[inline]
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    /// Returns the first bookmark with `label`, or `None` if there is none.
+    #[inline]
+    pub fn find(&self, label: &str) -> Option<&Bookmark> {
+        self.bookmarks.iter().find(|bookmark| bookmark.label == label)
+    }
+
+    /// Returns the recorded board of the first bookmark with `label`, or `None` if there is none.
+    pub fn labeled(&mut self, label: &str) -> Result<Option<Life<HEIGHT, WIDTH>>, ArchiveError> {
+        match self.find(label) {
+            Some(bookmark) => self.get(bookmark.generation),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The hashes of all generations of a run together with what produced them, so others can verify a published run by repeating it.
+///
+/// Manifests are created by [`Recorder::manifest`] and [`Archive::manifest`]. The [`rule`][Manifest#structfield.rule] and [`backend`][Manifest#structfield.backend] describe how the run was stepped and can be set by the application.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::history::Recorder;
+///
+/// let mut life = Life::<4, 4>::default();
+/// for x in 0..3 {
+///     life.set(x, 1, Cell::alive());
+/// }
+///
+/// let mut recorder = Recorder::new();
+/// recorder.play_for(&mut life, 2, |this, others, _, _| Rule::CONWAY.apply(this, others));
+///
+/// let mut manifest = recorder.manifest();
+/// manifest.rule = Some(Rule::CONWAY.to_string());
+///
+/// assert_eq!(manifest.frames.len(), 3);
+/// assert_eq!(manifest.frames[0].1, manifest.frames[2].1);
+/// assert!(manifest.to_json().starts_with(r#"{"crate":"your_game_of_life","version":""#));
+/// assert!(manifest.to_json().contains(r#""rule":"B3/S23","width":4,"height":4,"frames":[[0,""#));
+///
+/// // replaying with another rule differs right after the start
+/// let mut other = Recorder::new();
+/// let mut life = life;
+/// other.record(&recorder.get(0).unwrap());
+/// other.play(&mut life, |this, others, _, _| Rule::SEEDS.apply(this, others));
+/// assert_eq!(manifest.first_mismatch(&other.manifest()), Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Manifest {
+    /// The version of this crate that produced the run.
+    pub crate_version: String,
+    /// What stepped the board, `Life` by default.
+    pub backend: String,
+    /// The rule the run was stepped by, if known.
+    pub rule: Option<String>,
+    /// The width of the board.
+    pub width: usize,
+    /// The height of the board.
+    pub height: usize,
+    /// The number and [`stable_hash`][Life::stable_hash] of every generation, in order.
+    pub frames: Vec<(u64, u64)>,
+}
+
+impl Manifest {
+    /// Creates a Manifest of a `width` × `height` board without any generations.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            backend: "Life".to_string(),
+            rule: None,
+            width,
+            height,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Returns the first generation whose hash differs from `other`, or that only one of them has, or `None` if both have the same hashes.
+    ///
+    /// Only the hashes are compared, not the versions, backends and rules.
+    pub fn first_mismatch(&self, other: &Self) -> Option<u64> {
+        let mismatch = self.frames.iter().zip(&other.frames).find(|(a, b)| a != b).map(|(&(generation, _), _)| generation);
+
+        mismatch.or_else(|| match self.frames.len().cmp(&other.frames.len()) {
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Less => Some(other.frames[self.frames.len()].0),
+            std::cmp::Ordering::Greater => Some(self.frames[other.frames.len()].0),
+        })
+    }
+
+    /// Encodes the Manifest as a JSON object.
+    ///
+    /// The hashes are written as strings of 16 hexadecimal digits, since many JSON readers can't represent all 64-bit integers.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from(r#"{"crate":"your_game_of_life","version":"#);
+
+        write_json_string(&mut json, &self.crate_version);
+        json.push_str(r#","backend":"#);
+        write_json_string(&mut json, &self.backend);
+        json.push_str(r#","rule":"#);
+        match &self.rule {
+            Some(rule) => write_json_string(&mut json, rule),
+            None => json.push_str("null"),
+        }
+
+        json.push_str(&format!(r#","width":{},"height":{},"frames":["#, self.width, self.height));
+        for (i, (generation, hash)) in self.frames.iter().enumerate() {
+            if i != 0 {
+                json.push(',');
+            }
+            json.push_str(&format!(r#"[{generation},"{hash:016x}"]"#));
+        }
+        json.push_str("]}");
+
+        json
+    }
+}
+
+/// Appends `string` to `json` as a quoted JSON string.
+fn write_json_string(json: &mut String, string: &str) {
+    json.push('"');
+
+    for character in string.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            character if character < ' ' => json.push_str(&format!("\\u{:04x}", character as u32)),
+            character => json.push(character),
+        }
+    }
+
+    json.push('"');
+}
@@ -0,0 +1,117 @@
+use crate::{Cell, DynLife, Life};
+
+impl<const HEIGHT: usize, const WIDTH: usize, C> Life<HEIGHT, WIDTH, C> {
+    /// Returns an iterator over the cells, row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<2, 2>::from([[true, false], [true, true]]);
+    ///
+    /// assert_eq!(life.iter().filter(|cell| cell.is_alive()).count(), 3);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &C> {
+        self.cells.iter().flatten()
+    }
+
+    /// Returns an iterator over mutable references to the cells, row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<2, 2>::from([[true, false], [true, true]]);
+    ///
+    /// for cell in life.iter_mut().filter(|cell| cell.is_alive()) {
+    ///     *cell = Cell::red();
+    /// }
+    ///
+    /// assert_eq!(life.cells, [[Cell::red(), Cell::dead()], [Cell::red(), Cell::red()]]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut C> {
+        self.cells.iter_mut().flatten()
+    }
+
+    /// Returns an iterator over the cells and their `x` and `y` positions, row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<2, 2>::from([[false, false], [false, true]]);
+    ///
+    /// let alive: Vec<_> = life.enumerate_cells().filter(|(_, _, cell)| cell.is_alive()).map(|(x, y, _)| (x, y)).collect();
+    ///
+    /// assert_eq!(alive, [(1, 1)]);
+    /// ```
+    #[inline]
+    pub fn enumerate_cells(&self) -> impl Iterator<Item = (usize, usize, &C)> {
+        self.cells.iter().enumerate().flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| (x, y, cell)))
+    }
+
+    /// Returns an iterator over mutable references to the cells and their `x` and `y` positions, row by row.
+    #[inline]
+    pub fn enumerate_cells_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut C)> {
+        self.cells.iter_mut().enumerate().flat_map(|(y, row)| row.iter_mut().enumerate().map(move |(x, cell)| (x, y, cell)))
+    }
+
+    /// Returns an iterator over the rows of cells, from top to bottom.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[C; WIDTH]> {
+        self.cells.iter()
+    }
+
+    /// Returns an iterator over the columns of cells, from left to right, where each column is an iterator over its cells from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<2, 3>::from([[true, false, true], [true, false, false]]);
+    ///
+    /// let populations: Vec<_> = life.columns().map(|column| column.filter(|cell| cell.is_alive()).count()).collect();
+    ///
+    /// assert_eq!(populations, [2, 0, 1]);
+    /// ```
+    #[inline]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &C>> {
+        (0..WIDTH).map(move |x| self.cells.iter().map(move |row| &row[x]))
+    }
+}
+
+impl DynLife {
+    /// Returns an iterator over the [Cells][Cell], row by row.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Cell> {
+        self.cells().iter()
+    }
+
+    /// Returns an iterator over mutable references to the [Cells][Cell], row by row.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Cell> {
+        self.cells_mut().iter_mut()
+    }
+
+    /// Returns an iterator over the [Cells][Cell] and their `x` and `y` positions, row by row.
+    #[inline]
+    pub fn enumerate_cells(&self) -> impl Iterator<Item = (usize, usize, &Cell)> {
+        let width = self.width();
+        self.iter().enumerate().map(move |(i, cell)| (i % width, i / width, cell))
+    }
+
+    /// Returns an iterator over mutable references to the [Cells][Cell] and their `x` and `y` positions, row by row.
+    #[inline]
+    pub fn enumerate_cells_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut Cell)> {
+        let width = self.width();
+        self.iter_mut().enumerate().map(move |(i, cell)| (i % width, i / width, cell))
+    }
+
+    /// Returns an iterator over the columns of [Cells][Cell], from left to right, where each column is an iterator over its [Cells][Cell] from top to bottom.
+    #[inline]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &Cell>> {
+        (0..self.width()).map(move |x| self.rows().map(move |row| &row[x]))
+    }
+}
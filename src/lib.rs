@@ -1,6 +1,6 @@
 //! Conway's Game of Life but customized.
 //! 
-//! This is a small and simple crate. The crate is meant to mimic Conway's Game of Life but with customization. You can edit the rules using the [`play`] or [`play_for`] by providing your own closure. Also, the [Cells][Cell] (squares/pixels) are 8-bit RGB values, which means you can make a colored game of life.
+//! This is a small and simple crate. The crate is meant to mimic Conway's Game of Life but with customization. You can edit the rules using the [`play`] or [`play_for`] by providing your own closure. Also, the [Cells][Cell] (squares/pixels) are 8-bit RGB values, which means you can make a colored game of life. Cells carrying other state, like plain `bool`s or age counters, can be used through the [CellLike] trait.
 //! 
 //! Note that this crate only provides functionality for 2D matrix manipulation designed for Game of Life. It does not provide functionality for drawing the game.
 //! 
@@ -8,11 +8,13 @@
 //! 
 //! This is how you would use the normal rules of Conway's Game of Life. First, we create a life defining how big it is and which cells are alive and/or which color they are. (You can define them as RGB instead by replacing `ALIVE` or `DEAD` with `Cell {r: 255, g: 255, b: 255 }`.) Then we use the [`play_for`] method to apply our closure 2 times.
 //! ```
+//! use your_game_of_life::*;
+//! 
 //! const ALIVE: Cell = Cell::alive();
 //! const DEAD: Cell = Cell::dead();
 //! 
 //! fn main() {
-//!     let mut life = Life::from([
+//!     let mut life = Life::<3, 3>::from([
 //!         [ALIVE, DEAD,  ALIVE],
 //!         [DEAD,  ALIVE, DEAD],
 //!         [ALIVE, DEAD,  ALIVE],
@@ -37,30 +39,139 @@
 //! }
 //! ```
 //! 
+//! Common life-like rules don't need a closure at all: [`Life::play_rule`] steps a Life by a [Rule] parsed from a rulestring like `B36/S23`, and the [`rules`] module builds closures for them that also color newborn [Cells][Cell]. The [`prelude`] imports everything needed for that at once.
+//! 
+//! # Features
+//! 
+//! * `float` (enabled by default): Enables the APIs that use floating point, like [`game::Standing::win_rate`]. Everything else, including stepping, blending and statistics, only uses integer arithmetic, so disabling this feature makes the crate usable on microcontrollers without a floating-point unit.
+//! * `fuzz`: Enables the `fuzz` module with entry points for fuzzing the parsers and engines, for example with `cargo fuzz`.
+//! * `image`: Enables the `render` module for saving boards as PNG images and animated GIFs.
+//! * `midi`: Enables the `midi` module for using a Life as a MIDI sequencer.
+//! * `minifb`: Enables `Life::draw_into_minifb` for drawing a [Viewport] of a board into the `0RGB` window buffer of the `minifb` crate.
+//! * `osc`: Enables `events::OscSink` for sending [generation events][events] as Open Sound Control messages.
+//! * `parallel`: Enables stepping a Life on all cores, like `Life::play_par` and `Life::play_par_tiled`.
+//! * `pixels`: Enables `Life::draw_into` for drawing a [Viewport] of a board straight into the RGBA frame buffer of the `pixels` crate.
+//! * `rand`: Enables creating random soups from a seed, like `Life::random`, with a small built-in random number generator.
+//! * `rayon`: The same as `parallel`, under the name of the crate parallel stepping is usually built on. The threads are scoped standard library threads, so no dependency is pulled in.
+//! * `sdl2`: Enables `Life::draw_into_sdl2` for drawing a [Viewport] of a board into a locked `RGB24` texture of the `sdl2` crate.
+//! * `strict`: Replaces the unchecked indexing in stepping and collecting boards with checked indexing that panics, and validates the internal invariants of the boards and helpers each step. This is slower and meant for debugging and testing.
+//! 
+//! Without the `float` feature, clippy rejects any floating-point arithmetic in the crate, and the integer alternatives still cover ratings, blending and statistics:
+//! 
+//! ```
+//! use your_game_of_life::*;
+//! use your_game_of_life::game::*;
+//! 
+//! let report = tournament(&[2, 1], 1, &[0], |a, b, _| if a > b { Outcome::FirstWins } else { Outcome::SecondWins });
+//! assert_eq!(report.ranking()[0].rating_points, 1531);
+//! assert_eq!(report.standings[0].win_rate_permille(), 1000);
+//! 
+//! assert_eq!(Cell::red().blend(Cell::blue(), 128), Cell { r: 127, g: 0, b: 128 });
+//! 
+//! let mut life = Life::<4, 4>::default();
+//! life.set(1, 1, Cell::alive());
+//! let stats = life.stats();
+//! assert_eq!((stats.population, stats.bounding_box), (1, Some(Rect::new(1, 1, 1, 1))));
+//! ```
+//! 
 //! [`play`]: Life::play
 //! [`play_for`]: Life::play_for
 
+// keeps floating point from creeping in outside of the `float` feature
+#![cfg_attr(not(feature = "float"), deny(clippy::float_arithmetic))]
+
+mod active;
+pub use active::*;
+mod bit;
+pub use bit::*;
+mod boundary;
+pub use boundary::*;
 mod cell;
+mod changes;
+pub use changes::*;
+mod checksum;
+mod dynamic;
+pub use dynamic::*;
+mod geometry;
+pub use geometry::*;
+mod grid;
+pub use grid::*;
+mod iter;
+mod neighborhood;
+pub use neighborhood::*;
+#[cfg(feature = "parallel")]
+mod parallel;
 pub use cell::*;
+mod pool;
+pub use pool::*;
+mod quiescence;
+pub use quiescence::*;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rand")]
+pub use random::*;
+mod rule;
+pub use rule::*;
+mod small;
+pub use small::*;
+mod sparse;
+pub use sparse::*;
+mod stability;
+pub use stability::*;
+mod stamp;
+pub use stamp::*;
+mod stats;
+pub use stats::*;
+mod text;
+pub use text::*;
+mod tile;
+pub use tile::*;
+mod transform;
+mod viewport;
+pub use viewport::*;
+
+pub mod analysis;
+pub mod db;
+pub mod distributed;
+pub mod embedded;
+pub mod events;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+pub mod game;
+pub mod history;
+#[cfg(feature = "midi")]
+pub mod midi;
+pub mod pattern;
+pub mod post;
+pub mod prelude;
+#[cfg(feature = "image")]
+pub mod render;
+pub mod rules;
+pub mod snapshot;
 
 /// 2D array of [Cells].
 /// 
 /// This is the base of the game where you manage the [Cells] and provide the closures for running it.
 /// 
+/// The cells are RGB [Cells] unless another [CellLike] type is given as `C`. The accessors and [`play`][Life::play] work with any cell type, while most other APIs expect [Cells].
+/// 
 /// [Cells]: Cell
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Life<const HEIGHT: usize, const WIDTH: usize> {
-    /// The [Cells][Cell] that live in this Life.
-    pub cells: [[Cell; HEIGHT]; WIDTH],
-    /// The [Cell] that is added in the `[Cell; 8]` array on invoking the closure for [`play`][Life::play] and [`play_for`][Life::play_for] when the neighboring [Cell] would have been out of bounds.
-    pub out_of_bounds: Cell,
+pub struct Life<const HEIGHT: usize, const WIDTH: usize, C = Cell> {
+    /// The cells that live in this Life, stored row by row and indexed as `cells[y][x]`.
+    pub cells: [[C; WIDTH]; HEIGHT],
+    /// Decides which [Cell] is added in the `[Cell; 8]` array on invoking the closure for [`play`][Life::play] and [`play_for`][Life::play_for] when the neighboring [Cell] would have been out of bounds.
+    pub boundary: BoundaryMode,
+    /// Decides which [Cells][Cell] count as alive and which color dead [Cells][Cell] have, for [`play`][Life::play], [`play_rule`][Life::play_rule] and the exporters and analysis tools.
+    pub aliveness: Aliveness,
 }
 
-impl<const HEIGHT: usize, const WIDTH: usize> Default for Life<HEIGHT, WIDTH> {
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Default for Life<HEIGHT, WIDTH, C> {
     fn default() -> Self {
         Self {
-            cells: [[Cell::default(); HEIGHT]; WIDTH],
-            out_of_bounds: Cell::default(),
+            cells: [[C::default(); WIDTH]; HEIGHT],
+            boundary: BoundaryMode::default(),
+            aliveness: Aliveness::default(),
         }
     }
 }
@@ -73,7 +184,7 @@ impl<const HEIGHT: usize, const WIDTH: usize> Default for Life<HEIGHT, WIDTH> {
 /// # use your_game_of_life::Life;
 /// let life = Life::<2, 2>::from(vec![vec![true, false], vec![false, true]]);
 /// ```
-impl<T, const HEIGHT: usize, const WIDTH: usize> From<T> for Life<HEIGHT, WIDTH>
+impl<T, const HEIGHT: usize, const WIDTH: usize, C> From<T> for Life<HEIGHT, WIDTH, C>
 where T: IntoIterator,
 Self: FromIterator<T::Item> {
     fn from(iter: T) -> Self {
@@ -82,28 +193,116 @@ Self: FromIterator<T::Item> {
 }
 
 /// Converts any 2D container of [Cells][Cell] into a Life, for example a [`Vec<Vec<bool>>`].
-impl<A, const HEIGHT: usize, const WIDTH: usize> FromIterator<A> for Life<HEIGHT, WIDTH>
+impl<A, const HEIGHT: usize, const WIDTH: usize, C: CellLike> FromIterator<A> for Life<HEIGHT, WIDTH, C>
 where A: IntoIterator,
-A::Item: Into<Cell> {
+A::Item: Into<C> {
     fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
-        let mut cells = [[Cell::default(); HEIGHT]; WIDTH];
+        let mut cells = [[C::default(); WIDTH]; HEIGHT];
 
-        for (y, row) in iter.into_iter().enumerate().take(WIDTH) {
-            for (x, cell) in row.into_iter().enumerate().take(HEIGHT) {
+        for (y, row) in iter.into_iter().enumerate().take(HEIGHT) {
+            for (x, cell) in row.into_iter().enumerate().take(WIDTH) {
+                // take keeps x and y within the board
                 unsafe {
-                    *cells.get_unchecked_mut(y).get_unchecked_mut(x) = cell.into();
+                    let row = unchecked_mut(&mut cells, y);
+                    *unchecked_mut(row, x) = cell.into();
                 }
             }
         }
 
         Self {
             cells,
-            out_of_bounds: Cell::default(),
+            boundary: BoundaryMode::default(),
+            aliveness: Aliveness::default(),
         }
     }
 }
 
-impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+/// Indexes the [Cells][Cell] of a Life by `(x, y)`.
+///
+/// # Panics
+///
+/// Panics if either the `x` or `y` index is out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut life = Life::<2, 3>::default();
+/// life[(2, 1)] = Cell::red();
+///
+/// assert_eq!(life[(2, 1)], Cell::red());
+/// assert_eq!(life.cells[1][2], Cell::red());
+/// ```
+impl<const HEIGHT: usize, const WIDTH: usize, C> std::ops::Index<(usize, usize)> for Life<HEIGHT, WIDTH, C> {
+    type Output = C;
+
+    #[track_caller]
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &C {
+        &self.cells[y][x]
+    }
+}
+
+/// Mutably indexes the [Cells][Cell] of a Life by `(x, y)`.
+///
+/// # Panics
+///
+/// Panics if either the `x` or `y` index is out of bounds.
+impl<const HEIGHT: usize, const WIDTH: usize, C> std::ops::IndexMut<(usize, usize)> for Life<HEIGHT, WIDTH, C> {
+    #[track_caller]
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut C {
+        &mut self.cells[y][x]
+    }
+}
+
+/// The `(dx, dy)` offsets of the neighbors of a [Cell], in the order used by [CellNeighbors].
+pub(crate) const NEIGHBOR_OFFSETS: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// Returns a reference to `slice[i]` without checking the bounds, unless the `strict` feature is enabled.
+///
+/// # Safety
+///
+/// `i` must be in bounds. With the `strict` feature, this panics instead of being undefined behavior.
+#[track_caller]
+#[inline(always)]
+pub(crate) unsafe fn unchecked<T>(slice: &[T], i: usize) -> &T {
+    #[cfg(feature = "strict")]
+    return &slice[i];
+
+    #[cfg(not(feature = "strict"))]
+    slice.get_unchecked(i)
+}
+
+/// Returns a mutable reference to `slice[i]` without checking the bounds, unless the `strict` feature is enabled.
+///
+/// # Safety
+///
+/// `i` must be in bounds. With the `strict` feature, this panics instead of being undefined behavior.
+#[track_caller]
+#[inline(always)]
+pub(crate) unsafe fn unchecked_mut<T>(slice: &mut [T], i: usize) -> &mut T {
+    #[cfg(feature = "strict")]
+    return &mut slice[i];
+
+    #[cfg(not(feature = "strict"))]
+    slice.get_unchecked_mut(i)
+}
+
+/// Invokes `f` on `this` and its neighbors [normalized][CellLike::normalize_by] by `aliveness` and returns the normalized result, so `f` sees the board as if only black [Cells][Cell] were dead.
+#[inline(always)]
+pub(crate) fn normalized<C: CellLike, N: AsMut<[C]>>(aliveness: Aliveness, f: impl FnOnce(C, N) -> C, this: C, mut others: N) -> C {
+    if aliveness == Aliveness::NonBlack {
+        return f(this, others);
+    }
+
+    for neighbor in others.as_mut() {
+        *neighbor = neighbor.normalize_by(aliveness);
+    }
+    f(this.normalize_by(aliveness), others).normalize_by(aliveness)
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Life<HEIGHT, WIDTH, C> {
     /// Returns the [Cell] at the given index.
     /// 
     /// # Panics
@@ -111,7 +310,7 @@ impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
     /// Panics if either the `x` or `y` index is out of bounds.
     #[track_caller]
     #[inline]
-    pub fn get(self, x: usize, y: usize) -> Cell {
+    pub fn get(self, x: usize, y: usize) -> C {
         self.cells[y][x]
     }
     
@@ -122,7 +321,7 @@ impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
     /// Panics if either the `x` or `y` index is out of bounds.
     #[track_caller]
     #[inline]
-    pub fn get_ref(&self, x: usize, y: usize) -> &Cell {
+    pub fn get_ref(&self, x: usize, y: usize) -> &C {
         &self.cells[y][x]
     }
 
@@ -133,7 +332,7 @@ impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
     /// Panics if either the `x` or `y` index is out of bounds.
     #[track_caller]
     #[inline]
-    pub fn get_mut(&mut self, x: usize, y: usize) -> &mut Cell {
+    pub fn get_mut(&mut self, x: usize, y: usize) -> &mut C {
         &mut self.cells[y][x]
     }
 
@@ -144,48 +343,111 @@ impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
     /// Panics if either the `x` or `y` index is out of bounds.
     #[track_caller]
     #[inline]
-    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+    pub fn set(&mut self, x: usize, y: usize, cell: C) {
         self.cells[y][x] = cell;
     }
 
-    unsafe fn get_surrounding(self, x: usize, y: usize) -> [Cell; 8] {
-        let (mut tl, mut t, mut tr, mut l, mut r, mut bl, mut b, mut br) = (Cell::default(), Cell::default(), Cell::default(), Cell::default(), Cell::default(), Cell::default(), Cell::default(), Cell::default());
+    /// Returns the [Cell] neighbors beyond the edges are replaced with, like the former `out_of_bounds` field.
+    ///
+    /// This is the [`outside`][BoundaryMode::outside] [Cell] of the [`boundary`][Life#structfield.boundary] mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<3, 3>::default();
+    /// life.set_out_of_bounds(Cell::red());
+    ///
+    /// assert_eq!(life.boundary, BoundaryMode::Constant(Cell::red()));
+    /// assert_eq!(life.out_of_bounds(), Cell::red());
+    /// ```
+    #[deprecated(since = "0.1.0", note = "the `out_of_bounds` field was replaced by `boundary`, use `boundary.outside()` instead")]
+    #[inline]
+    pub const fn out_of_bounds(&self) -> Cell {
+        self.boundary.outside()
+    }
 
-        macro_rules! insert {
-            ($var:ident, $xop:tt $xoff:literal, $yop:tt $yoff:literal) => {
-                $var = *self.cells.get_unchecked(x $xop $xoff).get_unchecked(y $yop $yoff)
-            }
-        }
+    /// Sets the [`boundary`][Life#structfield.boundary] mode to [`BoundaryMode::Constant`] with the given [Cell], like setting the former `out_of_bounds` field.
+    #[deprecated(since = "0.1.0", note = "the `out_of_bounds` field was replaced by `boundary`, set `boundary` to `BoundaryMode::Constant(cell)` instead")]
+    #[inline]
+    pub fn set_out_of_bounds(&mut self, cell: Cell) {
+        self.boundary = BoundaryMode::Constant(cell);
+    }
 
-        if x != 0 {
-            insert!(l, -1, +0);
+    /// Returns the [Cell] at the given index, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<2, 3>::from([[true, false, false], [false; 3]]);
+    ///
+    /// assert_eq!(life.try_get(0, 0), Some(Cell::alive()));
+    /// assert_eq!(life.try_get(3, 0), None);
+    /// assert_eq!(life.try_get(0, 2), None);
+    /// ```
+    #[inline]
+    pub fn try_get(&self, x: usize, y: usize) -> Option<C> {
+        self.cells.get(y)?.get(x).copied()
+    }
 
-            if y != 0 {
-                insert!(tl, -1, -1);
-            }
-            if y != HEIGHT - 1 {
-                insert!(bl, -1, +1);
-            }
-        }
-        if y != 0 {
-            insert!(t, +0, -1);
+    /// Returns a mutable reference to the [Cell] at the given index, or `None` if it is out of bounds.
+    #[inline]
+    pub fn try_get_mut(&mut self, x: usize, y: usize) -> Option<&mut C> {
+        self.cells.get_mut(y)?.get_mut(x)
+    }
 
-            if x != WIDTH - 1 {
-                insert!(tr, +1, -1);
-            }
-        }
-        if x != WIDTH - 1 {
-            insert!(r, +1, +0);
+    /// Returns the [Cells][Cell] surrounding the given index, exactly as the closure of [`play`][Life::play] would get them.
+    ///
+    /// The [`boundary`] mode decides which [Cell] is in the array for neighbors out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<3, 3>::from([[true, false, false], [false; 3], [false, false, true]]);
+    ///
+    /// assert_eq!(life.neighbors(1, 1).alive(), 2);
+    /// assert_eq!(life.neighbors(0, 0).alive(), 0);
+    ///
+    /// life.boundary = BoundaryMode::Wrap;
+    /// assert!(life.neighbors(0, 0).top_left().is_alive());
+    /// ```
+    ///
+    /// [`boundary`]: Life#structfield.boundary
+    #[track_caller]
+    #[inline]
+    pub fn neighbors(&self, x: usize, y: usize) -> [C; 8] {
+        assert!(x < WIDTH && y < HEIGHT, "index ({x}, {y}) out of bounds for a board of {WIDTH}x{HEIGHT} cells");
+
+        // x and y are within the board
+        unsafe { self.get_surrounding(x, y) }
+    }
+
+    /// Returns the [Cells][Cell] surrounding the given index, which must be within the board.
+    #[track_caller]
+    unsafe fn get_surrounding(&self, x: usize, y: usize) -> [C; 8] {
+        #[cfg(feature = "strict")]
+        assert!(x < WIDTH && y < HEIGHT, "index ({x}, {y}) out of bounds for a board of {WIDTH}x{HEIGHT} cells");
+
+        let mut surrounding = [C::from(self.boundary.outside()); 8];
 
-            if y != HEIGHT - 1 {
-                insert!(br, +1, +1);
+        for (i, (dx, dy)) in NEIGHBOR_OFFSETS.into_iter().enumerate() {
+            let nx = self.boundary.resolve(x as isize + dx, WIDTH);
+            let ny = self.boundary.resolve(y as isize + dy, HEIGHT);
+
+            if let (Some(nx), Some(ny)) = (nx, ny) {
+                let row = unchecked(&self.cells, ny);
+                *unchecked_mut(&mut surrounding, i) = *unchecked(row, nx);
             }
         }
-        if y != WIDTH - 1 {
-            insert!(b, +0, +1);
-        }
 
-        [tl, t, tr, l, r, bl, b, br]
+        surrounding
     }
 
     /// Invokes the given closure on each [Cell] in the Life.
@@ -196,17 +458,20 @@ impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
     /// * The x-position
     /// * The y-position
     /// 
-    /// If a neighboring [Cell] would have been out of bounds, it's instead replaced by the [`out_of_bounds`] [Cell] in the `[Cell; 8]` array.
+    /// If a neighboring [Cell] would have been out of bounds, the [`boundary`] mode decides which [Cell] is in the `[Cell; 8]` array instead.
+    /// 
+    /// The Cells passed to the closure and the Cell it returns are [normalized][Aliveness::normalize] by the [`aliveness`] of the Life, so the closure sees dead Cells as black and [`Cell::is_alive`] and [`CellNeighbors::alive`] follow the aliveness.
     /// 
     /// You can make use of the [CellNeighbors] trait for indexing the surrounding Cells with readability.
     /// 
     /// # Examples
     /// 
     /// ```
+    /// # use your_game_of_life::*;
     /// let mut life = Life::<3, 2>::from([
-    ///     [1, 0]
-    ///     [0, 1]
-    ///     [1, 1]
+    ///     [true, false],
+    ///     [false, true],
+    ///     [true, true],
     /// ]);
     /// 
     /// life.play(|this, others, x, y| {
@@ -217,14 +482,37 @@ impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
     /// });
     /// ```
     /// 
-    /// [`out_of_bounds`]: struct.Life.html#structfield.out_of_bounds
-    pub fn play(&mut self, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+    /// On a board with a styled background, dead Cells stay the background:
+    /// 
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let navy = Cell { r: 0, g: 0, b: 64 };
+    /// let mut life = Life::<3, 3>::from([[navy, navy, navy], [Cell::red(); 3], [navy, navy, navy]]);
+    /// life.aliveness = Aliveness::Background(navy);
+    /// // beyond the edges is the background too, black would count as alive
+    /// life.boundary = BoundaryMode::Constant(navy);
+    /// 
+    /// life.play(|this, others, _, _| match (this.is_alive(), others.alive()) {
+    ///     (true, 2 | 3) => this,
+    ///     (false, 3) => Cell::alive(),
+    ///     _ => Cell::dead(),
+    /// });
+    /// 
+    /// assert_eq!(life.cells, [[navy, Cell::alive(), navy], [navy, Cell::red(), navy], [navy, Cell::alive(), navy]]);
+    /// ```
+    /// 
+    /// [`boundary`]: struct.Life.html#structfield.boundary
+    /// [`aliveness`]: struct.Life.html#structfield.aliveness
+    pub fn play(&mut self, mut f: impl FnMut(C, [C; 8], usize, usize) -> C) {
+        let aliveness = self.aliveness;
         let mut proto = self.cells;
 
-        for (x, column) in self.cells.into_iter().enumerate() {
-            for (y, cell) in column.into_iter().enumerate() {
+        for (y, row) in self.cells.into_iter().enumerate() {
+            for (x, cell) in row.into_iter().enumerate() {
+                // x and y are within the board
                 unsafe {
-                    *proto.get_unchecked_mut(x).get_unchecked_mut(y) = f(cell, self.get_surrounding(x, y), x, y);
+                    let row = unchecked_mut(&mut proto, y);
+                    *unchecked_mut(row, x) = normalized(aliveness, |this, others| f(this, others, x, y), cell, self.get_surrounding(x, y));
                 }
             }
         }
@@ -240,17 +528,18 @@ impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
     /// * The x-position
     /// * The y-position
     /// 
-    /// If a neighboring [Cell] would have been out of bounds, it's instead replaced by the [`out_of_bounds`] [Cell] in the `[Cell; 8]` array.
+    /// If a neighboring [Cell] would have been out of bounds, the [`boundary`] mode decides which [Cell] is in the `[Cell; 8]` array instead.
     /// 
     /// You can make use of the [CellNeighbors] trait for indexing the surrounding Cells with readability.
     /// 
     /// # Examples
     /// 
     /// ```
+    /// # use your_game_of_life::*;
     /// let mut life = Life::<3, 2>::from([
-    ///     [1, 0]
-    ///     [0, 1]
-    ///     [1, 1]
+    ///     [true, false],
+    ///     [false, true],
+    ///     [true, true],
     /// ]);
     /// 
     /// life.play_for(2, |_this, others, x, y| {
@@ -261,9 +550,9 @@ impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
     /// });
     /// ```
     /// 
-    /// [`out_of_bounds`]: struct.Life.html#structfield.out_of_bounds
+    /// [`boundary`]: struct.Life.html#structfield.boundary
     #[inline]
-    pub fn play_for(&mut self, n: u32, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+    pub fn play_for(&mut self, n: u32, mut f: impl FnMut(C, [C; 8], usize, usize) -> C) {
         for _ in 0..n {
             self.play(&mut f);
         }
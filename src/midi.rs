@@ -0,0 +1,177 @@
+//! Turning a [Life] into a MIDI sequencer.
+//!
+//! A common use of Game of Life in music is to assign a note to each row or column of the board and to play that note whenever [Cells][crate::Cell] are born on it. The [MidiMapper] implements this mapping and produces plain [MidiEvents][MidiEvent] that can be sent to any MIDI output.
+
+use crate::Life;
+
+/// A MIDI channel voice message produced by a [MidiMapper].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MidiEvent {
+    /// Starts playing a note.
+    NoteOn {
+        /// The channel, from 0 to 15.
+        channel: u8,
+        /// The note, from 0 to 127.
+        note: u8,
+        /// The velocity, from 1 to 127.
+        velocity: u8,
+    },
+    /// Stops playing a note.
+    NoteOff {
+        /// The channel, from 0 to 15.
+        channel: u8,
+        /// The note, from 0 to 127.
+        note: u8,
+    },
+}
+
+impl MidiEvent {
+    /// Returns the three bytes of the message as sent over a MIDI connection.
+    #[inline]
+    pub const fn to_bytes(self) -> [u8; 3] {
+        match self {
+            Self::NoteOn { channel, note, velocity } => [0x90 | (channel & 0x0F), note & 0x7F, velocity & 0x7F],
+            Self::NoteOff { channel, note } => [0x80 | (channel & 0x0F), note & 0x7F, 0],
+        }
+    }
+}
+
+/// Along which lines of the board a [MidiMapper] assigns its notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Axis {
+    /// Each row `y` has its own note.
+    Rows,
+    /// Each column `x` has its own note.
+    Columns,
+}
+
+/// Maps the births on the rows or columns of a [Life] to MIDI notes.
+///
+/// Each line (row or column) of the board can be given a note. When [Cells][crate::Cell] are born on a line during a step, a [`NoteOn`][MidiEvent::NoteOn] is emitted for its note, with a velocity that grows with the number of births. A note that was started in the previous step and has no births in the current step receives a [`NoteOff`][MidiEvent::NoteOff]. Lines without a note are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::midi::*;
+///
+/// // C major triad on the first three rows
+/// let mut mapper = MidiMapper::new(Axis::Rows, vec![Some(60), Some(64), Some(67)]);
+///
+/// let mut life = Life::<3, 3>::default();
+/// life.set(0, 1, Cell::alive());
+///
+/// assert_eq!(mapper.step_to_midi(&life), [MidiEvent::NoteOn { channel: 0, note: 64, velocity: 42 }]);
+/// assert_eq!(mapper.step_to_midi(&life), [MidiEvent::NoteOff { channel: 0, note: 64 }]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MidiMapper {
+    /// The lines the notes are assigned to.
+    pub axis: Axis,
+    /// The note of each line, or `None` if the line is silent.
+    pub notes: Vec<Option<u8>>,
+    /// The channel the events are sent on.
+    pub channel: u8,
+    size: (usize, usize),
+    previous: Vec<bool>,
+    playing: Vec<u8>,
+}
+
+impl MidiMapper {
+    /// Creates a MidiMapper on channel 0 with the given note for each line.
+    #[inline]
+    pub const fn new(axis: Axis, notes: Vec<Option<u8>>) -> Self {
+        Self {
+            axis,
+            notes,
+            channel: 0,
+            size: (0, 0),
+            previous: Vec::new(),
+            playing: Vec::new(),
+        }
+    }
+
+    /// Compares the board with the one given to the previous call and returns the resulting MIDI events.
+    ///
+    /// [Cells][crate::Cell] are alive according to the [`aliveness`][Life#structfield.aliveness] of the board. On the first call, or when the size of the board changes, every living [Cell][crate::Cell] counts as a birth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use your_game_of_life::*;
+    /// use your_game_of_life::midi::*;
+    ///
+    /// let mut mapper = MidiMapper::new(Axis::Rows, vec![Some(60), Some(64)]);
+    ///
+    /// let mut wide = Life::<1, 2>::default();
+    /// wide.set(1, 0, Cell::alive());
+    /// assert_eq!(mapper.step_to_midi(&wide), [MidiEvent::NoteOn { channel: 0, note: 60, velocity: 63 }]);
+    ///
+    /// // a board of the same number of cells but another shape starts over
+    /// let mut tall = Life::<2, 1>::default();
+    /// tall.set(0, 1, Cell::alive());
+    /// assert_eq!(mapper.step_to_midi(&tall), [
+    ///     MidiEvent::NoteOn { channel: 0, note: 64, velocity: 127 },
+    ///     MidiEvent::NoteOff { channel: 0, note: 60 },
+    /// ]);
+    /// ```
+    pub fn step_to_midi<const HEIGHT: usize, const WIDTH: usize>(&mut self, life: &Life<HEIGHT, WIDTH>) -> Vec<MidiEvent> {
+        let (lines, length) = match self.axis {
+            Axis::Rows => (HEIGHT, WIDTH),
+            Axis::Columns => (WIDTH, HEIGHT),
+        };
+
+        if self.size != (WIDTH, HEIGHT) {
+            self.size = (WIDTH, HEIGHT);
+            self.previous = vec![false; HEIGHT * WIDTH];
+        }
+
+        let mut births = vec![0usize; lines];
+
+        for (y, row) in life.cells.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let alive = life.aliveness.is_alive(*cell);
+
+                if alive && !self.previous[y * WIDTH + x] {
+                    births[match self.axis {
+                        Axis::Rows => y,
+                        Axis::Columns => x,
+                    }] += 1;
+                }
+
+                self.previous[y * WIDTH + x] = alive;
+            }
+        }
+
+        let mut events = Vec::new();
+        let mut playing = Vec::new();
+
+        for (line, &count) in births.iter().enumerate() {
+            let Some(&Some(note)) = self.notes.get(line) else {
+                continue;
+            };
+
+            if count != 0 {
+                events.push(MidiEvent::NoteOn {
+                    channel: self.channel,
+                    note,
+                    velocity: (count * 127 / length).clamp(1, 127) as u8,
+                });
+                playing.push(note);
+            }
+        }
+
+        for &note in &self.playing {
+            if !playing.contains(&note) {
+                events.push(MidiEvent::NoteOff {
+                    channel: self.channel,
+                    note,
+                });
+            }
+        }
+
+        self.playing = playing;
+
+        events
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{normalized, Aliveness, BoundaryMode, Cell, CellLike, DynLife, Life, Offset};
+
+/// Which [Cells][Cell] around a [Cell] count as its neighbors, for [`Life::play_with`].
+///
+/// [`play`][Life::play] always uses the 8 [Cells][Cell] of the Moore neighborhood of radius 1. A Neighborhood allows other automata, like von Neumann rules, Larger than Life or hexagonal rules emulated on the square grid.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// assert_eq!(Neighborhood::Moore(1).offsets().len(), 8);
+/// assert_eq!(Neighborhood::Moore(2).offsets().len(), 24);
+/// assert_eq!(Neighborhood::VonNeumann(1).offsets(), [Offset::new(0, -1), Offset::new(-1, 0), Offset::new(1, 0), Offset::new(0, 1)]);
+/// assert_eq!(Neighborhood::Hexagonal.offsets().len(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Neighborhood {
+    /// Every [Cell] in the square of the given radius around the [Cell].
+    Moore(usize),
+    /// Every [Cell] within the given number of horizontal and vertical steps from the [Cell].
+    VonNeumann(usize),
+    /// The 6 neighbors of a hexagonal grid stored as a square grid whose rows are shifted by half a [Cell] each: the Moore neighborhood without the top right and bottom left [Cells][Cell], like in Golly.
+    Hexagonal,
+    /// The [Cells][Cell] at the given [Offsets][Offset], in the given order.
+    Custom(Vec<Offset>),
+}
+
+impl Default for Neighborhood {
+    /// Returns `Neighborhood::Moore(1)`, the neighborhood used by [`Life::play`].
+    #[inline]
+    fn default() -> Self {
+        Self::Moore(1)
+    }
+}
+
+impl Neighborhood {
+    /// Returns the [Offsets][Offset] of the neighbors, in the order they are passed to the closure of [`Life::play_with`].
+    ///
+    /// The built-in neighborhoods are ordered row by row from the top left, without the [Cell] itself, so `Neighborhood::Moore(1)` has the same order as the `[Cell; 8]` of [`Life::play`] and [CellNeighbors][crate::CellNeighbors].
+    pub fn offsets(&self) -> Vec<Offset> {
+        let square = |radius: usize| {
+            let radius = radius as isize;
+            (-radius..=radius).flat_map(move |dy| (-radius..=radius).map(move |dx| Offset::new(dx, dy))).filter(|&offset| offset != Offset::default())
+        };
+
+        match self {
+            Self::Moore(radius) => square(*radius).collect(),
+            Self::VonNeumann(radius) => square(*radius).filter(|offset| offset.dx.unsigned_abs() + offset.dy.unsigned_abs() <= *radius).collect(),
+            Self::Hexagonal => square(1).filter(|&offset| offset != Offset::new(1, -1) && offset != Offset::new(-1, 1)).collect(),
+            Self::Custom(offsets) => offsets.clone(),
+        }
+    }
+
+    /// Returns the index of the neighbor at `offset` in the neighbors passed to the closure of [`Life::play_with`], or `None` if it isn't a neighbor.
+    ///
+    /// This computes the [`offsets`][Neighborhood::offsets], so closures should look up the indices they need once, outside of the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// assert_eq!(Neighborhood::Moore(1).index_of(Offset::new(1, 1)), Some(7));
+    /// assert_eq!(Neighborhood::VonNeumann(2).index_of(Offset::new(0, -2)), Some(0));
+    /// assert_eq!(Neighborhood::Hexagonal.index_of(Offset::new(1, -1)), None);
+    /// ```
+    pub fn index_of(&self, offset: Offset) -> Option<usize> {
+        self.offsets().into_iter().position(|neighbor| neighbor == offset)
+    }
+}
+
+/// Helpers for the neighbors passed to the closure of [`Life::play_with`], like [CellNeighbors][crate::CellNeighbors] for `[Cell; 8]`.
+///
+/// Since arrays coerce to slices, these helpers also work on `[Cell; N]` of any length.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let neighborhood = Neighborhood::Moore(2);
+/// let offsets = neighborhood.offsets();
+///
+/// let mut others = [Cell::dead(); 24];
+/// others[neighborhood.index_of(Offset::new(2, 0)).unwrap()] = Cell::red();
+///
+/// assert_eq!(others.alive(), 1);
+/// assert_eq!(others.with_offsets(&offsets).find(|(_, cell)| cell.is_alive()), Some((Offset::new(2, 0), Cell::red())));
+/// ```
+pub trait NeighborSlice {
+    /// Returns the number of living [Cells][Cell].
+    ///
+    /// Whether a [Cell] is alive is determined by the [`Cell::is_alive`] method.
+    fn alive(&self) -> usize;
+
+    /// Returns the number of [Cells][Cell] that are alive according to `aliveness`.
+    fn alive_by(&self, aliveness: Aliveness) -> usize;
+
+    /// Returns the average color of the [Cells][Cell], or [`Cell::black`] if there are none.
+    fn average(&self) -> Cell;
+
+    /// Returns an iterator over the [Cells][Cell] paired with the [Offsets][Offset] they are at, usually the [`offsets`][Neighborhood::offsets] of the [Neighborhood] they were collected with.
+    fn with_offsets<'a>(&'a self, offsets: &'a [Offset]) -> impl Iterator<Item = (Offset, Cell)> + 'a;
+}
+
+impl NeighborSlice for [Cell] {
+    #[inline]
+    fn alive(&self) -> usize {
+        self.iter().filter(|cell| cell.is_alive()).count()
+    }
+
+    #[inline]
+    fn alive_by(&self, aliveness: Aliveness) -> usize {
+        self.iter().filter(|&&cell| aliveness.is_alive(cell)).count()
+    }
+
+    #[inline]
+    fn with_offsets<'a>(&'a self, offsets: &'a [Offset]) -> impl Iterator<Item = (Offset, Cell)> + 'a {
+        offsets.iter().copied().zip(self.iter().copied())
+    }
+
+    fn average(&self) -> Cell {
+        if self.is_empty() {
+            return Cell::black();
+        }
+
+        let sum = self.iter().fold([0usize; 3], |[r, g, b], cell| [r + cell.r as usize, g + cell.g as usize, b + cell.b as usize]);
+        let average = |sum: usize| ((sum + self.len() / 2) / self.len()) as u8;
+
+        Cell {
+            r: average(sum[0]),
+            g: average(sum[1]),
+            b: average(sum[2]),
+        }
+    }
+}
+
+/// Convenience trait for the `[Cell; 6]` of a [`Neighborhood::Hexagonal`], like [CellNeighbors][crate::CellNeighbors] for `[Cell; 8]`.
+///
+/// The directions are named after the square grid the hexagonal grid is stored in, so the top left and bottom right neighbors are the ones that remain diagonal.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut life = Life::<3, 3>::default();
+/// life.set(2, 2, Cell::alive());
+///
+/// life.play_with(&Neighborhood::Hexagonal, |this, others, _, _| {
+///     let others = <[Cell; 6]>::try_from(others).unwrap();
+///
+///     match others.bottom_right().is_alive() {
+///         true => Cell::red(),
+///         false => this,
+///     }
+/// });
+///
+/// assert_eq!(life.get(1, 1), Cell::red());
+/// assert_eq!(life.get(0, 0), Cell::dead());
+///
+/// let navy = Cell { r: 0, g: 0, b: 128 };
+/// let others = [navy, navy, Cell::alive(), navy, navy, Cell::black()];
+/// assert_eq!(others.alive(), 5);
+/// assert_eq!(others.alive_by(Aliveness::Background(navy)), 2);
+/// ```
+pub trait HexNeighbors {
+    /// The type of the neighboring cells.
+    type Cell;
+
+    /// Returns the number of neighboring alive cells.
+    fn alive(self) -> u8;
+
+    /// Returns the number of neighboring cells that are alive according to `aliveness`.
+    fn alive_by(self, aliveness: Aliveness) -> u8;
+
+    /// Returns the cell at [`Offset::new(-1, -1)`](Offset::new).
+    fn top_left(self) -> Self::Cell;
+    /// Returns the cell at [`Offset::new(0, -1)`](Offset::new).
+    fn top(self) -> Self::Cell;
+    /// Returns the cell at [`Offset::new(-1, 0)`](Offset::new).
+    fn left(self) -> Self::Cell;
+    /// Returns the cell at [`Offset::new(1, 0)`](Offset::new).
+    fn right(self) -> Self::Cell;
+    /// Returns the cell at [`Offset::new(0, 1)`](Offset::new).
+    fn bottom(self) -> Self::Cell;
+    /// Returns the cell at [`Offset::new(1, 1)`](Offset::new).
+    fn bottom_right(self) -> Self::Cell;
+}
+
+impl<C: CellLike> HexNeighbors for [C; 6] {
+    type Cell = C;
+
+    #[inline]
+    fn alive(self) -> u8 {
+        self.into_iter().filter(|cell| cell.is_alive()).count() as u8
+    }
+
+    #[inline]
+    fn alive_by(self, aliveness: Aliveness) -> u8 {
+        self.into_iter().filter(|&cell| cell.is_alive_by(aliveness)).count() as u8
+    }
+
+    #[inline]
+    fn top_left(self) -> C {
+        self[0]
+    }
+
+    #[inline]
+    fn top(self) -> C {
+        self[1]
+    }
+
+    #[inline]
+    fn left(self) -> C {
+        self[2]
+    }
+
+    #[inline]
+    fn right(self) -> C {
+        self[3]
+    }
+
+    #[inline]
+    fn bottom(self) -> C {
+        self[4]
+    }
+
+    #[inline]
+    fn bottom_right(self) -> C {
+        self[5]
+    }
+}
+
+/// Returns the next generation of a `width` × `height` board whose [Cells][Cell] are returned by `get`, with the neighbors at `offsets` and the Cells [normalized][crate::normalized] by `aliveness`.
+pub(crate) fn step_with(width: usize, height: usize, boundary: BoundaryMode, aliveness: Aliveness, offsets: &[Offset], get: impl Fn(usize, usize) -> Cell, mut f: impl FnMut(Cell, &[Cell], usize, usize) -> Cell) -> Vec<Cell> {
+    let mut neighbors = vec![boundary.outside(); offsets.len()];
+    let mut next = Vec::with_capacity(width * height);
+
+    for y in 0..height {
+        for x in 0..width {
+            for (neighbor, offset) in neighbors.iter_mut().zip(offsets) {
+                let nx = boundary.resolve(x as isize + offset.dx, width);
+                let ny = boundary.resolve(y as isize + offset.dy, height);
+
+                *neighbor = match (nx, ny) {
+                    (Some(nx), Some(ny)) => get(nx, ny),
+                    _ => boundary.outside(),
+                };
+            }
+
+            next.push(normalized(aliveness, |this, others: &mut [Cell]| f(this, others, x, y), get(x, y), &mut neighbors[..]));
+        }
+    }
+
+    next
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Invokes the given closure on each [Cell] in the Life, with the neighbors given by `neighborhood`.
+    ///
+    /// This works like [`play`][Life::play], but the closure receives the neighbors as a slice in the order of [`Neighborhood::offsets`]. The [NeighborSlice] trait adds helpers like [`alive`][NeighborSlice::alive] to it.
+    ///
+    /// # Examples
+    ///
+    /// A von Neumann rule where a [Cell] is alive if an odd number of its 4 neighbors is:
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<3, 3>::default();
+    /// life.set(1, 1, Cell::alive());
+    ///
+    /// life.play_with(&Neighborhood::VonNeumann(1), |_, others, _, _| Cell::from(others.alive() % 2 == 1));
+    ///
+    /// assert_eq!(life, Life::from([
+    ///     [false, true, false],
+    ///     [true, false, true],
+    ///     [false, true, false],
+    /// ]));
+    /// ```
+    pub fn play_with(&mut self, neighborhood: &Neighborhood, f: impl FnMut(Cell, &[Cell], usize, usize) -> Cell) {
+        let next = step_with(WIDTH, HEIGHT, self.boundary, self.aliveness, &neighborhood.offsets(), |x, y| self.cells[y][x], f);
+        self.cells.as_flattened_mut().copy_from_slice(&next);
+    }
+
+    /// Invokes [`play_with`][Life::play_with] `n` times.
+    #[inline]
+    pub fn play_with_for(&mut self, n: u32, neighborhood: &Neighborhood, mut f: impl FnMut(Cell, &[Cell], usize, usize) -> Cell) {
+        for _ in 0..n {
+            self.play_with(neighborhood, &mut f);
+        }
+    }
+}
+
+impl DynLife {
+    /// Invokes the given closure on each [Cell] in the DynLife, with the neighbors given by `neighborhood`.
+    ///
+    /// This works exactly like [`Life::play_with`].
+    pub fn play_with(&mut self, neighborhood: &Neighborhood, f: impl FnMut(Cell, &[Cell], usize, usize) -> Cell) {
+        let next = step_with(self.width(), self.height(), self.boundary, self.aliveness, &neighborhood.offsets(), |x, y| self.get(x, y), f);
+        self.cells_mut().copy_from_slice(&next);
+    }
+
+    /// Invokes [`play_with`][DynLife::play_with] `n` times.
+    #[inline]
+    pub fn play_with_for(&mut self, n: u32, neighborhood: &Neighborhood, mut f: impl FnMut(Cell, &[Cell], usize, usize) -> Cell) {
+        for _ in 0..n {
+            self.play_with(neighborhood, &mut f);
+        }
+    }
+}
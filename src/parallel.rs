@@ -0,0 +1,155 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::{normalized, Cell, Life};
+
+/// Runs `run` for every task index in `0..tasks` on up to `threads` threads and returns the results in task order.
+///
+/// Every thread starts with an equal, contiguous share of the tasks. A thread that runs out of tasks steals from the back of the queue of another thread, so expensive regions don't leave the other threads idle.
+pub(crate) fn work_stealing<T: Send>(tasks: usize, threads: usize, run: impl Fn(usize) -> T + Sync) -> Vec<T> {
+    let threads = threads.clamp(1, tasks.max(1));
+    let queues: Vec<_> = (0..threads).map(|thread| Mutex::new((tasks * thread / threads..tasks * (thread + 1) / threads).collect::<VecDeque<_>>())).collect();
+
+    let mut results: Vec<_> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads).map(|thread| {
+            let (queues, run) = (&queues, &run);
+
+            scope.spawn(move || {
+                let mut done = Vec::new();
+
+                loop {
+                    let own = queues[thread].lock().unwrap().pop_front();
+                    let task = own.or_else(|| (1..threads).find_map(|offset| queues[(thread + offset) % threads].lock().unwrap().pop_back()));
+
+                    match task {
+                        Some(task) => done.push((task, run(task))),
+                        None => break done,
+                    }
+                }
+            })
+        }).collect();
+
+        workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect()
+    });
+
+    results.sort_unstable_by_key(|&(task, _)| task);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Returns the number of threads to use for parallel stepping.
+fn threads() -> usize {
+    std::thread::available_parallelism().map_or(1, |threads| threads.get())
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Invokes the given closure on each [Cell] in the Life in parallel, splitting the rows across all cores.
+    ///
+    /// The result is identical to [`play`][Life::play], but the closure has to be [Sync] and is invoked in no particular order. Use [`play_par_tiled`][Life::play_par_tiled] to choose how the board is split up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<100, 100>::default();
+    /// life.set(50, 49, Cell::alive());
+    /// life.set(50, 50, Cell::alive());
+    /// life.set(50, 51, Cell::alive());
+    ///
+    /// let rule = |this: Cell, others: [Cell; 8], _, _| match others.alive() {
+    ///     2 => this,
+    ///     3 => Cell::alive(),
+    ///     _ => Cell::dead(),
+    /// };
+    ///
+    /// let mut expected = life;
+    /// expected.play(rule);
+    ///
+    /// life.play_par(rule);
+    ///
+    /// assert_eq!(life, expected);
+    /// ```
+    pub fn play_par(&mut self, f: impl Fn(Cell, [Cell; 8], usize, usize) -> Cell + Sync) {
+        // a few bands per thread, so a slow band doesn't hold up the others
+        let rows = HEIGHT.div_ceil(threads() * 4).max(1);
+
+        self.play_par_tiled(WIDTH.max(1), rows, f);
+    }
+
+    /// Invokes [`play_par`][Life::play_par] `n` times.
+    #[inline]
+    pub fn play_for_par(&mut self, n: u32, f: impl Fn(Cell, [Cell; 8], usize, usize) -> Cell + Sync) {
+        for _ in 0..n {
+            self.play_par(&f);
+        }
+    }
+
+    /// Invokes the given closure on each [Cell] in the Life in parallel, one tile of `tile_width` × `tile_height` [Cells][Cell] at a time.
+    ///
+    /// The result is identical to [`play`][Life::play], but the closure has to be [Sync] and is invoked in no particular order. The tiles are balanced between the threads by work stealing, so rules that are much more expensive in some regions than in others still keep all cores busy. Smaller tiles balance better, larger tiles have less overhead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tile_width` or `tile_height` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<16, 16>::default();
+    /// life.set(3, 4, Cell::alive());
+    ///
+    /// let rule = |this: Cell, others: [Cell; 8], _, _| Cell::from(this.is_alive() || others.alive() != 0);
+    ///
+    /// let mut expected = life;
+    /// expected.play(rule);
+    ///
+    /// life.play_par_tiled(4, 4, rule);
+    ///
+    /// assert_eq!(life, expected);
+    ///
+    /// // tiles larger than the board cover it with a single tile
+    /// expected.play(rule);
+    /// life.play_par_tiled(usize::MAX, usize::MAX, rule);
+    ///
+    /// assert_eq!(life, expected);
+    /// ```
+    #[track_caller]
+    pub fn play_par_tiled(&mut self, tile_width: usize, tile_height: usize, f: impl Fn(Cell, [Cell; 8], usize, usize) -> Cell + Sync) {
+        assert!(tile_width != 0 && tile_height != 0, "tiles must not be empty");
+
+        let columns = WIDTH.div_ceil(tile_width);
+        let tiles = columns * HEIGHT.div_ceil(tile_height);
+
+        let results = work_stealing(tiles, threads(), |tile| {
+            let (x0, y0) = (tile % columns * tile_width, tile / columns * tile_height);
+            let mut cells = Vec::with_capacity(tile_width.min(WIDTH) * tile_height.min(HEIGHT));
+
+            for y in y0..(y0 + tile_height).min(HEIGHT) {
+                for x in x0..(x0 + tile_width).min(WIDTH) {
+                    // x and y are within the board
+                    cells.push(normalized(self.aliveness, |this, others| f(this, others, x, y), self.cells[y][x], unsafe { self.get_surrounding(x, y) }));
+                }
+            }
+
+            cells
+        });
+
+        for (tile, cells) in results.into_iter().enumerate() {
+            let (x0, y0) = (tile % columns * tile_width, tile / columns * tile_height);
+            let width = tile_width.min(WIDTH - x0);
+
+            for (row, cells) in self.cells[y0..].iter_mut().zip(cells.chunks_exact(width)) {
+                row[x0..x0 + width].copy_from_slice(cells);
+            }
+        }
+    }
+
+    /// Invokes [`play_par_tiled`][Life::play_par_tiled] `n` times.
+    #[inline]
+    #[track_caller]
+    pub fn play_for_par_tiled(&mut self, n: u32, tile_width: usize, tile_height: usize, f: impl Fn(Cell, [Cell; 8], usize, usize) -> Cell + Sync) {
+        for _ in 0..n {
+            self.play_par_tiled(tile_width, tile_height, &f);
+        }
+    }
+}
@@ -0,0 +1,715 @@
+//! Reading and writing standard pattern formats.
+//!
+//! Patterns from collections like the LifeWiki come in a few text formats, all of which only know living and dead [Cells][Cell]:
+//!
+//! * [RLE] (`.rle`), the most common one, with [`Life::from_rle`] and [`Life::to_rle`]
+//! * [Plaintext] (`.cells`), with [`Life::from_plaintext`] and [`Life::to_plaintext`]
+//! * [Life 1.06] (`.lif`), a list of coordinates, with [`Life::from_life106`] and [`Life::to_life106`]
+//!
+//! Patterns copied to the clipboard by Golly, or as text from anywhere else, are read and written with [`Life::from_clipboard_text`] and [`Life::to_clipboard_text`].
+//!
+//! Objects can also be looked up by their apgcode with [`fetch`] and encoded as one with [`to_apgcode`].
+//!
+//! When reading, living [Cells][Cell] become [`Cell::alive`] and dead ones [`Cell::dead`]. A pattern smaller than the Life is placed in its top left corner. A pattern larger than the Life is an error, since silently cutting it off would change how it evolves. When writing, [Cells][Cell] are living if they are alive according to the [`aliveness`][Life#structfield.aliveness] of the board. A Life of another [CellLike] type is read with [`CellLike::from_rgb`] and written with [`CellLike::is_alive_by`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use your_game_of_life::*;
+//! let glider = Life::<3, 3>::from_rle("#N Glider\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!").unwrap();
+//!
+//! assert_eq!(glider.to_plaintext(), ".O.\n..O\nOOO\n");
+//! ```
+//!
+//! [RLE]: https://conwaylife.com/wiki/Run_Length_Encoded
+//! [Plaintext]: https://conwaylife.com/wiki/Plaintext
+//! [Life 1.06]: https://conwaylife.com/wiki/Life_1.06
+
+use std::fmt::{self, Write};
+
+use crate::{Cell, CellLike, DynLife, Life, Rule};
+
+/// The largest number of [Cells][Cell] a pattern may have, about 67 million.
+///
+/// Readers creating a [DynLife] just large enough for the pattern, like [`DynLife::from_rle`], reject larger patterns with [`PatternError::TooLarge`], so a short header like `x = 100000, y = 100000` can't make them allocate gigabytes of memory.
+pub const MAX_CELLS: usize = 1 << 26;
+
+/// The error returned when a pattern can't be read.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatternError {
+    /// The pattern lacks a required header line.
+    MissingHeader,
+    /// A header line couldn't be understood.
+    InvalidHeader {
+        /// The line number, starting at 1.
+        line: usize,
+    },
+    /// The pattern contains a character that isn't allowed at that position.
+    InvalidCharacter {
+        /// The line number, starting at 1.
+        line: usize,
+        /// The offending character.
+        character: char,
+    },
+    /// A number couldn't be read.
+    InvalidNumber {
+        /// The line number, starting at 1.
+        line: usize,
+    },
+    /// The pattern doesn't fit into the Life, or has more than [MAX_CELLS] [Cells][Cell].
+    TooLarge {
+        /// The width of the pattern.
+        width: usize,
+        /// The height of the pattern.
+        height: usize,
+    },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::MissingHeader => write!(f, "pattern is missing its header"),
+            Self::InvalidHeader { line } => write!(f, "invalid header on line {line}"),
+            Self::InvalidCharacter { line, character } => write!(f, "invalid character {character:?} on line {line}"),
+            Self::InvalidNumber { line } => write!(f, "invalid number on line {line}"),
+            Self::TooLarge { width, height } => write!(f, "pattern of {width}x{height} cells doesn't fit"),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// A pattern read from text: its size, the positions of its living [Cells][Cell] and the rule it's meant for, if given.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct Parsed {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) alive: Vec<(usize, usize)>,
+    pub(crate) rule: Option<String>,
+}
+
+impl Parsed {
+    /// Returns true if the pattern is larger than `limit` or has more than [MAX_CELLS] [Cells][Cell].
+    fn exceeds(&self, limit: (usize, usize)) -> bool {
+        self.width > limit.0 || self.height > limit.1 || self.width.saturating_mul(self.height) > MAX_CELLS
+    }
+
+    /// Extends the pattern to include `(x, y)`, failing if it would grow beyond `limit`.
+    fn grow(&mut self, x: usize, y: usize, limit: (usize, usize)) -> Result<(), PatternError> {
+        self.width = self.width.max(x.saturating_add(1));
+        self.height = self.height.max(y.saturating_add(1));
+
+        match self.exceeds(limit) {
+            true => Err(PatternError::TooLarge {
+                width: self.width,
+                height: self.height,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    fn push(&mut self, x: usize, y: usize, limit: (usize, usize)) -> Result<(), PatternError> {
+        self.grow(x, y, limit)?;
+        self.alive.push((x, y));
+        Ok(())
+    }
+
+    /// Places the pattern in the top left corner of a new Life.
+    ///
+    /// The pattern must have been read with a limit of at most `(WIDTH, HEIGHT)`.
+    pub(crate) fn into_life<const HEIGHT: usize, const WIDTH: usize, C: CellLike>(self) -> Life<HEIGHT, WIDTH, C> {
+        let mut life = Life::default();
+
+        for (x, y) in self.alive {
+            life.cells[y][x] = C::from_rgb(Cell::alive());
+        }
+
+        life
+    }
+
+    /// Reads an RLE pattern of at most `limit` (width, height) [Cells][Cell].
+    pub(crate) fn rle(text: &str, limit: (usize, usize)) -> Result<Self, PatternError> {
+        let mut lines = rle_lines(text);
+        let (header_line, header) = lines.next().ok_or(PatternError::MissingHeader)?;
+
+        let mut pattern = Self::default();
+        let mut fields = header;
+
+        while !fields.is_empty() {
+            let (key, rest) = fields.split_once('=').ok_or(PatternError::InvalidHeader { line: header_line })?;
+
+            // the rule comes last and may contain commas, like the size of a bounded grid in `B3/S23:T100,100`
+            let (value, rest) = match key.trim() {
+                "rule" => (rest, ""),
+                _ => rest.split_once(',').unwrap_or((rest, "")),
+            };
+            let value = value.trim();
+            fields = rest;
+
+            match key.trim() {
+                "x" => pattern.width = value.parse().map_err(|_| PatternError::InvalidNumber { line: header_line })?,
+                "y" => pattern.height = value.parse().map_err(|_| PatternError::InvalidNumber { line: header_line })?,
+                "rule" => pattern.rule = Some(value.to_owned()),
+                _ => return Err(PatternError::InvalidHeader { line: header_line }),
+            }
+        }
+
+        if pattern.exceeds(limit) {
+            return Err(PatternError::TooLarge {
+                width: pattern.width,
+                height: pattern.height,
+            });
+        }
+
+        pattern.rle_runs(lines, limit)
+    }
+
+    /// Reads the runs of an RLE pattern following its header line.
+    ///
+    /// The states of multi-state patterns, `A` to `X` and the two letter states `pA` to `yO`, are all read as living.
+    fn rle_runs<'a>(mut self, lines: impl Iterator<Item = (usize, &'a str)>, limit: (usize, usize)) -> Result<Self, PatternError> {
+        let (mut x, mut y) = (0usize, 0usize);
+        let mut count: Option<usize> = None;
+
+        'lines: for (line, text) in lines {
+            for character in text.chars() {
+                if let Some(digit) = character.to_digit(10) {
+                    let run = count.unwrap_or(0).checked_mul(10).and_then(|run| run.checked_add(digit as usize));
+                    count = Some(run.ok_or(PatternError::InvalidNumber { line })?);
+                    continue;
+                }
+                // the prefix of a two letter state, which always ends in a capital letter
+                if character.is_whitespace() || matches!(character, 'p'..='y') {
+                    continue;
+                }
+
+                let run = count.take().unwrap_or(1);
+
+                match character {
+                    'b' | '.' => x = x.saturating_add(run),
+                    'o' | 'A'..='X' => {
+                        self.grow(x.saturating_add(run - 1), y, limit)?;
+
+                        for _ in 0..run {
+                            self.push(x, y, limit)?;
+                            x += 1;
+                        }
+                    }
+                    '$' => {
+                        x = 0;
+                        y = y.saturating_add(run);
+                    }
+                    '!' => break 'lines,
+                    character => return Err(PatternError::InvalidCharacter { line, character }),
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Reads a pattern copied to the clipboard, as by Golly, of at most `limit` (width, height) [Cells][Cell].
+    ///
+    /// The text is read as Life 1.06 if it starts with its header, as plaintext if every line that isn't a `!` comment only holds `.`, `O` and `*`, and as RLE otherwise. Unlike RLE files, the RLE may lack its header line.
+    pub(crate) fn clipboard(text: &str, limit: (usize, usize)) -> Result<Self, PatternError> {
+        if text.trim_start().starts_with("#Life 1.06") {
+            return Self::life106(text, limit);
+        }
+
+        // text pasted into code is often indented, which plaintext doesn't allow
+        let trimmed: Vec<_> = text.lines().map(str::trim).collect();
+        let start = trimmed.iter().position(|line| !line.is_empty()).unwrap_or(trimmed.len());
+        let end = trimmed.iter().rposition(|line| !line.is_empty()).map_or(start, |end| end + 1);
+        let trimmed = &trimmed[start..end];
+
+        let plaintext = trimmed.iter().filter(|line| !line.starts_with('!')).all(|line| line.chars().all(|character| matches!(character, '.' | 'O' | '*')));
+        if plaintext && !trimmed.is_empty() {
+            return Self::plaintext(&trimmed.join("\n"), limit);
+        }
+
+        let mut lines = rle_lines(text).peekable();
+        match lines.peek() {
+            Some((_, line)) if line.starts_with('x') => Self::rle(text, limit),
+            _ => Self::default().rle_runs(lines, limit),
+        }
+    }
+
+    /// Reads a plaintext pattern of at most `limit` (width, height) [Cells][Cell].
+    pub(crate) fn plaintext(text: &str, limit: (usize, usize)) -> Result<Self, PatternError> {
+        let mut pattern = Self::default();
+
+        for (y, (line, text)) in text.lines().enumerate().map(|(i, line)| (i + 1, line.trim_end())).filter(|(_, line)| !line.starts_with('!')).enumerate() {
+            pattern.grow(0, y, (usize::MAX, limit.1))?;
+
+            for (x, character) in text.chars().enumerate() {
+                match character {
+                    '.' => pattern.grow(x, y, limit)?,
+                    'O' | '*' => pattern.push(x, y, limit)?,
+                    character => return Err(PatternError::InvalidCharacter { line, character }),
+                }
+            }
+        }
+
+        Ok(pattern)
+    }
+
+    /// Reads a Life 1.06 pattern of at most `limit` (width, height) [Cells][Cell].
+    pub(crate) fn life106(text: &str, limit: (usize, usize)) -> Result<Self, PatternError> {
+        let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line.trim())).filter(|(_, line)| !line.is_empty());
+
+        match lines.next() {
+            Some((_, "#Life 1.06")) => (),
+            _ => return Err(PatternError::MissingHeader),
+        }
+
+        let mut positions = Vec::new();
+
+        for (line, text) in lines.filter(|(_, line)| !line.starts_with('#')) {
+            let mut numbers = text.split_whitespace().map(|number| number.parse::<i64>().map_err(|_| PatternError::InvalidNumber { line }));
+
+            match (numbers.next(), numbers.next(), numbers.next()) {
+                (Some(x), Some(y), None) => positions.push((x?, y?)),
+                _ => return Err(PatternError::InvalidNumber { line }),
+            }
+        }
+
+        let mut pattern = Self::default();
+        let min_x = positions.iter().map(|&(x, _)| x).min().unwrap_or(0);
+        let min_y = positions.iter().map(|&(_, y)| y).min().unwrap_or(0);
+
+        for (x, y) in positions {
+            pattern.push(x.abs_diff(min_x).try_into().unwrap_or(usize::MAX), y.abs_diff(min_y).try_into().unwrap_or(usize::MAX), limit)?;
+        }
+
+        Ok(pattern)
+    }
+}
+
+/// Returns the trimmed lines of an RLE pattern with their line numbers, starting at 1, skipping empty lines and `#` comments.
+fn rle_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines().enumerate().map(|(i, line)| (i + 1, line.trim())).filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Writes living and dead [Cells][Cell] as RLE runs, wrapping lines at 70 characters.
+pub(crate) fn write_rle(width: usize, height: usize, rule: &str, alive: impl Fn(usize, usize) -> bool) -> String {
+    let mut tokens = Vec::new();
+    let mut last_row = 0;
+
+    for y in 0..height {
+        let mut runs: Vec<(usize, char)> = Vec::new();
+
+        for x in 0..width {
+            let tag = match alive(x, y) {
+                true => 'o',
+                false => 'b',
+            };
+
+            match runs.last_mut() {
+                Some((count, last)) if *last == tag => *count += 1,
+                _ => runs.push((1, tag)),
+            }
+        }
+
+        if runs.last().is_some_and(|&(_, tag)| tag == 'b') {
+            runs.pop();
+        }
+
+        if runs.is_empty() {
+            continue;
+        }
+
+        if y != last_row {
+            tokens.push((y - last_row, '$'));
+            last_row = y;
+        }
+        tokens.extend(runs);
+    }
+
+    let mut rle = format!("x = {width}, y = {height}, rule = {rule}\n");
+    let mut line_len = 0;
+
+    for token in tokens.into_iter().map(|(count, tag)| match count {
+        1 => tag.to_string(),
+        count => format!("{count}{tag}"),
+    }).chain(["!".to_owned()]) {
+        if line_len + token.len() > 70 {
+            rle.push('\n');
+            line_len = 0;
+        }
+
+        line_len += token.len();
+        rle.push_str(&token);
+    }
+
+    rle.push('\n');
+    rle
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Life<HEIGHT, WIDTH, C> {
+    /// Reads a pattern in the RLE format.
+    ///
+    /// Comment lines starting with `#` are skipped and the rule in the header is ignored. Multi-state patterns are read with every non-dead state as living.
+    pub fn from_rle(rle: &str) -> Result<Self, PatternError> {
+        Ok(Parsed::rle(rle, (WIDTH, HEIGHT))?.into_life())
+    }
+
+    /// Writes the Life in the RLE format, with `B3/S23` as the rule in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let blinker = Life::<3, 3>::from([[false; 3], [true; 3], [false; 3]]);
+    ///
+    /// assert_eq!(blinker.to_rle(), "x = 3, y = 3, rule = B3/S23\n$3o!\n");
+    /// ```
+    pub fn to_rle(&self) -> String {
+        write_rle(WIDTH, HEIGHT, "B3/S23", |x, y| self.cells[y][x].is_alive_by(self.aliveness))
+    }
+
+    /// Reads a pattern copied to the clipboard, for example by selecting it in [Golly] and pressing Ctrl+C.
+    ///
+    /// Golly copies patterns as RLE, with comment lines like `#CXRLE Pos=-1,-1` and a rule like `B3/S23:T100,100` in the header, which are ignored like in [`from_rle`][Life::from_rle]. Text copied from elsewhere is accepted too: RLE without its header line, plaintext and Life 1.06 are recognized by their contents, and indented lines, as in string literals, are trimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let copied = "#CXRLE Pos=-1,-1\nx = 3, y = 3, rule = B3/S23:P16,16\nbo$2bo$3o!\n";
+    /// let glider = Life::<3, 3>::from_clipboard_text(copied).unwrap();
+    ///
+    /// assert_eq!(glider.to_plaintext(), ".O.\n..O\nOOO\n");
+    /// assert_eq!(Life::<3, 3>::from_clipboard_text("bo$2bo$3o!"), Ok(glider));
+    /// assert_eq!(Life::<3, 3>::from_clipboard_text("
+    ///     .O.
+    ///     ..O
+    ///     OOO
+    /// "), Ok(glider));
+    /// ```
+    ///
+    /// [Golly]: https://golly.sourceforge.io
+    pub fn from_clipboard_text(text: &str) -> Result<Self, PatternError> {
+        Ok(Parsed::clipboard(text, (WIDTH, HEIGHT))?.into_life())
+    }
+
+    /// Writes the Life in the RLE format with `rule` in the header, the way Golly copies patterns to the clipboard, so it can be pasted into Golly with Ctrl+V.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let blinker = Life::<3, 3>::from([[false; 3], [true; 3], [false; 3]]);
+    /// let highlife = Rule::new(&[3, 6], &[2, 3]);
+    ///
+    /// assert_eq!(blinker.to_clipboard_text(&highlife), "x = 3, y = 3, rule = B36/S23\n$3o!\n");
+    /// ```
+    pub fn to_clipboard_text(&self, rule: &Rule) -> String {
+        write_rle(WIDTH, HEIGHT, &rule.to_string(), |x, y| self.cells[y][x].is_alive_by(self.aliveness))
+    }
+
+    /// Reads a pattern in the plaintext format, where `.` is a dead [Cell] and `O` a living one.
+    ///
+    /// Comment lines starting with `!` are skipped. `*` is accepted as a living [Cell] too.
+    pub fn from_plaintext(plaintext: &str) -> Result<Self, PatternError> {
+        Ok(Parsed::plaintext(plaintext, (WIDTH, HEIGHT))?.into_life())
+    }
+
+    /// Writes the Life in the plaintext format, with every row ending in a newline.
+    pub fn to_plaintext(&self) -> String {
+        let mut plaintext = String::with_capacity(HEIGHT * (WIDTH + 1));
+
+        for row in &self.cells {
+            plaintext.extend(row.iter().map(|cell| match cell.is_alive_by(self.aliveness) {
+                true => 'O',
+                false => '.',
+            }));
+            plaintext.push('\n');
+        }
+
+        plaintext
+    }
+
+    /// Reads a pattern in the Life 1.06 format.
+    ///
+    /// The coordinates may be negative. The pattern is shifted so that its leftmost and topmost living [Cells][Cell] are on the edges of the Life.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<2, 3>::from_life106("#Life 1.06\n-1 0\n1 -1\n").unwrap();
+    ///
+    /// assert_eq!(life.to_plaintext(), "..O\nO..\n");
+    /// ```
+    pub fn from_life106(life106: &str) -> Result<Self, PatternError> {
+        Ok(Parsed::life106(life106, (WIDTH, HEIGHT))?.into_life())
+    }
+
+    /// Writes the Life in the Life 1.06 format, with the top left [Cell] at `0 0`.
+    pub fn to_life106(&self) -> String {
+        let mut life106 = String::from("#Life 1.06\n");
+
+        for (y, row) in self.cells.iter().enumerate() {
+            for (x, _) in row.iter().enumerate().filter(|(_, cell)| cell.is_alive_by(self.aliveness)) {
+                let _ = writeln!(life106, "{x} {y}");
+            }
+        }
+
+        life106
+    }
+}
+
+/// The error returned when an apgcode can't be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApgcodeError {
+    /// The apgcode doesn't start with a known prefix like `xs4_`, `xp2_` or `xq4_`.
+    UnknownPrefix,
+    /// The apgcode contains a character that isn't allowed at that position.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for ApgcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::UnknownPrefix => write!(f, "apgcode has an unknown prefix"),
+            Self::InvalidCharacter(character) => write!(f, "invalid character {character:?} in apgcode"),
+        }
+    }
+}
+
+impl std::error::Error for ApgcodeError {}
+
+/// Returns the number an extended Wechsler character stands for.
+fn wechsler_value(character: char) -> Option<u32> {
+    match character {
+        '0'..='9' | 'a'..='z' => character.to_digit(36),
+        _ => None,
+    }
+}
+
+/// Resolves an [apgcode] like `xq4_153`, as used by Catagolue and the LifeWiki, to the object it names.
+///
+/// Still lifes (`xs`), oscillators (`xp`) and spaceships (`xq`) are decoded from their extended Wechsler format, so this works offline and never touches the network. The returned DynLife is just large enough to hold the object, with living [Cells][Cell] as [`Cell::alive`] and dead ones as [`Cell::dead`].
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// use your_game_of_life::pattern::fetch;
+///
+/// let glider = fetch("xq4_153").unwrap();
+///
+/// assert_eq!(glider.to_rle(), "x = 3, y = 3, rule = B3/S23\n3o$2bo$bo!\n");
+/// ```
+///
+/// [apgcode]: https://conwaylife.com/wiki/Apgcode
+pub fn fetch(apgcode: &str) -> Result<DynLife, ApgcodeError> {
+    let (prefix, code) = apgcode.split_once('_').ok_or(ApgcodeError::UnknownPrefix)?;
+
+    match prefix.get(..2) {
+        Some("xs" | "xp" | "xq") if prefix.len() > 2 && prefix[2..].bytes().all(|byte| byte.is_ascii_digit()) => (),
+        _ => return Err(ApgcodeError::UnknownPrefix),
+    }
+
+    // every strip is 5 rows tall and made of columns of 5 bits, the lowest bit being the top row
+    let mut strips = vec![Vec::new()];
+    let mut characters = code.chars();
+
+    while let Some(character) = characters.next() {
+        let strip = strips.last_mut().unwrap();
+
+        match character {
+            'w' => strip.extend([0; 2]),
+            'x' => strip.extend([0; 3]),
+            'y' => {
+                let zeros = characters.next().and_then(wechsler_value).ok_or(ApgcodeError::InvalidCharacter('y'))?;
+                strip.extend(std::iter::repeat_n(0, 4 + zeros as usize));
+            }
+            'z' => strips.push(Vec::new()),
+            character => strip.push(wechsler_value(character).filter(|&value| value < 32).ok_or(ApgcodeError::InvalidCharacter(character))?),
+        }
+    }
+
+    let alive: Vec<(usize, usize)> = strips.iter()
+        .enumerate()
+        .flat_map(|(s, strip)| strip.iter().enumerate().flat_map(move |(x, &column)| (0..5).filter(move |bit| column >> bit & 1 == 1).map(move |bit| (x, s * 5 + bit))))
+        .collect();
+
+    let min_x = alive.iter().map(|&(x, _)| x).min().unwrap_or(0);
+    let min_y = alive.iter().map(|&(_, y)| y).min().unwrap_or(0);
+    let width = alive.iter().map(|&(x, _)| x + 1 - min_x).max().unwrap_or(0);
+    let height = alive.iter().map(|&(_, y)| y + 1 - min_y).max().unwrap_or(0);
+
+    let mut life = DynLife::new(width, height);
+
+    for (x, y) in alive {
+        life.set(x - min_x, y - min_y, Cell::alive());
+    }
+
+    Ok(life)
+}
+
+/// Returns the living [Cells][Cell] of the board cut down to their bounding box, and the position of the box on the board.
+fn trimmed(life: &DynLife) -> (DynLife, usize, usize) {
+    let alive = || (0..life.height()).flat_map(|y| (0..life.width()).map(move |x| (x, y))).filter(|&(x, y)| life.aliveness.is_alive(life.get(x, y)));
+
+    let (min_x, min_y) = alive().fold((usize::MAX, usize::MAX), |(min_x, min_y), (x, y)| (min_x.min(x), min_y.min(y)));
+    let (max_x, max_y) = alive().fold((0, 0), |(max_x, max_y), (x, y)| (max_x.max(x + 1), max_y.max(y + 1)));
+
+    if min_x == usize::MAX {
+        return (DynLife::new(0, 0), 0, 0);
+    }
+
+    let mut trimmed = DynLife::new(max_x - min_x, max_y - min_y);
+
+    for (x, y) in alive() {
+        trimmed.set(x - min_x, y - min_y, Cell::alive());
+    }
+
+    (trimmed, min_x, min_y)
+}
+
+/// Returns the extended Wechsler format of the living [Cells][Cell] of the board, without a prefix.
+fn wechsler(life: &DynLife) -> String {
+    let mut code = String::new();
+
+    for strip in 0..life.height().div_ceil(5) {
+        if strip != 0 {
+            code.push('z');
+        }
+
+        let columns: Vec<u32> = (0..life.width())
+            .map(|x| (0..5).filter(|bit| strip * 5 + bit < life.height() && life.aliveness.is_alive(life.get(x, strip * 5 + bit))).fold(0, |column, bit| column | 1 << bit))
+            .collect();
+        let len = columns.iter().rposition(|&column| column != 0).map_or(0, |last| last + 1);
+
+        let mut zeros = 0;
+
+        for &column in columns[..len].iter().chain([&u32::MAX]) {
+            if column == 0 {
+                zeros += 1;
+                continue;
+            }
+
+            while zeros > 0 {
+                let run = zeros.min(39);
+
+                match run {
+                    1 => code.push('0'),
+                    2 => code.push('w'),
+                    3 => code.push('x'),
+                    run => {
+                        code.push('y');
+                        code.push(char::from_digit(run - 4, 36).unwrap());
+                    }
+                }
+                zeros -= run;
+            }
+
+            if column != u32::MAX {
+                code.push(char::from_digit(column, 32).unwrap());
+            }
+        }
+    }
+
+    code
+}
+
+/// Returns the variants of the board in all 8 orientations.
+fn orientations(life: &DynLife) -> impl Iterator<Item = DynLife> + '_ {
+    (0..8).map(move |symmetry| {
+        let (width, height) = match symmetry & 4 != 0 {
+            true => (life.height(), life.width()),
+            false => (life.width(), life.height()),
+        };
+        let mut oriented = DynLife::new(width, height);
+
+        for y in 0..height {
+            for x in 0..width {
+                let (x0, y0) = match (symmetry & 1 != 0, symmetry & 2 != 0) {
+                    (true, true) => (width - 1 - x, height - 1 - y),
+                    (true, false) => (width - 1 - x, y),
+                    (false, true) => (x, height - 1 - y),
+                    (false, false) => (x, y),
+                };
+                let source = match symmetry & 4 != 0 {
+                    true => (y0, x0),
+                    false => (x0, y0),
+                };
+
+                oriented.set(x, y, life.get(source.0, source.1));
+            }
+        }
+
+        oriented
+    })
+}
+
+/// Encodes an object as its [apgcode], the inverse of [`fetch`].
+///
+/// The object is stepped by `rule` until it repeats to tell still lifes (`xs`), oscillators (`xp`) and spaceships (`xq`) apart. Like on Catagolue, the code is the shortest, and then alphabetically first, code of all phases in all orientations, so every object has exactly one apgcode regardless of how it's placed. Only the living [Cells][Cell] of the board matter.
+///
+/// Returns `None` if the board has no living [Cells][Cell], or if the object doesn't repeat within `max_period` generations, like a growing or dying pattern.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// use your_game_of_life::pattern::{fetch, to_apgcode};
+///
+/// let glider = DynLife::from_plaintext(".O.\n..O\nOOO\n").unwrap();
+/// let blinker = DynLife::from([[true, true, true]]);
+///
+/// assert_eq!(to_apgcode(&glider, &Rule::CONWAY, 100).as_deref(), Some("xq4_153"));
+/// assert_eq!(to_apgcode(&blinker, &Rule::CONWAY, 100).as_deref(), Some("xp2_7"));
+/// assert_eq!(to_apgcode(&fetch("xs4_33").unwrap(), &Rule::CONWAY, 100).as_deref(), Some("xs4_33"));
+/// ```
+///
+/// [apgcode]: https://conwaylife.com/wiki/Apgcode
+pub fn to_apgcode(life: &DynLife, rule: &Rule, max_period: u32) -> Option<String> {
+    let (start, ..) = trimmed(life);
+
+    if start.width() == 0 {
+        return None;
+    }
+
+    let mut phases = vec![start.clone()];
+    let (mut dx, mut dy) = (0isize, 0isize);
+    let mut current = start.clone();
+
+    let period = (1..=max_period).find(|_| {
+        // a margin of one Cell is enough room for one generation
+        let width = current.width() + 2;
+        let mut padded = DynLife::new(width, current.height() + 2);
+        for (y, row) in current.rows().enumerate() {
+            padded.cells_mut()[(y + 1) * width + 1..][..row.len()].copy_from_slice(row);
+        }
+        padded.play_rule(rule);
+
+        let (next, x, y) = trimmed(&padded);
+        dx += x as isize - 1;
+        dy += y as isize - 1;
+        current = next;
+
+        match current == start {
+            true => true,
+            false => {
+                phases.push(current.clone());
+                false
+            }
+        }
+    })?;
+
+    let prefix = match (period, dx, dy) {
+        (1, 0, 0) => format!("xs{}", start.cells().iter().filter(|&&cell| start.aliveness.is_alive(cell)).count()),
+        (period, 0, 0) => format!("xp{period}"),
+        (period, ..) => format!("xq{period}"),
+    };
+
+    let code = phases.iter()
+        .flat_map(orientations)
+        .map(|oriented| wechsler(&oriented))
+        .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))?;
+
+    Some(format!("{prefix}_{code}"))
+}
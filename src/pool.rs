@@ -0,0 +1,98 @@
+use crate::Cell;
+
+/// A pool of reusable [Cell] buffers for temporary grids.
+///
+/// Searches and analyses create and drop huge numbers of small boards. Taking their buffers from a GridPool and giving them back when done avoids asking the allocator for new memory every time.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut pool = GridPool::new();
+///
+/// let grid = pool.take(16, Cell::dead());
+/// let address = grid.as_ptr();
+/// pool.give(grid);
+///
+/// // the same memory is handed out again
+/// let grid = pool.take(9, Cell::alive());
+/// assert_eq!(grid.as_ptr(), address);
+/// assert_eq!(grid, [Cell::alive(); 9]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridPool {
+    free: Vec<Vec<Cell>>,
+    limit: usize,
+}
+
+impl Default for GridPool {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GridPool {
+    /// The number of buffers a GridPool created with [`GridPool::new`] keeps at most.
+    pub const DEFAULT_LIMIT: usize = 64;
+
+    /// Creates an empty GridPool keeping up to [`DEFAULT_LIMIT`][GridPool::DEFAULT_LIMIT] buffers.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_limit(Self::DEFAULT_LIMIT)
+    }
+
+    /// Creates an empty GridPool keeping up to `limit` buffers. Buffers given back to a full pool are dropped.
+    #[inline]
+    pub const fn with_limit(limit: usize) -> Self {
+        Self {
+            free: Vec::new(),
+            limit,
+        }
+    }
+
+    /// Returns the number of buffers waiting to be reused.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Returns true if no buffers are waiting to be reused.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.free.is_empty()
+    }
+
+    /// Returns a buffer of `len` [Cells][Cell] all set to `fill`.
+    ///
+    /// The smallest pooled buffer that can hold `len` [Cells][Cell] is reused. If there is none, a new buffer is allocated.
+    pub fn take(&mut self, len: usize, fill: Cell) -> Vec<Cell> {
+        let best = self.free.iter()
+            .enumerate()
+            .filter(|(_, buffer)| buffer.capacity() >= len)
+            .min_by_key(|(_, buffer)| buffer.capacity())
+            .map(|(i, _)| i);
+
+        let mut buffer = match best {
+            Some(i) => self.free.swap_remove(i),
+            None => Vec::with_capacity(len),
+        };
+
+        buffer.clear();
+        buffer.resize(len, fill);
+        buffer
+    }
+
+    /// Gives a buffer back to the pool.
+    pub fn give(&mut self, buffer: Vec<Cell>) {
+        if self.free.len() < self.limit && buffer.capacity() != 0 {
+            self.free.push(buffer);
+        }
+    }
+
+    /// Drops all pooled buffers.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.free.clear();
+    }
+}
@@ -0,0 +1,317 @@
+//! Post-processing passes for rendering.
+//!
+//! These passes work on the colors of a [Life] only and are meant to be applied to a copy made for drawing, like fading out old frames for trails, blurring a glow or blending two boards, right before converting the result with [`to_rgba_buffer`] or writing it into a frame buffer with [`Life::write_rgba`].
+//!
+//! The passes run once per frame over every [Cell], so they are vectorized: on x86-64 processors supporting AVX2, which is detected at runtime, a version compiled for AVX2 is used. All versions only use integer arithmetic and give identical results.
+
+use crate::embedded::{check_len, frame_len, BufferTooSmall};
+use crate::{Cell, Life};
+
+/// Picks the fastest available version of a kernel at runtime.
+macro_rules! dispatch {
+    ($kernel:ident($($arg:expr),*)) => {{
+        #[cfg(target_arch = "x86_64")]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                #[target_feature(enable = "avx2")]
+                unsafe fn avx2(kernel: impl FnOnce()) {
+                    kernel()
+                }
+
+                // SAFETY: AVX2 support was just detected.
+                return unsafe { avx2(#[inline(always)] || $kernel($($arg),*)) };
+            }
+        }
+
+        $kernel($($arg),*)
+    }};
+}
+
+/// Returns the [Cells][Cell] of the board as bytes in RGB order, row by row.
+#[inline]
+pub(crate) fn as_bytes<const HEIGHT: usize, const WIDTH: usize>(cells: &[[Cell; WIDTH]; HEIGHT]) -> &[u8] {
+    // SAFETY: Cell is repr(C) with three u8 fields, so it has no padding and an alignment of 1.
+    unsafe { std::slice::from_raw_parts(cells.as_ptr().cast(), HEIGHT * WIDTH * 3) }
+}
+
+/// Returns the [Cells][Cell] of the board as mutable bytes in RGB order, row by row.
+#[inline]
+pub(crate) fn as_bytes_mut<const HEIGHT: usize, const WIDTH: usize>(cells: &mut [[Cell; WIDTH]; HEIGHT]) -> &mut [u8] {
+    // SAFETY: Cell is repr(C) with three u8 fields, so any bytes are valid Cells.
+    unsafe { std::slice::from_raw_parts_mut(cells.as_mut_ptr().cast(), HEIGHT * WIDTH * 3) }
+}
+
+#[inline(always)]
+fn scale_kernel(bytes: &mut [u8], factor: u8) {
+    for byte in bytes {
+        *byte = ((*byte as u16 * factor as u16 + 127) / 255) as u8;
+    }
+}
+
+#[inline(always)]
+fn lerp_kernel(bytes: &mut [u8], other: &[u8], weight: u8) {
+    for (byte, &other) in bytes.iter_mut().zip(other) {
+        *byte = ((*byte as u16 * (255 - weight as u16) + other as u16 * weight as u16 + 127) / 255) as u8;
+    }
+}
+
+#[inline(always)]
+fn rgba_kernel(rgb: &[u8], rgba: &mut [u8], alpha: u8) {
+    for (rgb, rgba) in rgb.chunks_exact(3).zip(rgba.chunks_exact_mut(4)) {
+        rgba.copy_from_slice(&[rgb[0], rgb[1], rgb[2], alpha]);
+    }
+}
+
+#[inline(always)]
+fn sum3_kernel(sums: &mut [u16], a: &[u16], b: &[u16], c: &[u16]) {
+    for (((sum, &a), &b), &c) in sums.iter_mut().zip(a).zip(b).zip(c) {
+        *sum = a + b + c;
+    }
+}
+
+#[inline(always)]
+fn average9_kernel(bytes: &mut [u8], a: &[u16], b: &[u16], c: &[u16]) {
+    for (((byte, &a), &b), &c) in bytes.iter_mut().zip(a).zip(b).zip(c) {
+        *byte = ((a + b + c + 4) / 9) as u8;
+    }
+}
+
+fn scale(bytes: &mut [u8], factor: u8) {
+    dispatch!(scale_kernel(bytes, factor))
+}
+
+fn lerp(bytes: &mut [u8], other: &[u8], weight: u8) {
+    dispatch!(lerp_kernel(bytes, other, weight))
+}
+
+fn rgba(rgb: &[u8], rgba: &mut [u8], alpha: u8) {
+    dispatch!(rgba_kernel(rgb, rgba, alpha))
+}
+
+fn sum3(sums: &mut [u16], a: &[u16], b: &[u16], c: &[u16]) {
+    dispatch!(sum3_kernel(sums, a, b, c))
+}
+
+fn average9(bytes: &mut [u8], a: &[u16], b: &[u16], c: &[u16]) {
+    dispatch!(average9_kernel(bytes, a, b, c))
+}
+
+/// Scales the color of every [Cell] by `factor`, where 255 leaves the colors unchanged and 0 makes every [Cell] black.
+///
+/// Applying this to the previous frame before drawing the next one on top gives the classic fading trails.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::post::fade;
+///
+/// let mut life = Life::<1, 2>::from([[Cell::white(), Cell::red()]]);
+/// fade(&mut life, 128);
+///
+/// assert_eq!(life.cells, [[Cell::all(128), Cell { r: 128, g: 0, b: 0 }]]);
+/// ```
+pub fn fade<const HEIGHT: usize, const WIDTH: usize>(life: &mut Life<HEIGHT, WIDTH>, factor: u8) {
+    scale(as_bytes_mut(&mut life.cells), factor);
+}
+
+/// Blends the colors of `top` over the colors of `base`, where an `alpha` of 0 leaves `base` unchanged and 255 replaces it with `top`.
+///
+/// Every [Cell] is blended like [`Cell::blend`] would.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::post::overlay;
+///
+/// let mut base = Life::<1, 1>::from([[Cell::red()]]);
+/// overlay(&mut base, &Life::from([[Cell::blue()]]), 51);
+///
+/// assert_eq!(base.cells, [[Cell::red().blend(Cell::blue(), 51)]]);
+/// ```
+pub fn overlay<const HEIGHT: usize, const WIDTH: usize>(base: &mut Life<HEIGHT, WIDTH>, top: &Life<HEIGHT, WIDTH>, alpha: u8) {
+    lerp(as_bytes_mut(&mut base.cells), as_bytes(&top.cells), alpha);
+}
+
+/// Replaces the color of every [Cell] with the average color of the 3 × 3 square around it.
+///
+/// Beyond the edges, the [`boundary`] mode of the Life decides which [Cells][Cell] are averaged.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::post::blur;
+///
+/// let mut life = Life::<3, 3>::default();
+/// life.set(1, 1, Cell::all(90));
+/// blur(&mut life);
+///
+/// assert_eq!(life.cells, [[Cell::all(10); 3]; 3]);
+/// ```
+///
+/// [`boundary`]: crate::Life#structfield.boundary
+pub fn blur<const HEIGHT: usize, const WIDTH: usize>(life: &mut Life<HEIGHT, WIDTH>) {
+    if HEIGHT == 0 || WIDTH == 0 {
+        return;
+    }
+
+    let boundary = life.boundary;
+    let cell = |x: isize, y: isize| match (boundary.resolve(x, WIDTH), boundary.resolve(y, HEIGHT)) {
+        (Some(x), Some(y)) => life.cells[y][x],
+        _ => boundary.outside(),
+    };
+
+    // the horizontal sums of each row including the rows beyond the top and bottom edges
+    let mut sums = vec![0u16; (HEIGHT + 2) * WIDTH * 3];
+    let mut padded = vec![[0u16; 3]; WIDTH + 2];
+
+    for (y, row) in sums.chunks_exact_mut(WIDTH * 3).enumerate() {
+        for (x, padded) in padded.iter_mut().enumerate() {
+            let cell = cell(x as isize - 1, y as isize - 1);
+            *padded = [cell.r as u16, cell.g as u16, cell.b as u16];
+        }
+
+        let padded = padded.as_flattened();
+        sum3(row, padded, &padded[3..], &padded[6..]);
+    }
+
+    for (y, row) in as_bytes_mut(&mut life.cells).chunks_exact_mut(WIDTH * 3).enumerate() {
+        let rows = &sums[y * WIDTH * 3..];
+        average9(row, rows, &rows[WIDTH * 3..], &rows[WIDTH * 6..]);
+    }
+}
+
+/// Returns the colors of the board as bytes in RGBA order, row by row, with every alpha set to `alpha`.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::*;
+/// use your_game_of_life::post::to_rgba_buffer;
+///
+/// let life = Life::<1, 2>::from([[Cell::red(), Cell::blue()]]);
+///
+/// assert_eq!(to_rgba_buffer(&life, 255), [255, 0, 0, 255, 0, 0, 255, 255]);
+/// ```
+pub fn to_rgba_buffer<const HEIGHT: usize, const WIDTH: usize>(life: &Life<HEIGHT, WIDTH>, alpha: u8) -> Vec<u8> {
+    let mut buffer = vec![0; HEIGHT * WIDTH * 4];
+    rgba(as_bytes(&life.cells), &mut buffer, alpha);
+    buffer
+}
+
+/// Writes `rgb`, a row of `width` pixels of `bytes` bytes each, into `buffer` with every pixel expanded to a `scale` × `scale` block.
+fn write_scaled(rgb: &[u8], width: usize, bytes: usize, scale: usize, buffer: &mut [u8], mut pixel: impl FnMut(&[u8], &mut [u8])) {
+    if width == 0 {
+        return;
+    }
+
+    let line = width * scale * bytes;
+
+    for (row, lines) in rgb.chunks_exact(width * 3).zip(buffer.chunks_exact_mut(line * scale)) {
+        let (first, rest) = lines.split_at_mut(line);
+
+        for (rgb, block) in row.chunks_exact(3).zip(first.chunks_exact_mut(scale * bytes)) {
+            for target in block.chunks_exact_mut(bytes) {
+                pixel(rgb, target);
+            }
+        }
+
+        for copy in rest.chunks_exact_mut(line) {
+            copy.copy_from_slice(first);
+        }
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Returns the colors of the board as bytes in RGB order, row by row, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<1, 2>::from([[Cell::red(), Cell::blue()]]);
+    ///
+    /// assert_eq!(life.as_flat_rgb(), [255, 0, 0, 0, 0, 255]);
+    /// ```
+    #[inline]
+    pub fn as_flat_rgb(&self) -> &[u8] {
+        as_bytes(&self.cells)
+    }
+
+    /// Writes the colors of the board into `buffer` as bytes in RGB order, row by row, returning the number of bytes written.
+    ///
+    /// If the buffer is too small, nothing is written.
+    #[inline]
+    pub fn write_rgb(&self, buffer: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        self.write_rgb_scaled(buffer, 1)
+    }
+
+    /// Writes the colors of the board into `buffer` like [`write_rgb`][Life::write_rgb], with every [Cell] expanded to a block of `scale` × `scale` pixels.
+    ///
+    /// The frame is `WIDTH * scale` pixels wide and `HEIGHT * scale` pixels high. If the buffer is too small, nothing is written. A frame too large to be addressed at all needs [`usize::MAX`] bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<1, 2>::from([[Cell::red(), Cell::blue()]]);
+    /// let mut frame = [0; 4 * 2 * 3];
+    ///
+    /// assert_eq!(life.write_rgb_scaled(&mut frame, 2), Ok(frame.len()));
+    /// assert_eq!(frame[..12], [255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255]);
+    /// assert_eq!(frame[..12], frame[12..]);
+    ///
+    /// // no buffer can hold a frame whose size overflows
+    /// assert_eq!(life.write_rgb_scaled(&mut frame, usize::MAX).unwrap_err().needed, usize::MAX);
+    /// ```
+    #[track_caller]
+    pub fn write_rgb_scaled(&self, buffer: &mut [u8], scale: usize) -> Result<usize, BufferTooSmall> {
+        assert!(scale != 0, "the scale must not be 0");
+
+        let len = frame_len(&[HEIGHT, WIDTH, scale, scale, 3]);
+        check_len(len, buffer.len())?;
+
+        match scale {
+            1 => buffer[..len].copy_from_slice(self.as_flat_rgb()),
+            _ => write_scaled(self.as_flat_rgb(), WIDTH, 3, scale, &mut buffer[..len], |rgb, pixel| pixel.copy_from_slice(rgb)),
+        }
+
+        Ok(len)
+    }
+
+    /// Writes the colors of the board into `buffer` as bytes in RGBA order, row by row, with every alpha set to `alpha`, returning the number of bytes written.
+    ///
+    /// This works like [`to_rgba_buffer`] without allocating. If the buffer is too small, nothing is written.
+    #[inline]
+    pub fn write_rgba(&self, buffer: &mut [u8], alpha: u8) -> Result<usize, BufferTooSmall> {
+        self.write_rgba_scaled(buffer, alpha, 1)
+    }
+
+    /// Writes the colors of the board into `buffer` like [`write_rgba`][Life::write_rgba], with every [Cell] expanded to a block of `scale` × `scale` pixels.
+    ///
+    /// The frame is `WIDTH * scale` pixels wide and `HEIGHT * scale` pixels high. If the buffer is too small, nothing is written. A frame too large to be addressed at all needs [`usize::MAX`] bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is 0.
+    #[track_caller]
+    pub fn write_rgba_scaled(&self, buffer: &mut [u8], alpha: u8, scale: usize) -> Result<usize, BufferTooSmall> {
+        assert!(scale != 0, "the scale must not be 0");
+
+        let len = frame_len(&[HEIGHT, WIDTH, scale, scale, 4]);
+        check_len(len, buffer.len())?;
+
+        match scale {
+            1 => rgba(self.as_flat_rgb(), &mut buffer[..len], alpha),
+            _ => write_scaled(self.as_flat_rgb(), WIDTH, 4, scale, &mut buffer[..len], |rgb, pixel| pixel.copy_from_slice(&[rgb[0], rgb[1], rgb[2], alpha])),
+        }
+
+        Ok(len)
+    }
+}
@@ -0,0 +1,15 @@
+//! The items needed by most programs, for importing them all at once.
+//!
+//! ```
+//! use your_game_of_life::prelude::*;
+//!
+//! let mut life: Life<3, 3> = ".O.\n.O.\n.O.".parse().unwrap();
+//! let rule: Rule = "B3/S23".parse().unwrap();
+//!
+//! life.play(life_like(rule, ColorPolicy::White));
+//!
+//! assert_eq!(life.to_string(), "...\nOOO\n...");
+//! ```
+
+pub use crate::rules::{life_like, totalistic, ColorPolicy, RuleSpec};
+pub use crate::{Aliveness, BoundaryMode, Cell, CellLike, CellNeighbors, DynLife, Life, NeighborSlice, Neighborhood, Offset, Point, Rect, Rule, RuleError};
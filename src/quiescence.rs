@@ -0,0 +1,150 @@
+use crate::{normalized, BoundaryMode, CellLike, Life, Point, Rect};
+
+/// Steps a [Life] while skipping the blocks of the board where nothing happens.
+///
+/// The board is divided into blocks of [`BLOCK_SIZE`][QuiescentBlocks::BLOCK_SIZE] × [`BLOCK_SIZE`][QuiescentBlocks::BLOCK_SIZE] cells. A block is quiescent once none of its cells changed for the given number of generations. When a block and the 8 blocks around it are all quiescent, every cell in it sees the same neighbors as in the generation before, so the closure isn't invoked for it and its cells are kept as they are.
+///
+/// This is only correct for closures that return the same cell for the same cell and neighbors, so closures depending on the position or on outside state should use [`Life::play`] instead. The Life must not be edited between steps, or [`reset`][QuiescentBlocks::reset] must be called after editing it.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut life = Life::<48, 48>::default();
+/// for x in 7..10 {
+///     life.set(x, 7, Cell::alive());
+/// }
+/// let mut expected = life;
+///
+/// let mut blocks = QuiescentBlocks::new(48, 48, 1);
+/// let mut calls = 0;
+///
+/// blocks.play_for(&mut life, 2, |this, others, _, _| {
+///     calls += 1;
+///     Rule::CONWAY.apply(this, others)
+/// });
+/// expected.play_rule_for(2, &Rule::CONWAY);
+///
+/// // the first generation evaluates all 9 blocks, the second skips the 5 blocks away from the blinker
+/// assert_eq!(calls, 48 * 48 + 4 * 16 * 16);
+/// assert_eq!(life, expected);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct QuiescentBlocks {
+    columns: usize,
+    rows: usize,
+    generations: u32,
+    unchanged: Vec<u32>,
+}
+
+impl QuiescentBlocks {
+    /// The width and height of a block in cells.
+    pub const BLOCK_SIZE: usize = 16;
+
+    /// Creates a QuiescentBlocks for a board of `width` × `height` cells, where a block is quiescent once none of its cells changed for `generations` generations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `generations` is 0.
+    #[track_caller]
+    pub fn new(width: usize, height: usize, generations: u32) -> Self {
+        assert!(generations != 0, "a block must be unchanged for at least 1 generation");
+
+        let columns = width.div_ceil(Self::BLOCK_SIZE);
+        let rows = height.div_ceil(Self::BLOCK_SIZE);
+
+        Self {
+            columns,
+            rows,
+            generations,
+            unchanged: vec![0; columns * rows],
+        }
+    }
+
+    /// Returns the number of blocks in a row and in a column of the board.
+    #[inline]
+    pub fn blocks(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    /// Returns the number of blocks that are currently quiescent.
+    pub fn quiescent(&self) -> usize {
+        self.unchanged.iter().filter(|&&unchanged| unchanged >= self.generations).count()
+    }
+
+    /// Forgets which blocks are quiescent, so the next step evaluates the whole board.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.unchanged.fill(0);
+    }
+
+    /// Returns true if the block and all blocks around it are quiescent.
+    fn can_skip(&self, boundary: BoundaryMode, column: usize, row: usize) -> bool {
+        (-1..=1).all(|dy| (-1..=1).all(|dx| {
+            let neighbor = |position: usize, delta: isize, len: usize| match boundary {
+                BoundaryMode::Wrap => Some((position as isize + delta).rem_euclid(len as isize) as usize),
+                _ => position.checked_add_signed(delta).filter(|&position| position < len),
+            };
+
+            match (neighbor(column, dx, self.columns), neighbor(row, dy, self.rows)) {
+                (Some(column), Some(row)) => self.unchanged[row * self.columns + column] >= self.generations,
+                _ => true,
+            }
+        }))
+    }
+
+    /// Invokes the given closure on each [Cell][crate::Cell] in the Life outside of quiescent blocks, like [`Life::play`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Life doesn't have the size of the QuiescentBlocks.
+    #[track_caller]
+    pub fn play<const HEIGHT: usize, const WIDTH: usize, C: CellLike + PartialEq>(&mut self, life: &mut Life<HEIGHT, WIDTH, C>, mut f: impl FnMut(C, [C; 8], usize, usize) -> C) {
+        assert!(WIDTH.div_ceil(Self::BLOCK_SIZE) == self.columns && HEIGHT.div_ceil(Self::BLOCK_SIZE) == self.rows, "the Life doesn't match the size of the QuiescentBlocks");
+
+        let skip: Vec<bool> = (0..self.rows).flat_map(|row| (0..self.columns).map(move |column| (column, row))).map(|(column, row)| self.can_skip(life.boundary, column, row)).collect();
+        let mut next = life.cells;
+
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let block = row * self.columns + column;
+
+                if skip[block] {
+                    self.unchanged[block] = self.unchanged[block].saturating_add(1);
+                    continue;
+                }
+
+                let (x, y) = (column * Self::BLOCK_SIZE, row * Self::BLOCK_SIZE);
+                let mut changed = false;
+
+                for Point { x, y } in Rect::new(x, y, Self::BLOCK_SIZE.min(WIDTH - x), Self::BLOCK_SIZE.min(HEIGHT - y)).points() {
+                    // x and y are within the board
+                    let cell = normalized(life.aliveness, |this, others| f(this, others, x, y), life.cells[y][x], unsafe { life.get_surrounding(x, y) });
+
+                    changed |= cell != life.cells[y][x];
+                    next[y][x] = cell;
+                }
+
+                self.unchanged[block] = match changed {
+                    true => 0,
+                    false => self.unchanged[block].saturating_add(1),
+                };
+            }
+        }
+
+        life.cells = next;
+    }
+
+    /// Invokes [`QuiescentBlocks::play`] `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Life doesn't have the size of the QuiescentBlocks.
+    #[track_caller]
+    #[inline]
+    pub fn play_for<const HEIGHT: usize, const WIDTH: usize, C: CellLike + PartialEq>(&mut self, life: &mut Life<HEIGHT, WIDTH, C>, n: u32, mut f: impl FnMut(C, [C; 8], usize, usize) -> C) {
+        for _ in 0..n {
+            self.play(life, &mut f);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{Cell, DynLife, Life, Rect};
+
+/// A small, fast pseudorandom number generator for creating soups.
+///
+/// This is SplitMix64: the same seed always gives the same numbers on every platform, so soups can be reproduced from their seed. It is not suitable for cryptography.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut a = Rng::new(42);
+/// let mut b = Rng::new(42);
+///
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// assert_ne!(a.next_u64(), Rng::new(43).next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates an Rng from `seed`.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random [u64].
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next random [u32].
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns true with the probability given by `density`.
+    #[inline]
+    pub fn chance(&mut self, density: Density) -> bool {
+        (self.next_u32() as u64) < density.threshold
+    }
+
+    /// Returns a random living [Cell], whose RGB values aren't all 0.
+    pub fn cell(&mut self) -> Cell {
+        loop {
+            let [r, g, b, ..] = self.next_u32().to_le_bytes();
+            let cell = Cell { r, g, b };
+
+            if cell.is_alive() {
+                return cell;
+            }
+        }
+    }
+}
+
+/// The share of [Cells][Cell] that are made alive in a soup.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// assert_eq!(Density::per_mille(500), Density::HALF);
+/// assert_eq!(Density::per_mille(2000), Density::FULL);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Density {
+    /// The probability of a [Cell] being alive, in 2^32ths.
+    threshold: u64,
+}
+
+impl Density {
+    /// No [Cells][Cell] are alive.
+    pub const EMPTY: Self = Self::per_mille(0);
+    /// Half of the [Cells][Cell] are alive.
+    pub const HALF: Self = Self::per_mille(500);
+    /// All [Cells][Cell] are alive.
+    pub const FULL: Self = Self::per_mille(1000);
+
+    /// Creates a Density of `per_mille` thousandths, where 1000 and greater make all [Cells][Cell] alive.
+    #[inline]
+    pub const fn per_mille(per_mille: u32) -> Self {
+        let per_mille = if per_mille > 1000 { 1000 } else { per_mille };
+
+        Self {
+            threshold: ((per_mille as u64) << 32) / 1000,
+        }
+    }
+
+    /// Creates a Density from a probability between 0 and 1, clamping values outside of it.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn new(density: f64) -> Self {
+        Self {
+            threshold: (density.clamp(0.0, 1.0) * (1u64 << 32) as f64) as u64,
+        }
+    }
+}
+
+/// Randomizes the [Cells][Cell] of a `width` wide board, stored row by row, inside of `rect`.
+fn randomize(cells: &mut [Cell], width: usize, rect: Rect, rng: &mut Rng, density: Density, colored: bool) {
+    for point in rect.points() {
+        cells[point.y * width + point.x] = match rng.chance(density) {
+            true if colored => rng.cell(),
+            true => Cell::alive(),
+            false => Cell::dead(),
+        };
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Creates a soup: a Life whose [Cells][Cell] are [`Cell::alive`] with the probability given by `density` and [`Cell::dead`] otherwise.
+    ///
+    /// The same seed always gives the same Life.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let soup = Life::<16, 16>::random(7, Density::HALF);
+    ///
+    /// assert_eq!(soup, Life::random(7, Density::HALF));
+    /// assert!((96..160).contains(&soup.iter().filter(|cell| cell.is_alive()).count()));
+    /// assert_eq!(Life::<4, 4>::random(7, Density::EMPTY), Life::default());
+    /// ```
+    pub fn random(seed: u64, density: Density) -> Self {
+        let mut life = Self::default();
+        life.randomize(&mut Rng::new(seed), density);
+        life
+    }
+
+    /// Makes every [Cell] [`Cell::alive`] with the probability given by `density` and [`Cell::dead`] otherwise.
+    #[inline]
+    pub fn randomize(&mut self, rng: &mut Rng, density: Density) {
+        self.randomize_rect(rng, Rect::of_size(WIDTH, HEIGHT), density);
+    }
+
+    /// Makes every [Cell] alive in a random color with the probability given by `density` and [`Cell::dead`] otherwise.
+    #[inline]
+    pub fn randomize_colors(&mut self, rng: &mut Rng, density: Density) {
+        self.randomize_rect_colors(rng, Rect::of_size(WIDTH, HEIGHT), density);
+    }
+
+    /// Randomizes the [Cells][Cell] inside of `rect` like [`randomize`][Life::randomize], leaving the others unchanged.
+    ///
+    /// The parts of `rect` outside of the Life are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<4, 4>::default();
+    /// life.randomize_rect(&mut Rng::new(1), Rect::new(2, 2, 5, 5), Density::FULL);
+    ///
+    /// assert_eq!(life.to_string(), "....\n....\n..OO\n..OO");
+    /// ```
+    pub fn randomize_rect(&mut self, rng: &mut Rng, rect: Rect, density: Density) {
+        if let Some(rect) = rect.intersection(Rect::of_size(WIDTH, HEIGHT)) {
+            randomize(self.cells.as_flattened_mut(), WIDTH, rect, rng, density, false);
+        }
+    }
+
+    /// Randomizes the [Cells][Cell] inside of `rect` like [`randomize_colors`][Life::randomize_colors], leaving the others unchanged.
+    ///
+    /// The parts of `rect` outside of the Life are ignored.
+    pub fn randomize_rect_colors(&mut self, rng: &mut Rng, rect: Rect, density: Density) {
+        if let Some(rect) = rect.intersection(Rect::of_size(WIDTH, HEIGHT)) {
+            randomize(self.cells.as_flattened_mut(), WIDTH, rect, rng, density, true);
+        }
+    }
+}
+
+impl DynLife {
+    /// Creates a `width` × `height` soup like [`Life::random`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of [Cells][Cell] overflows a `usize`.
+    #[track_caller]
+    pub fn random(width: usize, height: usize, seed: u64, density: Density) -> Self {
+        let mut life = Self::new(width, height);
+        life.randomize(&mut Rng::new(seed), density);
+        life
+    }
+
+    /// Makes every [Cell] [`Cell::alive`] with the probability given by `density` and [`Cell::dead`] otherwise.
+    #[inline]
+    pub fn randomize(&mut self, rng: &mut Rng, density: Density) {
+        self.randomize_rect(rng, Rect::of_size(self.width(), self.height()), density);
+    }
+
+    /// Makes every [Cell] alive in a random color with the probability given by `density` and [`Cell::dead`] otherwise.
+    #[inline]
+    pub fn randomize_colors(&mut self, rng: &mut Rng, density: Density) {
+        self.randomize_rect_colors(rng, Rect::of_size(self.width(), self.height()), density);
+    }
+
+    /// Randomizes the [Cells][Cell] inside of `rect` like [`randomize`][DynLife::randomize], leaving the others unchanged.
+    ///
+    /// The parts of `rect` outside of the DynLife are ignored.
+    pub fn randomize_rect(&mut self, rng: &mut Rng, rect: Rect, density: Density) {
+        let width = self.width();
+
+        if let Some(rect) = rect.intersection(Rect::of_size(width, self.height())) {
+            randomize(self.cells_mut(), width, rect, rng, density, false);
+        }
+    }
+
+    /// Randomizes the [Cells][Cell] inside of `rect` like [`randomize_colors`][DynLife::randomize_colors], leaving the others unchanged.
+    ///
+    /// The parts of `rect` outside of the DynLife are ignored.
+    pub fn randomize_rect_colors(&mut self, rng: &mut Rng, rect: Rect, density: Density) {
+        let width = self.width();
+
+        if let Some(rect) = rect.intersection(Rect::of_size(width, self.height())) {
+            randomize(self.cells_mut(), width, rect, rng, density, true);
+        }
+    }
+}
@@ -0,0 +1,362 @@
+//! Saving boards as images.
+//!
+//! Since [Cells][Cell] are already RGB colors, a [Life] can be written as a PNG image with [`Life::to_png`] or [`Life::save_png`], and a run can be recorded as an animated GIF with a [GifRecorder]. Every [Cell] is drawn as a square of `cell_size` × `cell_size` pixels.
+//!
+//! The encoders are part of this crate and favor simplicity over file size: PNG images are stored without compression, and GIF frames are encoded with their own palette of up to 256 colors. Frames with more colors are reduced to a fixed palette of 3 bits of red, 3 bits of green and 2 bits of blue.
+//!
+//! # Examples
+//!
+//! ```
+//! use your_game_of_life::*;
+//! use your_game_of_life::render::GifRecorder;
+//!
+//! let mut life = Life::<3, 3>::from([[false; 3], [true; 3], [false; 3]]);
+//!
+//! let png = life.to_png(4);
+//! assert_eq!(png[..8], *b"\x89PNG\r\n\x1a\n");
+//!
+//! let mut recorder = GifRecorder::<3, 3>::new(4, 50);
+//! recorder.record(&life);
+//! recorder.play_for(&mut life, 2, |this, others, _, _| Rule::CONWAY.apply(this, others));
+//!
+//! assert_eq!(recorder.frames(), 3);
+//! assert_eq!(recorder.finish()[..6], *b"GIF89a");
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::marker::PhantomData;
+use std::path::Path;
+
+use crate::checksum::crc32;
+use crate::{Cell, Life};
+
+/// The largest amount of data in a stored deflate block.
+const STORED_BLOCK_LEN: usize = 65535;
+
+/// Returns the Adler-32 checksum of `bytes`, as used by zlib streams.
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.chunks(5552).fold((1u32, 0u32), |(mut a, mut b), chunk| {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+
+        (a % 65521, b % 65521)
+    });
+
+    b << 16 | a
+}
+
+/// Appends a PNG chunk of the given type with its length and checksum.
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+
+    let checksum = crc32(&png[start..]);
+    png.extend_from_slice(&checksum.to_be_bytes());
+}
+
+/// Encodes `rgb`, the pixels of a `width` × `height` image row by row, as a PNG image.
+fn png(width: usize, height: usize, rgb: &[u8]) -> Vec<u8> {
+    // every scanline starts with the filter type 0, which leaves the pixels as they are
+    let mut raw = Vec::with_capacity(height * (width * 3 + 1));
+    for row in rgb.chunks_exact(width * 3).take(height) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    let mut zlib = vec![0x78, 0x01];
+    let blocks = raw.len().div_ceil(STORED_BLOCK_LEN);
+
+    for (i, block) in raw.chunks(STORED_BLOCK_LEN).enumerate() {
+        zlib.push((i + 1 == blocks) as u8);
+        zlib.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        zlib.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, no interlacing
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    push_chunk(&mut png, b"IHDR", &header);
+    push_chunk(&mut png, b"IDAT", &zlib);
+    push_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Encodes the Life as a PNG image, with every [Cell] drawn as a square of `cell_size` × `cell_size` pixels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is 0, the Life is empty, the image is larger than the PNG format allows or its pixels overflow a `usize`.
+    #[track_caller]
+    pub fn to_png(&self, cell_size: usize) -> Vec<u8> {
+        assert!(cell_size != 0, "the cell size must not be 0");
+        assert!(WIDTH != 0 && HEIGHT != 0, "a PNG image can't be empty");
+
+        let pixels = |cells: usize| cells.checked_mul(cell_size).filter(|&pixels| u32::try_from(pixels).is_ok());
+        let (Some(width), Some(height)) = (pixels(WIDTH), pixels(HEIGHT)) else {
+            panic!("a PNG image can't be larger than {} pixels", u32::MAX);
+        };
+        let len = width.checked_mul(height).and_then(|len| len.checked_mul(3)).expect("the number of bytes of the image overflows a usize");
+
+        let mut rgb = vec![0; len];
+        self.write_rgb_scaled(&mut rgb, cell_size).expect("the buffer has exactly the size of the frame");
+
+        png(width, height, &rgb)
+    }
+
+    /// Saves the Life as a PNG image at `path`, like [`Life::to_png`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is 0, the Life is empty or the image is larger than the PNG format allows.
+    #[track_caller]
+    pub fn save_png(&self, path: impl AsRef<Path>, cell_size: usize) -> io::Result<()> {
+        fs::write(path, self.to_png(cell_size))
+    }
+}
+
+/// Writes codes of growing sizes with the least significant bit first, as GIF images do.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+
+        self.bytes
+    }
+}
+
+/// Compresses palette indices with the variable-length LZW used by GIF images.
+fn lzw(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    const MAX_CODES: u16 = 4096;
+
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut writer = BitWriter {
+        bytes: Vec::new(),
+        buffer: 0,
+        bits: 0,
+    };
+    let mut codes: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut size = min_code_size + 1;
+
+    writer.write(clear, size);
+
+    let mut indices = indices.iter().copied();
+    let Some(first) = indices.next() else {
+        writer.write(end, size);
+        return writer.finish();
+    };
+    let mut prefix = first as u16;
+
+    for index in indices {
+        if let Some(&code) = codes.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+
+        writer.write(prefix, size);
+
+        if next < MAX_CODES {
+            codes.insert((prefix, index), next);
+            next += 1;
+
+            if next > 1 << size && size < 12 {
+                size += 1;
+            }
+        }
+        else {
+            writer.write(clear, size);
+            codes.clear();
+            next = end + 1;
+            size = min_code_size + 1;
+        }
+
+        prefix = index as u16;
+    }
+
+    writer.write(prefix, size);
+    writer.write(end, size);
+    writer.finish()
+}
+
+/// Returns the palette of the [Cells][Cell] and the index of each [Cell] in it.
+///
+/// If there are more than 256 colors, every color is reduced to 3 bits of red, 3 bits of green and 2 bits of blue.
+fn palette(cells: &[Cell]) -> (Vec<Cell>, Vec<u8>) {
+    let mut colors: HashMap<Cell, u8> = HashMap::new();
+    let mut palette = Vec::new();
+    let mut indices = Vec::with_capacity(cells.len());
+
+    for &cell in cells {
+        let index = match colors.get(&cell) {
+            Some(&index) => index,
+            None if palette.len() < 256 => {
+                let index = palette.len() as u8;
+                colors.insert(cell, index);
+                palette.push(cell);
+                index
+            }
+            None => {
+                let palette = (0..=255u16).map(|i| Cell {
+                    r: ((i >> 5) * 255 / 7) as u8,
+                    g: ((i >> 2 & 7) * 255 / 7) as u8,
+                    b: ((i & 3) * 85) as u8,
+                }).collect();
+                let indices = cells.iter().map(|cell| cell.r & 0xE0 | cell.g >> 5 << 2 | cell.b >> 6).collect();
+
+                return (palette, indices);
+            }
+        };
+
+        indices.push(index);
+    }
+
+    (palette, indices)
+}
+
+/// Records generations of a [Life] as the frames of an animated GIF.
+///
+/// Frames are encoded as soon as they are recorded, and [`finish`][GifRecorder::finish] returns the whole animation, which loops forever.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GifRecorder<const HEIGHT: usize, const WIDTH: usize> {
+    cell_size: usize,
+    delay: u16,
+    frames: usize,
+    bytes: Vec<u8>,
+    // a recorder only takes Lifes of one size
+    size: PhantomData<Life<HEIGHT, WIDTH>>,
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> GifRecorder<HEIGHT, WIDTH> {
+    /// Creates a GifRecorder drawing every [Cell] as a square of `cell_size` × `cell_size` pixels and showing each frame for `delay` hundredths of a second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is 0 or the animation is larger than the GIF format allows.
+    #[track_caller]
+    pub fn new(cell_size: usize, delay: u16) -> Self {
+        assert!(cell_size != 0, "the cell size must not be 0");
+
+        let pixels = |cells: usize| cells.checked_mul(cell_size).and_then(|pixels| u16::try_from(pixels).ok());
+        let (Some(width), Some(height)) = (pixels(WIDTH), pixels(HEIGHT)) else {
+            panic!("a GIF image can't be larger than {} pixels", u16::MAX);
+        };
+
+        let mut bytes = b"GIF89a".to_vec();
+        bytes.extend_from_slice(&width.to_le_bytes());
+        bytes.extend_from_slice(&height.to_le_bytes());
+        // no global color table, background color 0, no aspect ratio
+        bytes.extend_from_slice(&[0, 0, 0]);
+        // the NETSCAPE2.0 extension repeating the animation forever
+        bytes.extend_from_slice(b"\x21\xFF\x0BNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+        Self {
+            cell_size,
+            delay,
+            frames: 0,
+            bytes,
+            size: PhantomData,
+        }
+    }
+
+    /// Returns the number of recorded frames.
+    #[inline]
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Adds the current state of the Life as a frame.
+    pub fn record(&mut self, life: &Life<HEIGHT, WIDTH>) {
+        let (palette, indices) = palette(life.cells.as_flattened());
+        let bits = (palette.len().max(2) - 1).ilog2() + 1;
+
+        // the graphic control extension, leaving each frame in place until the next one is drawn
+        self.bytes.extend_from_slice(&[0x21, 0xF9, 0x04, 0x04]);
+        self.bytes.extend_from_slice(&self.delay.to_le_bytes());
+        self.bytes.extend_from_slice(&[0, 0]);
+
+        // the image descriptor, with a local color table
+        self.bytes.push(0x2C);
+        self.bytes.extend_from_slice(&[0, 0, 0, 0]);
+        self.bytes.extend_from_slice(&((WIDTH * self.cell_size) as u16).to_le_bytes());
+        self.bytes.extend_from_slice(&((HEIGHT * self.cell_size) as u16).to_le_bytes());
+        self.bytes.push(0x80 | (bits - 1) as u8);
+
+        for i in 0..1 << bits {
+            let cell = palette.get(i).copied().unwrap_or_default();
+            self.bytes.extend_from_slice(&[cell.r, cell.g, cell.b]);
+        }
+
+        let mut pixels = Vec::with_capacity(indices.len() * self.cell_size * self.cell_size);
+        for row in indices.chunks_exact(WIDTH.max(1)) {
+            let line: Vec<u8> = row.iter().flat_map(|&index| std::iter::repeat_n(index, self.cell_size)).collect();
+
+            for _ in 0..self.cell_size {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        let min_code_size = bits.max(2);
+        self.bytes.push(min_code_size as u8);
+
+        for block in lzw(&pixels, min_code_size).chunks(255) {
+            self.bytes.push(block.len() as u8);
+            self.bytes.extend_from_slice(block);
+        }
+        self.bytes.push(0);
+
+        self.frames += 1;
+    }
+
+    /// Invokes [`Life::play`] with the given closure `n` times, recording each generation as a frame.
+    pub fn play_for(&mut self, life: &mut Life<HEIGHT, WIDTH>, n: u32, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        for _ in 0..n {
+            life.play(&mut f);
+            self.record(life);
+        }
+    }
+
+    /// Returns the encoded animation.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.bytes.push(0x3B);
+        self.bytes
+    }
+
+    /// Saves the animation as a GIF image at `path`.
+    pub fn save(self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.finish())
+    }
+}
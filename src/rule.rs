@@ -0,0 +1,218 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Aliveness, Cell, CellNeighbors, DynLife, Life};
+
+/// A life-like rule in B/S notation, deciding which dead [Cells][Cell] are born and which living ones survive by their number of living neighbors.
+///
+/// Rules are parsed from Golly-style rulestrings like `B3/S23`, in either order and any case, as well as from the older survival/birth notation like `23/3`.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let highlife: Rule = "B36/S23".parse().unwrap();
+///
+/// assert_eq!(highlife, Rule::HIGHLIFE);
+/// assert!(highlife.is_born(6));
+/// assert!(highlife.survives(2));
+/// assert!(!highlife.survives(6));
+/// assert_eq!("s23/b3".parse(), Ok(Rule::CONWAY));
+/// assert_eq!(Rule::SEEDS.to_string(), "B2/S");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rule {
+    birth: u16,
+    survival: u16,
+}
+
+/// The error returned when a rulestring can't be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleError {
+    /// The rulestring isn't made of a birth and a survival part separated by `/`.
+    InvalidFormat,
+    /// The rulestring contains a character that isn't a neighbor count from 0 to 8.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::InvalidFormat => write!(f, "rulestring must have the form B.../S..."),
+            Self::InvalidCharacter(character) => write!(f, "invalid character {character:?} in rulestring"),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+impl Default for Rule {
+    /// Returns [`Rule::CONWAY`].
+    #[inline]
+    fn default() -> Self {
+        Self::CONWAY
+    }
+}
+
+impl Rule {
+    /// Conway's Game of Life, `B3/S23`.
+    pub const CONWAY: Self = Self::new(&[3], &[2, 3]);
+    /// HighLife, `B36/S23`, which has a replicator.
+    pub const HIGHLIFE: Self = Self::new(&[3, 6], &[2, 3]);
+    /// Seeds, `B2/S`, where every living [Cell] dies.
+    pub const SEEDS: Self = Self::new(&[2], &[]);
+    /// Day & Night, `B3678/S34678`, where living and dead [Cells][Cell] behave the same.
+    pub const DAY_AND_NIGHT: Self = Self::new(&[3, 6, 7, 8], &[3, 4, 6, 7, 8]);
+
+    /// Creates a Rule where dead [Cells][Cell] with a number of living neighbors in `birth` are born, and living [Cells][Cell] with a number of living neighbors in `survival` survive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a number is greater than 8.
+    #[track_caller]
+    pub const fn new(birth: &[u8], survival: &[u8]) -> Self {
+        Self {
+            birth: Self::mask(birth),
+            survival: Self::mask(survival),
+        }
+    }
+
+    #[track_caller]
+    const fn mask(counts: &[u8]) -> u16 {
+        let mut mask = 0;
+        let mut i = 0;
+
+        while i < counts.len() {
+            assert!(counts[i] <= 8, "a cell has at most 8 neighbors");
+            mask |= 1 << counts[i];
+            i += 1;
+        }
+
+        mask
+    }
+
+    /// Returns true if a dead [Cell] with `neighbors` living neighbors is born.
+    #[inline]
+    pub const fn is_born(self, neighbors: u8) -> bool {
+        neighbors <= 8 && self.birth >> neighbors & 1 == 1
+    }
+
+    /// Returns true if a living [Cell] with `neighbors` living neighbors survives.
+    #[inline]
+    pub const fn survives(self, neighbors: u8) -> bool {
+        neighbors <= 8 && self.survival >> neighbors & 1 == 1
+    }
+
+    /// Returns the next generation of `this`: a surviving [Cell] is kept as it is, a born [Cell] becomes [`Cell::alive`] and every other [Cell] becomes [`Cell::dead`].
+    ///
+    /// This has the signature of the closures taken by [`Life::play`], except for the positions.
+    #[inline]
+    pub fn apply(self, this: Cell, others: [Cell; 8]) -> Cell {
+        self.apply_by(this, others, Aliveness::NonBlack)
+    }
+
+    /// Returns the next generation of `this` like [`apply`][Rule::apply], with [Cells][Cell] counted as alive according to `aliveness` and dying [Cells][Cell] becoming [`aliveness.dead()`][Aliveness::dead].
+    ///
+    /// This is what [`apply`][Rule::apply] returns in the closure of [`Life::play`] on a board with that aliveness, which [normalizes][Aliveness::normalize] the [Cells][Cell]. Born [Cells][Cell] are [`aliveness.normalize(Cell::alive())`][Aliveness::normalize], which is [`Cell::alive`] unless white is the background or too dark to count as alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let navy = Cell { r: 0, g: 0, b: 64 };
+    /// let aliveness = Aliveness::Background(navy);
+    ///
+    /// let mut others = [navy; 8];
+    /// others[..3].fill(Cell::red());
+    ///
+    /// assert_eq!(Rule::CONWAY.apply_by(navy, others, aliveness), Cell::alive());
+    /// assert_eq!(Rule::CONWAY.apply_by(Cell::red(), [navy; 8], aliveness), navy);
+    /// ```
+    #[inline]
+    pub fn apply_by(self, this: Cell, others: [Cell; 8], aliveness: Aliveness) -> Cell {
+        let neighbors = others.alive_by(aliveness);
+
+        match aliveness.is_alive(this) {
+            true if self.survives(neighbors) => this,
+            false if self.is_born(neighbors) => aliveness.normalize(Cell::alive()),
+            _ => aliveness.dead(),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    /// Writes the rule in B/S notation, like `B3/S23`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = |mask: u16| (0..=8).filter(move |count| mask >> count & 1 == 1).map(|count| char::from(b'0' + count));
+
+        write!(f, "B{}/S{}", digits(self.birth).collect::<String>(), digits(self.survival).collect::<String>())
+    }
+}
+
+impl FromStr for Rule {
+    type Err = RuleError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        fn strip(part: &str, prefix: char) -> Option<&str> {
+            part.strip_prefix([prefix, prefix.to_ascii_lowercase()])
+        }
+
+        let (first, second) = rule.trim().split_once('/').ok_or(RuleError::InvalidFormat)?;
+
+        let mask = |part: &str| part.chars().try_fold(0u16, |mask, character| match character.to_digit(10) {
+            Some(count) if count <= 8 => Ok(mask | 1 << count),
+            _ => Err(RuleError::InvalidCharacter(character)),
+        });
+
+        let (birth, survival) = match (strip(first, 'B'), strip(second, 'S'), strip(first, 'S'), strip(second, 'B')) {
+            (Some(birth), Some(survival), _, _) | (_, _, Some(survival), Some(birth)) => (birth, survival),
+            // the older notation lists the survival counts first
+            _ if !first.starts_with(char::is_alphabetic) && !second.starts_with(char::is_alphabetic) => (second, first),
+            _ => return Err(RuleError::InvalidFormat),
+        };
+
+        Ok(Self {
+            birth: mask(birth)?,
+            survival: mask(survival)?,
+        })
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Steps the Life once by the given [Rule], as described by [`Rule::apply_by`] with the [`aliveness`][Life#structfield.aliveness] of the Life.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<3, 3>::from([[false; 3], [true; 3], [false; 3]]);
+    ///
+    /// life.play_rule(&Rule::CONWAY);
+    ///
+    /// assert_eq!(life, Life::from([[false, true, false]; 3]));
+    /// ```
+    #[inline]
+    pub fn play_rule(&mut self, rule: &Rule) {
+        self.play(|this, others, _, _| rule.apply(this, others));
+    }
+
+    /// Steps the Life `n` times by the given [Rule], as described by [`Rule::apply_by`] with the [`aliveness`][Life#structfield.aliveness] of the Life.
+    #[inline]
+    pub fn play_rule_for(&mut self, n: u32, rule: &Rule) {
+        self.play_for(n, |this, others, _, _| rule.apply(this, others));
+    }
+}
+
+impl DynLife {
+    /// Steps the DynLife once by the given [Rule], as described by [`Rule::apply_by`] with the [`aliveness`][DynLife#structfield.aliveness] of the DynLife.
+    #[inline]
+    pub fn play_rule(&mut self, rule: &Rule) {
+        self.play(|this, others, _, _| rule.apply(this, others));
+    }
+
+    /// Steps the DynLife `n` times by the given [Rule], as described by [`Rule::apply_by`] with the [`aliveness`][DynLife#structfield.aliveness] of the DynLife.
+    #[inline]
+    pub fn play_rule_for(&mut self, n: u32, rule: &Rule) {
+        self.play_for(n, |this, others, _, _| rule.apply(this, others));
+    }
+}
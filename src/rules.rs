@@ -0,0 +1,395 @@
+//! Ready-made closures for [`Life::play`].
+//!
+//! Instead of writing out the same `match` on the number of living neighbors for every project, rules can be built from their birth and survival counts and a [ColorPolicy] deciding the color of newborn [Cells][Cell]. A [RuleSpec] holds both, can describe itself for display and explains why a [Cell] lives or dies with [`Life::explain_cell`].
+//!
+//! # Examples
+//!
+//! ```
+//! use your_game_of_life::prelude::*;
+//!
+//! let mut life = Life::<3, 3>::from([
+//!     [Cell::dead(), Cell::red(), Cell::dead()],
+//!     [Cell::dead(), Cell::red(), Cell::dead()],
+//!     [Cell::dead(), Cell::blue(), Cell::dead()],
+//! ]);
+//!
+//! life.play(totalistic(&[3], &[2, 3], ColorPolicy::Average));
+//!
+//! // the newborn cells mix the colors of their parents
+//! assert_eq!(life.cells[1], [Cell { r: 170, g: 0, b: 85 }, Cell::red(), Cell { r: 170, g: 0, b: 85 }]);
+//! ```
+//!
+//! [`Life::play`]: crate::Life::play
+//! [`Life::explain_cell`]: crate::Life::explain_cell
+
+use std::fmt::{self, Write};
+
+use crate::{Aliveness, BoundaryMode, Cell, CellNeighbors, DynLife, Life, Offset, Point, Rule, NEIGHBOR_OFFSETS};
+
+/// Which color a [Cell] gets when it is born.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorPolicy {
+    /// Newborn [Cells][Cell] are [`Cell::alive`].
+    #[default]
+    White,
+    /// Newborn [Cells][Cell] have the given color.
+    Fixed(Cell),
+    /// Newborn [Cells][Cell] get the average color of their living neighbors.
+    Average,
+}
+
+impl ColorPolicy {
+    /// Returns the color of a [Cell] born with the given neighbors.
+    pub fn born(self, others: [Cell; 8]) -> Cell {
+        match self {
+            Self::White => Cell::alive(),
+            Self::Fixed(cell) => cell,
+            Self::Average => {
+                let (count, sum) = others.into_iter().filter(|cell| cell.is_alive()).fold((0u16, [0u16; 3]), |(count, [r, g, b]), cell| {
+                    (count + 1, [r + cell.r as u16, g + cell.g as u16, b + cell.b as u16])
+                });
+
+                match count {
+                    0 => Cell::alive(),
+                    count => {
+                        let average = |sum: u16| ((sum + count / 2) / count) as u8;
+                        let cell = Cell::from(sum.map(average));
+
+                        // rounding can't make an average of living cells black, but keep newborns alive regardless
+                        if cell.is_alive() { cell } else { Cell::alive() }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A rule as built by this module: a life-like [Rule] and the [ColorPolicy] of its newborn [Cells][Cell].
+///
+/// Besides building the closure with [`closure`][RuleSpec::closure], a RuleSpec can [`describe`][RuleSpec::describe] itself, for frontends showing which rule is running.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::prelude::*;
+///
+/// let spec = RuleSpec::new(Rule::HIGHLIFE, ColorPolicy::Average);
+///
+/// assert_eq!(spec.describe(), "\
+/// B36/S23 (HighLife)
+/// neighborhood: Moore, radius 1 (8 neighbors)
+/// states: 2 (dead and alive)
+/// birth: 3, 6
+/// survival: 2, 3
+/// newborn color: the average of the living neighbors
+///
+/// neighbors 0 1 2 3 4 5 6 7 8
+/// dead      . . . O . . O . .
+/// alive     . . O O . . . . .
+/// ");
+///
+/// let mut life = Life::<3, 3>::from([[false; 3], [true; 3], [false; 3]]);
+/// life.play(spec.closure());
+///
+/// assert_eq!(life, Life::from([[false, true, false]; 3]));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RuleSpec {
+    /// Decides which [Cells][Cell] are born and which survive.
+    pub rule: Rule,
+    /// Decides the color of newborn [Cells][Cell].
+    pub policy: ColorPolicy,
+}
+
+impl RuleSpec {
+    /// Creates a RuleSpec stepping by `rule` with newborn [Cells][Cell] colored by `policy`.
+    #[inline]
+    pub const fn new(rule: Rule, policy: ColorPolicy) -> Self {
+        Self { rule, policy }
+    }
+
+    /// Returns the closure for [`Life::play`] stepping by the RuleSpec, like [`life_like`].
+    ///
+    /// [`Life::play`]: crate::Life::play
+    #[inline]
+    pub fn closure(self) -> impl Fn(Cell, [Cell; 8], usize, usize) -> Cell + Copy {
+        life_like(self.rule, self.policy)
+    }
+
+    /// Returns a human-readable summary of the rule: its rulestring and name if it has one, its neighborhood and states, the numbers of living neighbors for birth and survival, the color of newborn [Cells][Cell], and the [`transition_table`][RuleSpec::transition_table].
+    pub fn describe(&self) -> String {
+        let counts = |survives: bool| {
+            let counts: Vec<_> = (0..=8).filter(|&neighbors| if survives { self.rule.survives(neighbors) } else { self.rule.is_born(neighbors) }).map(|neighbors| neighbors.to_string()).collect();
+
+            match counts.is_empty() {
+                true => "none".to_owned(),
+                false => counts.join(", "),
+            }
+        };
+
+        let name = match self.rule {
+            Rule::CONWAY => " (Conway's Game of Life)",
+            Rule::HIGHLIFE => " (HighLife)",
+            Rule::SEEDS => " (Seeds)",
+            Rule::DAY_AND_NIGHT => " (Day & Night)",
+            _ => "",
+        };
+        let color = match self.policy {
+            ColorPolicy::White => "white".to_owned(),
+            ColorPolicy::Fixed(Cell { r, g, b }) => format!("#{r:02x}{g:02x}{b:02x}"),
+            ColorPolicy::Average => "the average of the living neighbors".to_owned(),
+        };
+
+        format!(
+            "{}{name}\nneighborhood: Moore, radius 1 (8 neighbors)\nstates: 2 (dead and alive)\nbirth: {}\nsurvival: {}\nnewborn color: {color}\n\n{}",
+            self.rule,
+            counts(false),
+            counts(true),
+            self.transition_table(),
+        )
+    }
+
+    /// Returns a table of the next state of a dead and a living [Cell] for every number of living neighbors, where `O` is living and `.` dead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// use your_game_of_life::rules::RuleSpec;
+    ///
+    /// assert_eq!(RuleSpec::from(Rule::SEEDS).transition_table(), "\
+    /// neighbors 0 1 2 3 4 5 6 7 8
+    /// dead      . . O . . . . . .
+    /// alive     . . . . . . . . .
+    /// ");
+    /// ```
+    pub fn transition_table(&self) -> String {
+        let mut table = String::from("neighbors 0 1 2 3 4 5 6 7 8\n");
+
+        for (label, alive) in [("dead     ", false), ("alive    ", true)] {
+            table.push_str(label);
+
+            for neighbors in 0..=8 {
+                let next = if alive { self.rule.survives(neighbors) } else { self.rule.is_born(neighbors) };
+                let _ = write!(table, " {}", if next { 'O' } else { '.' });
+            }
+            table.push('\n');
+        }
+
+        table
+    }
+}
+
+/// What happens to a [Cell] in the next generation, as decided by the branch of its [Rule] that applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Transition {
+    /// The dead [Cell] is born, since its number of living neighbors is a birth count.
+    Born,
+    /// The living [Cell] survives, since its number of living neighbors is a survival count.
+    Survives,
+    /// The living [Cell] dies, since its number of living neighbors isn't a survival count.
+    Dies,
+    /// The dead [Cell] stays dead, since its number of living neighbors isn't a birth count.
+    StaysDead,
+}
+
+/// One of the neighbors of an [Explanation].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Neighbor {
+    /// The position of the neighbor relative to the explained [Cell].
+    pub offset: Offset,
+    /// The position of the neighbor on the board, or `None` if it lies beyond the edges and is the [`outside`][crate::BoundaryMode::outside] [Cell].
+    pub position: Option<Point>,
+    /// The neighbor itself.
+    pub cell: Cell,
+}
+
+/// Why a [Cell] lives or dies in the next generation, as returned by [`Life::explain_cell`].
+///
+/// The Display implementation writes the explanation as a sentence, for example for teaching the rules step by step.
+///
+/// # Examples
+///
+/// ```
+/// use your_game_of_life::prelude::*;
+/// use your_game_of_life::rules::Transition;
+///
+/// let life = Life::<3, 3>::from([[false; 3], [true; 3], [false; 3]]);
+/// let explanation = life.explain_cell(1, 0, &RuleSpec::from(Rule::CONWAY));
+///
+/// assert_eq!(explanation.living, 3);
+/// assert_eq!(explanation.transition, Transition::Born);
+/// assert_eq!(explanation.next, Cell::alive());
+/// assert_eq!(explanation.to_string(), "the dead cell at (1, 0) has 3 living neighbors at (0, 1), (1, 1) and (2, 1), so it is born, since 3 is a birth count of B3/S23");
+///
+/// let explanation = life.explain_cell(0, 1, &RuleSpec::from(Rule::CONWAY));
+/// assert_eq!(explanation.to_string(), "the living cell at (0, 1) has 1 living neighbor at (1, 1), so it dies, since 1 isn't a survival count of B3/S23");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Explanation {
+    /// The position of the explained [Cell].
+    pub position: Point,
+    /// The explained [Cell] in the current generation.
+    pub cell: Cell,
+    /// The [Aliveness] of the board, deciding which [Cells][Cell] are living.
+    pub aliveness: Aliveness,
+    /// The neighbors of the [Cell], in the order of the `[Cell; 8]` passed to [`Life::play`].
+    pub neighbors: [Neighbor; 8],
+    /// The number of living neighbors.
+    pub living: u8,
+    /// The [Rule] the [Cell] is stepped by.
+    pub rule: Rule,
+    /// The branch of the [Rule] that applies.
+    pub transition: Transition,
+    /// The [Cell] in the next generation, as returned by the [`closure`][RuleSpec::closure] of the [RuleSpec].
+    pub next: Cell,
+}
+
+impl Explanation {
+    /// Explains the next generation of `cell` at `position` with the given neighbors.
+    fn new(spec: &RuleSpec, aliveness: Aliveness, position: Point, cell: Cell, neighbors: [Neighbor; 8]) -> Self {
+        let others = neighbors.map(|neighbor| neighbor.cell);
+        let living = others.alive_by(aliveness);
+
+        let transition = match aliveness.is_alive(cell) {
+            true if spec.rule.survives(living) => Transition::Survives,
+            true => Transition::Dies,
+            false if spec.rule.is_born(living) => Transition::Born,
+            false => Transition::StaysDead,
+        };
+
+        Self {
+            position,
+            cell,
+            aliveness,
+            neighbors,
+            living,
+            rule: spec.rule,
+            transition,
+            next: crate::normalized(aliveness, |this, others| spec.closure()(this, others, position.x, position.y), cell, others),
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.aliveness.is_alive(self.cell) { "living" } else { "dead" };
+        let plural = if self.living == 1 { "" } else { "s" };
+        write!(f, "the {state} cell at {} has {} living neighbor{plural}", self.position, self.living)?;
+
+        let living: Vec<_> = self.neighbors.iter().filter(|neighbor| self.aliveness.is_alive(neighbor.cell)).map(|neighbor| match neighbor.position {
+            Some(position) => position.to_string(),
+            None => format!("{} outside the board", neighbor.offset),
+        }).collect();
+
+        if let Some((last, rest)) = living.split_last() {
+            match rest.is_empty() {
+                true => write!(f, " at {last}")?,
+                false => write!(f, " at {} and {last}", rest.join(", "))?,
+            }
+        }
+
+        let (outcome, is, counts) = match self.transition {
+            Transition::Born => ("is born", "is", "birth"),
+            Transition::Survives => ("survives", "is", "survival"),
+            Transition::Dies => ("dies", "isn't", "survival"),
+            Transition::StaysDead => ("stays dead", "isn't", "birth"),
+        };
+        write!(f, ", so it {outcome}, since {} {is} a {counts} count of {}", self.living, self.rule)
+    }
+}
+
+/// Returns the neighbors of the [Cell] at `x` and `y` of a board of `width` × `height` [Cells][Cell], given the [Cells][Cell] surrounding it.
+fn neighbors_of(boundary: BoundaryMode, x: usize, y: usize, width: usize, height: usize, surrounding: [Cell; 8]) -> [Neighbor; 8] {
+    std::array::from_fn(|i| {
+        let (dx, dy) = NEIGHBOR_OFFSETS[i];
+
+        Neighbor {
+            offset: Offset::new(dx, dy),
+            position: match (boundary.resolve(x as isize + dx, width), boundary.resolve(y as isize + dy, height)) {
+                (Some(x), Some(y)) => Some(Point::new(x, y)),
+                _ => None,
+            },
+            cell: surrounding[i],
+        }
+    })
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Explains why the [Cell] at the given index lives or dies in the next generation when stepping by `spec`: which neighbors it has and where, how many of them are living, and which branch of the [Rule] applies.
+    ///
+    /// Like [`play`][Life::play], this follows the [`aliveness`][Life#structfield.aliveness] of the board.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use your_game_of_life::prelude::*;
+    /// use your_game_of_life::rules::Transition;
+    ///
+    /// // a row of black cells on a white board
+    /// let white = Cell::white();
+    /// let mut life = Life::<3, 3>::from([[white; 3], [Cell::black(); 3], [white; 3]]);
+    /// life.aliveness = Aliveness::Background(white);
+    /// life.boundary = BoundaryMode::Constant(white);
+    ///
+    /// let spec = RuleSpec::from(Rule::CONWAY);
+    /// let explanation = life.explain_cell(1, 0, &spec);
+    /// assert_eq!(explanation.living, 3);
+    /// assert_eq!(explanation.transition, Transition::Born);
+    ///
+    /// life.play(spec.closure());
+    /// assert_eq!(life.get(1, 0), explanation.next);
+    /// ```
+    #[track_caller]
+    pub fn explain_cell(&self, x: usize, y: usize, spec: &RuleSpec) -> Explanation {
+        let neighbors = neighbors_of(self.boundary, x, y, WIDTH, HEIGHT, self.neighbors(x, y));
+        Explanation::new(spec, self.aliveness, Point::new(x, y), self.cells[y][x], neighbors)
+    }
+}
+
+impl DynLife {
+    /// Explains why the [Cell] at the given index lives or dies in the next generation when stepping by `spec`, like [`Life::explain_cell`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    pub fn explain_cell(&self, x: usize, y: usize, spec: &RuleSpec) -> Explanation {
+        let neighbors = neighbors_of(self.boundary, x, y, self.width(), self.height(), self.neighbors(x, y));
+        Explanation::new(spec, self.aliveness, Point::new(x, y), self.get(x, y), neighbors)
+    }
+}
+
+/// Converts a [Rule] into a RuleSpec where newborn [Cells][Cell] are [`Cell::alive`].
+impl From<Rule> for RuleSpec {
+    #[inline]
+    fn from(rule: Rule) -> Self {
+        Self::new(rule, ColorPolicy::White)
+    }
+}
+
+/// Returns a closure for [`Life::play`] stepping by a [Rule], where surviving [Cells][Cell] keep their color, newborn [Cells][Cell] are colored by `policy` and all others become [`Cell::dead`].
+///
+/// [`Life::play`]: crate::Life::play
+pub fn life_like(rule: Rule, policy: ColorPolicy) -> impl Fn(Cell, [Cell; 8], usize, usize) -> Cell + Copy {
+    move |this, others, _, _| match rule.apply(this, others) {
+        next if next.is_alive() && !this.is_alive() => policy.born(others),
+        next => next,
+    }
+}
+
+/// Returns a closure for [`Life::play`] like [`life_like`], where dead [Cells][Cell] with a number of living neighbors in `birth` are born and living [Cells][Cell] with a number in `survival` survive.
+///
+/// # Panics
+///
+/// Panics if a number is greater than 8.
+///
+/// [`Life::play`]: crate::Life::play
+#[track_caller]
+pub fn totalistic(birth: &[u8], survival: &[u8], policy: ColorPolicy) -> impl Fn(Cell, [Cell; 8], usize, usize) -> Cell + Copy {
+    life_like(Rule::new(birth, survival), policy)
+}
@@ -0,0 +1,325 @@
+use crate::{Cell, DynLife};
+
+/// The 8 symmetries of the square, as whether to transpose, flip vertically and flip horizontally, in that order.
+const SYMMETRIES: [(bool, bool, bool); 8] = [
+    (false, false, false), (false, false, true), (false, true, false), (false, true, true),
+    (true, false, false), (true, false, true), (true, true, false), (true, true, true),
+];
+
+/// A pattern of up to 8 × 8 living and dead [Cells][Cell] packed into a single [u64].
+///
+/// Searches that look at huge numbers of small objects, like censuses of what is left after a soup settles, spend most of their time on tiny still lifes and oscillators. A SmallPattern is [Copy], compares and hashes as a few integers and is canonicalized with a handful of bit operations instead of copying [Cells][Cell] around.
+///
+/// Patterns that don't fit are kept as a [DynLife] instead by [Object].
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let glider = SmallPattern::from_rows(&[".O.", "..O", "OOO"]).unwrap();
+/// let flipped = SmallPattern::from_rows(&["OOO", "..O", ".O."]).unwrap();
+///
+/// assert_ne!(glider, flipped);
+/// assert_eq!(glider.canonical(), flipped.canonical());
+/// assert_eq!(glider.population(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SmallPattern {
+    width: u8,
+    height: u8,
+    bits: u64,
+}
+
+impl SmallPattern {
+    /// The largest width and height of a SmallPattern.
+    pub const MAX_SIZE: usize = 8;
+
+    /// Creates a SmallPattern of `width` × `height` dead [Cells][Cell].
+    ///
+    /// Returns `None` if the width or height is larger than [`MAX_SIZE`][SmallPattern::MAX_SIZE].
+    #[inline]
+    pub const fn new(width: usize, height: usize) -> Option<Self> {
+        match width <= Self::MAX_SIZE && height <= Self::MAX_SIZE {
+            true => Some(Self {
+                width: width as u8,
+                height: height as u8,
+                bits: 0,
+            }),
+            false => None,
+        }
+    }
+
+    /// Creates a SmallPattern from rows in the plaintext format, where `O` is a living [Cell] and everything else a dead one.
+    ///
+    /// Returns `None` if the pattern is larger than [`MAX_SIZE`][SmallPattern::MAX_SIZE] in either direction.
+    pub fn from_rows(rows: &[&str]) -> Option<Self> {
+        let width = rows.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+        let mut pattern = Self::new(width, rows.len())?;
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, _) in row.chars().enumerate().filter(|&(_, character)| character == 'O') {
+                pattern.set(x, y, true);
+            }
+        }
+
+        Some(pattern)
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub const fn width(self) -> usize {
+        self.width as usize
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub const fn height(self) -> usize {
+        self.height as usize
+    }
+
+    /// Returns the [Cells][Cell] as bits, where the bit `y * 8 + x` is set if the [Cell] at `(x, y)` is living.
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.bits
+    }
+
+    /// Returns the number of living [Cells][Cell].
+    #[inline]
+    pub const fn population(self) -> u32 {
+        self.bits.count_ones()
+    }
+
+    /// Returns true if the [Cell] at the given index is living.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub const fn get(self, x: usize, y: usize) -> bool {
+        assert!(x < self.width() && y < self.height(), "index out of bounds");
+        self.bits >> (y * 8 + x) & 1 == 1
+    }
+
+    /// Makes the [Cell] at the given index living or dead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either the `x` or `y` index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub const fn set(&mut self, x: usize, y: usize, alive: bool) {
+        assert!(x < self.width() && y < self.height(), "index out of bounds");
+
+        match alive {
+            true => self.bits |= 1 << (y * 8 + x),
+            false => self.bits &= !(1 << (y * 8 + x)),
+        }
+    }
+
+    /// Returns the pattern cut down to the bounding box of its living [Cells][Cell].
+    ///
+    /// A pattern without living [Cells][Cell] becomes empty.
+    pub const fn trimmed(self) -> Self {
+        if self.bits == 0 {
+            return Self {
+                width: 0,
+                height: 0,
+                bits: 0,
+            };
+        }
+
+        let mut columns = 0;
+        let mut row = 0;
+        while row < 8 {
+            columns |= (self.bits >> (row * 8)) as u8;
+            row += 1;
+        }
+
+        // the columns left of the first living Cell are empty in every row, so nothing moves into another row
+        let bits = self.bits >> (self.bits.trailing_zeros() / 8 * 8) >> columns.trailing_zeros();
+        let rows = (64 - bits.leading_zeros()).div_ceil(8);
+
+        Self {
+            width: (8 - columns.leading_zeros() - columns.trailing_zeros()) as u8,
+            height: rows as u8,
+            bits,
+        }
+    }
+
+    /// Returns the pattern turned by one of the 8 symmetries of the square, before trimming.
+    const fn transformed(self, (transpose, flip_vertically, flip_horizontally): (bool, bool, bool)) -> Self {
+        let mut bits = self.bits;
+
+        if transpose {
+            // swaps the bit y * 8 + x with the bit x * 8 + y
+            let t = 0x0f0f_0f0f_0000_0000 & (bits ^ (bits << 28));
+            bits ^= t ^ (t >> 28);
+            let t = 0x3333_0000_3333_0000 & (bits ^ (bits << 14));
+            bits ^= t ^ (t >> 14);
+            let t = 0x5500_5500_5500_5500 & (bits ^ (bits << 7));
+            bits ^= t ^ (t >> 7);
+        }
+        if flip_vertically {
+            bits = bits.swap_bytes();
+        }
+        if flip_horizontally {
+            bits = (bits >> 1 & 0x5555_5555_5555_5555) | (bits & 0x5555_5555_5555_5555) << 1;
+            bits = (bits >> 2 & 0x3333_3333_3333_3333) | (bits & 0x3333_3333_3333_3333) << 2;
+            bits = (bits >> 4 & 0x0f0f_0f0f_0f0f_0f0f) | (bits & 0x0f0f_0f0f_0f0f_0f0f) << 4;
+        }
+
+        Self {
+            width: 8,
+            height: 8,
+            bits,
+        }
+    }
+
+    /// Returns the canonical form of the pattern, which is the same for all rotations, reflections and placements of its living [Cells][Cell].
+    ///
+    /// The canonical form is trimmed to the bounding box of the living [Cells][Cell] and is the smallest of the 8 orientations, so comparing or hashing canonical forms identifies objects regardless of how they appear in a board.
+    pub const fn canonical(self) -> Self {
+        let mut best = self.trimmed();
+        let mut i = 1;
+
+        while i < SYMMETRIES.len() {
+            let candidate = self.transformed(SYMMETRIES[i]).trimmed();
+
+            if candidate.bits < best.bits {
+                best = candidate;
+            }
+            i += 1;
+        }
+
+        best
+    }
+
+    /// Returns the pattern as a [DynLife], with living [Cells][Cell] as [`Cell::alive`] and dead ones as [`Cell::dead`].
+    pub fn to_dyn_life(self) -> DynLife {
+        let mut life = DynLife::new(self.width(), self.height());
+
+        for y in 0..self.height() {
+            for x in (0..self.width()).filter(|&x| self.get(x, y)) {
+                life.set(x, y, Cell::alive());
+            }
+        }
+
+        life
+    }
+}
+
+/// Converts a [DynLife] into a SmallPattern, with [Cells][Cell] living if [`Cell::is_alive`] returns true.
+///
+/// Returns the DynLife back if it is larger than [`MAX_SIZE`][SmallPattern::MAX_SIZE] in either direction.
+impl<'a> TryFrom<&'a DynLife> for SmallPattern {
+    type Error = &'a DynLife;
+
+    fn try_from(life: &'a DynLife) -> Result<Self, Self::Error> {
+        let mut pattern = Self::new(life.width(), life.height()).ok_or(life)?;
+
+        for (y, row) in life.rows().enumerate() {
+            for (x, _) in row.iter().enumerate().filter(|(_, cell)| cell.is_alive()) {
+                pattern.set(x, y, true);
+            }
+        }
+
+        Ok(pattern)
+    }
+}
+
+/// An object found on a board, kept as a [SmallPattern] if it fits and as a [DynLife] otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let block = DynLife::from([[false, true, true], [false, true, true]]);
+/// let long_line = DynLife::from([[true; 12]]);
+///
+/// assert_eq!(Object::from(&block), Object::Small(SmallPattern::from_rows(&[".OO", ".OO"]).unwrap()));
+/// assert!(matches!(Object::from(&long_line), Object::Large(_)));
+///
+/// assert_eq!(Object::from(&block).canonical(), Object::from(&DynLife::from([[true, true], [true, true]])));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Object {
+    /// An object of up to 8 × 8 [Cells][Cell].
+    Small(SmallPattern),
+    /// A larger object, with living [Cells][Cell] as [`Cell::alive`] and dead ones as [`Cell::dead`].
+    Large(DynLife),
+}
+
+impl Object {
+    /// Returns the number of living [Cells][Cell].
+    pub fn population(&self) -> usize {
+        match self {
+            Self::Small(pattern) => pattern.population() as usize,
+            Self::Large(life) => life.cells().iter().filter(|cell| cell.is_alive()).count(),
+        }
+    }
+
+    /// Returns the canonical form of the object, which is the same for all rotations, reflections and placements of its living [Cells][Cell].
+    ///
+    /// Objects that fit into a [SmallPattern] once trimmed become [`Object::Small`]. See [`SmallPattern::canonical`].
+    pub fn canonical(&self) -> Self {
+        let life = match self {
+            Self::Small(pattern) => return Self::Small(pattern.canonical()),
+            Self::Large(life) => life,
+        };
+
+        let alive: Vec<(usize, usize)> = life.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, cell)| cell.is_alive()).map(move |(x, _)| (x, y)))
+            .collect();
+
+        let orientation = |(transpose, flip_vertically, flip_horizontally): (bool, bool, bool)| {
+            let mut cells: Vec<(usize, usize)> = alive.iter().map(|&(x, y)| {
+                let (x, y) = match transpose {
+                    true => (y, x),
+                    false => (x, y),
+                };
+                (
+                    if flip_horizontally { usize::MAX - x } else { x },
+                    if flip_vertically { usize::MAX - y } else { y },
+                )
+            }).collect();
+
+            let min_x = cells.iter().map(|&(x, _)| x).min().unwrap_or(0);
+            let min_y = cells.iter().map(|&(_, y)| y).min().unwrap_or(0);
+
+            for (x, y) in &mut cells {
+                *x -= min_x;
+                *y -= min_y;
+            }
+
+            let width = cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+            let height = cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+
+            let mut life = DynLife::new(width, height);
+            for (x, y) in cells {
+                life.set(x, y, Cell::alive());
+            }
+
+            life
+        };
+
+        let trimmed = orientation(SYMMETRIES[0]);
+
+        // objects that fit once trimmed are canonicalized like every other SmallPattern
+        match SmallPattern::try_from(&trimmed) {
+            Ok(pattern) => Self::Small(pattern.canonical()),
+            Err(_) => Self::Large(SYMMETRIES.into_iter().map(orientation).min().unwrap_or(trimmed)),
+        }
+    }
+}
+
+/// Converts a [DynLife] into an Object, as a [SmallPattern] if it fits.
+impl From<&DynLife> for Object {
+    fn from(life: &DynLife) -> Self {
+        match SmallPattern::try_from(life) {
+            Ok(pattern) => Self::Small(pattern),
+            Err(life) => Self::Large(life.clone()),
+        }
+    }
+}
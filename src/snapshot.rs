@@ -0,0 +1,273 @@
+//! Saving and restoring boards.
+//!
+//! A snapshot holds the size, the [BoundaryMode], the [Aliveness] and all [Cells][Cell] of a [Life] or [DynLife] in a compact binary format ending with a checksum, so it can be stored between sessions or sent over the network and read back exactly as it was. Both kinds of board write the same format, so a snapshot of one can be restored as the other.
+//!
+//! The format starts with the signature `YGLS` and a version byte, followed by the width and height as little-endian [u32]s, the [BoundaryMode], the [Aliveness], the [Cells][Cell] row by row as RGB bytes and a CRC-32 checksum of everything before it.
+//!
+//! # Versions
+//!
+//! Snapshots are always written in the current [VERSION], but every version since [MIN_VERSION] can still be restored, and [migrate] rewrites an older snapshot in the current version. Version 1 snapshots have no [Aliveness] and are restored with the default [`Aliveness::NonBlack`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use your_game_of_life::*;
+//! let mut life = Life::<2, 3>::from([[true, false, true], [false, true, false]]);
+//! life.boundary = BoundaryMode::Wrap;
+//!
+//! let bytes = life.to_snapshot();
+//!
+//! assert_eq!(Life::<2, 3>::from_snapshot(&bytes), Ok(life));
+//! assert_eq!(DynLife::from_snapshot(&bytes), Ok(DynLife::from(life)));
+//! ```
+
+use std::fmt;
+
+use crate::checksum::crc32;
+use crate::{Aliveness, BoundaryMode, Cell, CellLike, DynLife, Life};
+
+const MAGIC: [u8; 4] = *b"YGLS";
+
+/// The version of the snapshot format written by this version of the crate.
+pub const VERSION: u8 = 2;
+
+/// The oldest version of the snapshot format that can still be restored.
+pub const MIN_VERSION: u8 = 1;
+
+/// The error returned when a snapshot can't be restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapshotError {
+    /// The bytes end before the snapshot does.
+    TooShort,
+    /// The bytes don't start with the snapshot signature.
+    BadMagic,
+    /// The snapshot was written by an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// The checksum of the snapshot doesn't match its contents.
+    ChecksumMismatch {
+        /// The checksum stored in the snapshot.
+        expected: u32,
+        /// The checksum of the read contents.
+        actual: u32,
+    },
+    /// The snapshot has an unknown [BoundaryMode].
+    BadBoundary(u8),
+    /// The snapshot has an unknown [Aliveness].
+    BadAliveness(u8),
+    /// The number of [Cells][Cell] doesn't match the size of the board, or the board doesn't match the size of the [Life] it's restored as.
+    SizeMismatch {
+        /// The width of the board in the snapshot.
+        width: usize,
+        /// The height of the board in the snapshot.
+        height: usize,
+    },
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::TooShort => write!(f, "snapshot is truncated"),
+            Self::BadMagic => write!(f, "bytes are not a snapshot"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported snapshot version {version}"),
+            Self::ChecksumMismatch { expected, actual } => write!(f, "snapshot checksum is {actual:08x} but should be {expected:08x}"),
+            Self::BadBoundary(mode) => write!(f, "unknown snapshot boundary mode {mode}"),
+            Self::BadAliveness(policy) => write!(f, "unknown snapshot aliveness policy {policy}"),
+            Self::SizeMismatch { width, height } => write!(f, "snapshot of {width}x{height} cells doesn't match the board"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Returns the length of the header of a snapshot of the given version, up to the [Cells][Cell].
+const fn header_len(version: u8) -> usize {
+    match version {
+        1 => 4 + 1 + 4 * 2 + 4,
+        _ => 4 + 1 + 4 * 2 + 4 + 4,
+    }
+}
+
+fn write(width: usize, height: usize, boundary: BoundaryMode, aliveness: Aliveness, cells: impl ExactSizeIterator<Item = Cell>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(header_len(VERSION) + cells.len() * 3 + 4);
+
+    bytes.extend_from_slice(&MAGIC);
+    bytes.push(VERSION);
+    bytes.extend_from_slice(&(width as u32).to_le_bytes());
+    bytes.extend_from_slice(&(height as u32).to_le_bytes());
+    bytes.extend_from_slice(&boundary.to_bytes());
+    bytes.extend_from_slice(&aliveness.to_bytes());
+
+    for cell in cells {
+        bytes.extend_from_slice(&[cell.r, cell.g, cell.b]);
+    }
+
+    bytes.extend_from_slice(&crc32(&bytes).to_le_bytes());
+    bytes
+}
+
+/// The contents of a snapshot of any supported version.
+struct Contents<'a> {
+    width: usize,
+    height: usize,
+    boundary: BoundaryMode,
+    aliveness: Aliveness,
+    /// The [Cells][Cell] as RGB bytes.
+    cells: &'a [u8],
+}
+
+fn read(bytes: &[u8]) -> Result<Contents<'_>, SnapshotError> {
+    let version = version(bytes)?;
+    let header_len = header_len(version);
+
+    if bytes.len() < header_len + 4 {
+        return Err(SnapshotError::TooShort);
+    }
+
+    let (body, checksum) = bytes.split_at(bytes.len() - 4);
+    let expected = u32::from_le_bytes(checksum.try_into().unwrap());
+    let actual = crc32(body);
+
+    if expected != actual {
+        return Err(SnapshotError::ChecksumMismatch { expected, actual });
+    }
+
+    let width = u32::from_le_bytes(body[5..9].try_into().unwrap()) as usize;
+    let height = u32::from_le_bytes(body[9..13].try_into().unwrap()) as usize;
+    let boundary = BoundaryMode::from_bytes(body[13..17].try_into().unwrap()).map_err(SnapshotError::BadBoundary)?;
+    let aliveness = match version {
+        1 => Aliveness::NonBlack,
+        _ => Aliveness::from_bytes(body[17..21].try_into().unwrap()).map_err(SnapshotError::BadAliveness)?,
+    };
+    let cells = &body[header_len..];
+
+    match width.checked_mul(height).and_then(|len| len.checked_mul(3)) == Some(cells.len()) {
+        true => Ok(Contents {
+            width,
+            height,
+            boundary,
+            aliveness,
+            cells,
+        }),
+        false => Err(SnapshotError::SizeMismatch { width, height }),
+    }
+}
+
+/// Returns the format version of a snapshot without restoring it, failing if it isn't supported.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// use your_game_of_life::snapshot::{self, SnapshotError};
+///
+/// let mut bytes = Life::<2, 2>::default().to_snapshot();
+/// assert_eq!(snapshot::version(&bytes), Ok(snapshot::VERSION));
+///
+/// bytes[4] = 99;
+/// assert_eq!(snapshot::version(&bytes), Err(SnapshotError::UnsupportedVersion(99)));
+/// ```
+pub fn version(bytes: &[u8]) -> Result<u8, SnapshotError> {
+    if bytes.len() < MAGIC.len() + 1 {
+        return Err(SnapshotError::TooShort);
+    }
+    if bytes[..4] != MAGIC {
+        return Err(SnapshotError::BadMagic);
+    }
+
+    match bytes[4] {
+        version @ MIN_VERSION..=VERSION => Ok(version),
+        version => Err(SnapshotError::UnsupportedVersion(version)),
+    }
+}
+
+/// Rewrites a snapshot of any supported version in the current [VERSION], verifying its checksum.
+///
+/// Snapshots that already have the current version are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// use your_game_of_life::snapshot;
+///
+/// // a version 1 snapshot of a single red cell
+/// let old = [b'Y', b'G', b'L', b'S', 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0x1a, 0x15, 0x1d, 0xf2];
+///
+/// let migrated = snapshot::migrate(&old).unwrap();
+///
+/// assert_eq!(snapshot::version(&migrated), Ok(snapshot::VERSION));
+/// assert_eq!(Life::<1, 1>::from_snapshot(&migrated), Life::from_snapshot(&old));
+/// assert_eq!(Life::<1, 1>::from_snapshot(&old).unwrap().cells, [[Cell::red()]]);
+/// assert_eq!(snapshot::migrate(&migrated).as_ref(), Ok(&migrated));
+/// ```
+pub fn migrate(bytes: &[u8]) -> Result<Vec<u8>, SnapshotError> {
+    let contents = read(bytes)?;
+
+    if bytes[4] == VERSION {
+        return Ok(bytes.to_vec());
+    }
+
+    let cells = contents.cells.chunks_exact(3).map(|rgb| Cell::from([rgb[0], rgb[1], rgb[2]]));
+    Ok(write(contents.width, contents.height, contents.boundary, contents.aliveness, cells))
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Life<HEIGHT, WIDTH, C> {
+    /// Encodes the Life as a snapshot, including its [`boundary`] mode and [`aliveness`].
+    ///
+    /// The [Cells][Cell] of other [CellLike] types are stored as the ones returned by [`CellLike::to_rgb`].
+    ///
+    /// [`boundary`]: struct.Life.html#structfield.boundary
+    /// [`aliveness`]: struct.Life.html#structfield.aliveness
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        write(WIDTH, HEIGHT, self.boundary, self.aliveness, self.cells.as_flattened().iter().map(|&cell| cell.to_rgb()))
+    }
+
+    /// Restores a Life from a snapshot written by [`Life::to_snapshot`] or [`DynLife::to_snapshot`], verifying its checksum.
+    ///
+    /// The board in the snapshot must have exactly the size of the Life. The cells of other [CellLike] types are created with [`CellLike::from_rgb`].
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let contents = read(bytes)?;
+
+        if contents.width != WIDTH || contents.height != HEIGHT {
+            return Err(SnapshotError::SizeMismatch {
+                width: contents.width,
+                height: contents.height,
+            });
+        }
+
+        let mut life = Self {
+            boundary: contents.boundary,
+            aliveness: contents.aliveness,
+            ..Self::default()
+        };
+
+        for (cell, rgb) in life.cells.as_flattened_mut().iter_mut().zip(contents.cells.chunks_exact(3)) {
+            *cell = C::from_rgb(Cell::from([rgb[0], rgb[1], rgb[2]]));
+        }
+
+        Ok(life)
+    }
+}
+
+impl DynLife {
+    /// Encodes the DynLife as a snapshot, including its [`boundary`] mode and [`aliveness`].
+    ///
+    /// [`boundary`]: DynLife#structfield.boundary
+    /// [`aliveness`]: DynLife#structfield.aliveness
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        write(self.width(), self.height(), self.boundary, self.aliveness, self.cells().iter().copied())
+    }
+
+    /// Restores a DynLife from a snapshot written by [`DynLife::to_snapshot`] or [`Life::to_snapshot`], verifying its checksum.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let Contents { width, height, boundary, aliveness, cells } = read(bytes)?;
+        let cells = cells.chunks_exact(3).map(|rgb| Cell::from([rgb[0], rgb[1], rgb[2]])).collect();
+
+        // read checked that the number of cells matches the size
+        let mut life = Self::from_cells(width, height, cells).ok_or(SnapshotError::SizeMismatch { width, height })?;
+        life.boundary = boundary;
+        life.aliveness = aliveness;
+
+        Ok(life)
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Aliveness, BoundaryMode, Cell, DynLife, Grid, Life, Point, Rule, SizeMismatch, NEIGHBOR_OFFSETS};
+
+/// A board of living and dead [Cells][Cell] storing only the positions of the living ones.
+///
+/// A [Life] or [DynLife] stores every [Cell], so a million-cell board with a few gliders on it wastes memory and time on empty space. A SparseLife only looks at the living [Cells][Cell] and their neighbors, so its cost grows with the population instead of the size of the board.
+///
+/// A SparseLife only knows living and dead [Cells][Cell], not colors: the closures of [`play`][SparseLife::play] receive [`Cell::alive`] and [`Cell::dead`] and their result is read with [`Cell::is_alive`]. Dead [Cells][Cell] without living neighbors are assumed to stay dead, so rules where they are born, like `B0` rules, aren't supported.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let mut life = SparseLife::new(1_000_000, 1_000_000);
+/// for x in 1..4 {
+///     life.set(Point::new(x, 500_000), true);
+/// }
+///
+/// life.play_rule(&Rule::CONWAY);
+///
+/// assert_eq!(life.population(), 3);
+/// assert!(life.get(Point::new(2, 499_999)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseLife {
+    width: usize,
+    height: usize,
+    alive: HashSet<Point>,
+    /// Decides which [Cell] is added in the `[Cell; 8]` array on invoking the closure for [`play`][SparseLife::play] when the neighboring [Cell] would have been out of bounds. The color of a [`Constant`][BoundaryMode::Constant] [Cell] only matters through [`Cell::is_alive`].
+    pub boundary: BoundaryMode,
+}
+
+impl SparseLife {
+    /// Creates a SparseLife of `width` × `height` dead [Cells][Cell].
+    #[inline]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            alive: HashSet::new(),
+            boundary: BoundaryMode::default(),
+        }
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of living [Cells][Cell].
+    #[inline]
+    pub fn population(&self) -> usize {
+        self.alive.len()
+    }
+
+    /// Returns an iterator over the positions of the living [Cells][Cell], in no particular order.
+    #[inline]
+    pub fn alive(&self) -> impl Iterator<Item = Point> + '_ {
+        self.alive.iter().copied()
+    }
+
+    /// Returns true if the [Cell] at `point` is living.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point` is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn get(&self, point: Point) -> bool {
+        assert!(point.x < self.width && point.y < self.height, "point {point} out of bounds for a board of {}x{} cells", self.width, self.height);
+        self.alive.contains(&point)
+    }
+
+    /// Makes the [Cell] at `point` living or dead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `point` is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn set(&mut self, point: Point, alive: bool) {
+        assert!(point.x < self.width && point.y < self.height, "point {point} out of bounds for a board of {}x{} cells", self.width, self.height);
+
+        match alive {
+            true => self.alive.insert(point),
+            false => self.alive.remove(&point),
+        };
+    }
+
+    /// Returns the position a neighbor at `(dx, dy)` of `point` refers to, or `None` if it's the [`Constant`][BoundaryMode::Constant] [Cell].
+    fn neighbor(&self, point: Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let x = self.boundary.resolve(point.x as isize + dx, self.width)?;
+        let y = self.boundary.resolve(point.y as isize + dy, self.height)?;
+        Some(Point::new(x, y))
+    }
+
+    /// Returns the [Cells][Cell] that may change in the next generation: the living ones, their neighbors and, if the [`Constant`][BoundaryMode::Constant] [Cell] is living, the edges.
+    fn candidates(&self) -> HashSet<Point> {
+        let mut candidates: HashSet<Point> = self.alive.iter()
+            .flat_map(|&point| NEIGHBOR_OFFSETS.into_iter().filter_map(move |offset| self.neighbor(point, offset)).chain([point]))
+            .collect();
+
+        if self.boundary.outside().is_alive() && self.width != 0 && self.height != 0 {
+            for x in 0..self.width {
+                candidates.extend([Point::new(x, 0), Point::new(x, self.height - 1)]);
+            }
+            for y in 0..self.height {
+                candidates.extend([Point::new(0, y), Point::new(self.width - 1, y)]);
+            }
+        }
+
+        candidates
+    }
+
+    /// Panics if a living [Cell] is out of bounds.
+    #[cfg(feature = "strict")]
+    fn check_invariants(&self) {
+        for point in &self.alive {
+            assert!(point.x < self.width && point.y < self.height, "living cell {point} out of bounds for a board of {}x{} cells", self.width, self.height);
+        }
+    }
+
+    /// Invokes the given closure on each [Cell] that may change, like [`Life::play`] would on every [Cell].
+    ///
+    /// The closure is only invoked on living [Cells][Cell] and dead [Cells][Cell] with at least one living neighbor.
+    pub fn play(&mut self, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        #[cfg(feature = "strict")]
+        self.check_invariants();
+
+        let outside = Cell::from(self.boundary.outside().is_alive());
+
+        let next = self.candidates().into_iter().filter(|&point| {
+            let mut neighbors = [outside; 8];
+
+            for (neighbor, offset) in neighbors.iter_mut().zip(NEIGHBOR_OFFSETS) {
+                if let Some(position) = self.neighbor(point, offset) {
+                    *neighbor = Cell::from(self.alive.contains(&position));
+                }
+            }
+
+            f(Cell::from(self.alive.contains(&point)), neighbors, point.x, point.y).is_alive()
+        }).collect();
+
+        self.alive = next;
+    }
+
+    /// Invokes [`play`][SparseLife::play] `n` times.
+    #[inline]
+    pub fn play_for(&mut self, n: u32, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) {
+        for _ in 0..n {
+            self.play(&mut f);
+        }
+    }
+
+    /// Steps the SparseLife once by the given [Rule].
+    ///
+    /// This gives the same result as [`play`][SparseLife::play] with [`Rule::apply`], but only counts neighbors around living [Cells][Cell] instead of collecting all 8 for every candidate.
+    pub fn play_rule(&mut self, rule: &Rule) {
+        #[cfg(feature = "strict")]
+        self.check_invariants();
+
+        if self.boundary.outside().is_alive() {
+            return self.play(|this, others, _, _| rule.apply(this, others));
+        }
+
+        let mut counts: HashMap<Point, u8> = HashMap::with_capacity(self.alive.len() * 8);
+
+        for &point in &self.alive {
+            for neighbor in NEIGHBOR_OFFSETS.into_iter().filter_map(|offset| self.neighbor(point, offset)) {
+                *counts.entry(neighbor).or_default() += 1;
+            }
+        }
+
+        let survivors = self.alive.iter().filter(|point| rule.survives(counts.get(point).copied().unwrap_or(0))).copied();
+        let births = counts.iter().filter(|&(point, &count)| !self.alive.contains(point) && rule.is_born(count)).map(|(&point, _)| point);
+
+        self.alive = survivors.chain(births).collect();
+    }
+
+    /// Steps the SparseLife `n` times by the given [Rule].
+    #[inline]
+    pub fn play_rule_for(&mut self, n: u32, rule: &Rule) {
+        for _ in 0..n {
+            self.play_rule(rule);
+        }
+    }
+
+    /// Creates a SparseLife from any [Grid], with [Cells][Cell] living if [`Cell::is_alive`] returns true.
+    #[inline]
+    pub fn from_grid(grid: &impl Grid) -> Self {
+        Self::from_grid_by(grid, Aliveness::NonBlack)
+    }
+
+    /// Creates a SparseLife from any [Grid], with [Cells][Cell] living if they are alive according to `aliveness`.
+    pub fn from_grid_by(grid: &impl Grid, aliveness: Aliveness) -> Self {
+        let mut life = Self::new(grid.width(), grid.height());
+
+        for y in 0..grid.height() {
+            life.alive.extend((0..grid.width()).map(|x| Point::new(x, y)).filter(|&point| aliveness.is_alive(grid.cell(point))));
+        }
+
+        life
+    }
+}
+
+/// Living [Cells][Cell] are [`Cell::alive`] and dead ones [`Cell::dead`].
+impl Grid for SparseLife {
+    #[inline]
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn cell(&self, point: Point) -> Cell {
+        Cell::from(self.get(point))
+    }
+}
+
+/// Converts a [Life] into a SparseLife, keeping its [`boundary`][Life#structfield.boundary] mode. [Cells][Cell] are living if they are alive according to its [`aliveness`][Life#structfield.aliveness].
+impl<const HEIGHT: usize, const WIDTH: usize> From<&Life<HEIGHT, WIDTH>> for SparseLife {
+    fn from(life: &Life<HEIGHT, WIDTH>) -> Self {
+        Self {
+            boundary: life.boundary.by_aliveness(life.aliveness),
+            ..Self::from_grid_by(life, life.aliveness)
+        }
+    }
+}
+
+/// Converts a [DynLife] into a SparseLife, keeping its [`boundary`][DynLife#structfield.boundary] mode. [Cells][Cell] are living if they are alive according to its [`aliveness`][DynLife#structfield.aliveness].
+impl From<&DynLife> for SparseLife {
+    fn from(life: &DynLife) -> Self {
+        Self {
+            boundary: life.boundary.by_aliveness(life.aliveness),
+            ..Self::from_grid_by(life, life.aliveness)
+        }
+    }
+}
+
+/// Converts a SparseLife into a [DynLife] of living [`Cell::alive`] and dead [`Cell::dead`] [Cells][Cell], keeping its [`boundary`][SparseLife#structfield.boundary] mode.
+impl From<&SparseLife> for DynLife {
+    fn from(life: &SparseLife) -> Self {
+        let mut dense = DynLife::new(life.width, life.height);
+        dense.boundary = life.boundary;
+
+        for point in life.alive() {
+            dense.set(point.x, point.y, Cell::alive());
+        }
+
+        dense
+    }
+}
+
+/// Converts a SparseLife into a [Life] of living [`Cell::alive`] and dead [`Cell::dead`] [Cells][Cell], keeping its [`boundary`][SparseLife#structfield.boundary] mode.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let life = Life::<2, 3>::from([[true, false, true], [false, true, false]]);
+///
+/// assert_eq!(Life::<2, 3>::try_from(&SparseLife::from(&life)), Ok(life));
+/// ```
+impl<const HEIGHT: usize, const WIDTH: usize> TryFrom<&SparseLife> for Life<HEIGHT, WIDTH> {
+    type Error = SizeMismatch;
+
+    fn try_from(life: &SparseLife) -> Result<Self, Self::Error> {
+        if life.width != WIDTH || life.height != HEIGHT {
+            return Err(SizeMismatch {
+                width: life.width,
+                height: life.height,
+            });
+        }
+
+        let mut dense = Life {
+            boundary: life.boundary,
+            ..Life::default()
+        };
+
+        for point in life.alive() {
+            dense.cells[point.y][point.x] = Cell::alive();
+        }
+
+        Ok(dense)
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{Aliveness, Cell, DynLife, Life};
+
+/// How a run of [`Life::play_until_stable`] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stability {
+    /// The board stopped changing.
+    StillLife,
+    /// The board repeats itself every `period` generations.
+    Oscillator {
+        /// The number of generations after which the board repeats, at least 2.
+        period: u32,
+    },
+    /// The step limit was reached before the board repeated itself.
+    StepLimit,
+}
+
+/// The result of [`Life::play_until_stable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Stabilization {
+    /// The number of generations that were run.
+    pub generations: u32,
+    /// How the run ended.
+    pub stability: Stability,
+}
+
+/// When two [Cells][Cell] count as the same, for detecting repetitions and comparing boards.
+///
+/// Colored rules rarely repeat exactly even when the pattern does, so the colors can be ignored entirely or compared only roughly.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// assert!(!CellEq::Exact.matches(Cell::red(), Cell::white()));
+/// assert!(CellEq::Alive.matches(Cell::red(), Cell::white()));
+/// assert!(!CellEq::Alive.matches(Cell::red(), Cell::black()));
+/// assert!(CellEq::Quantized { bits: 2 }.matches(Cell::all(200), Cell::all(250)));
+/// assert!(!CellEq::Quantized { bits: 2 }.matches(Cell::all(100), Cell::all(200)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CellEq {
+    /// [Cells][Cell] are the same if all their RGB values are equal.
+    #[default]
+    Exact,
+    /// [Cells][Cell] are the same if both are alive or both are dead, as determined by [`Cell::is_alive`]. When comparing boards, the [`aliveness`][crate::Life#structfield.aliveness] of each board decides instead.
+    Alive,
+    /// [Cells][Cell] are the same if the highest `bits` bits of each RGB value are equal, so colors are compared in `2^bits` levels per channel. 8 bits are the same as [`Exact`][CellEq::Exact] and 0 bits make all [Cells][Cell] the same.
+    Quantized {
+        /// The number of bits compared per channel, at most 8.
+        bits: u8,
+    },
+}
+
+impl CellEq {
+    /// Returns the [Cell] standing for all [Cells][Cell] that are the same as `cell`.
+    #[inline]
+    pub const fn normalize(self, cell: Cell) -> Cell {
+        match self {
+            Self::Exact => cell,
+            Self::Alive => match cell.is_alive() {
+                true => Cell::alive(),
+                false => Cell::dead(),
+            },
+            Self::Quantized { bits } => {
+                let mask = match bits {
+                    0 => 0,
+                    1..=7 => !(u8::MAX >> bits),
+                    _ => u8::MAX,
+                };
+
+                Cell {
+                    r: cell.r & mask,
+                    g: cell.g & mask,
+                    b: cell.b & mask,
+                }
+            }
+        }
+    }
+
+    /// Returns true if `a` and `b` are the same.
+    #[inline]
+    pub const fn matches(self, a: Cell, b: Cell) -> bool {
+        let (a, b) = (self.normalize(a), self.normalize(b));
+        a.r == b.r && a.g == b.g && a.b == b.b
+    }
+
+    /// Returns true if both slices have the same length and all of their [Cells][Cell] are the same.
+    pub fn matches_all(self, a: &[Cell], b: &[Cell]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(&a, &b)| self.matches(a, b))
+    }
+}
+
+fn hash_cells(cells: &[Cell], eq: CellEq, aliveness: Aliveness) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    match eq {
+        CellEq::Exact => cells.hash(&mut hasher),
+        CellEq::Alive => cells.iter().for_each(|&cell| aliveness.is_alive(cell).hash(&mut hasher)),
+        _ => cells.iter().for_each(|&cell| eq.normalize(cell).hash(&mut hasher)),
+    }
+
+    hasher.finish()
+}
+
+/// Returns true if both boards have the same [Cells][Cell] according to `eq`, with each board's [Aliveness] deciding for [`CellEq::Alive`].
+fn boards_match(a: &[Cell], a_aliveness: Aliveness, b: &[Cell], b_aliveness: Aliveness, eq: CellEq) -> bool {
+    match eq {
+        CellEq::Alive => a.len() == b.len() && a.iter().zip(b).all(|(&a, &b)| a_aliveness.is_alive(a) == b_aliveness.is_alive(b)),
+        eq => eq.matches_all(a, b),
+    }
+}
+
+/// Steps a board with `step` until it repeats within `period_limit` generations or `max_steps` generations have run.
+fn run_until_stable<T>(board: &mut T, max_steps: u32, period_limit: u32, hash: impl Fn(&T) -> u64, mut step: impl FnMut(&mut T)) -> Stabilization {
+    // the hashes of the most recent generations, newest first
+    let mut history = VecDeque::with_capacity(period_limit.min(1024) as usize);
+    history.push_front(hash(board));
+
+    for generation in 1..=max_steps {
+        step(board);
+        let current = hash(board);
+
+        if let Some(position) = history.iter().position(|&previous| previous == current) {
+            let period = position as u32 + 1;
+
+            return Stabilization {
+                generations: generation,
+                stability: match period {
+                    1 => Stability::StillLife,
+                    period => Stability::Oscillator { period },
+                },
+            };
+        }
+
+        if history.len() >= period_limit as usize {
+            history.pop_back();
+        }
+        history.push_front(current);
+    }
+
+    Stabilization {
+        generations: max_steps,
+        stability: Stability::StepLimit,
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Invokes the given closure on each [Cell] in the Life, like [`play`][Life::play], until the board stops changing or starts repeating itself.
+    ///
+    /// Oscillations are detected up to a period of `period_limit` generations by remembering a hash of the [Cells][Cell] of that many previous generations. The board is left in the generation where the repetition was noticed. At most `max_steps` generations are run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let rule = |this: Cell, others: [Cell; 8], _, _| Rule::CONWAY.apply(this, others);
+    ///
+    /// let mut blinker = Life::<5, 5>::default();
+    /// for x in 1..4 {
+    ///     blinker.set(x, 2, Cell::alive());
+    /// }
+    ///
+    /// assert_eq!(blinker.play_until_stable(100, 10, rule), Stabilization {
+    ///     generations: 2,
+    ///     stability: Stability::Oscillator { period: 2 },
+    /// });
+    ///
+    /// // a lone cell dies and leaves an empty board
+    /// let mut lonely = Life::<3, 3>::default();
+    /// lonely.set(1, 1, Cell::alive());
+    ///
+    /// assert_eq!(lonely.play_until_stable(100, 10, rule).stability, Stability::StillLife);
+    /// ```
+    #[inline]
+    pub fn play_until_stable(&mut self, max_steps: u32, period_limit: u32, f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) -> Stabilization {
+        self.play_until_stable_with(max_steps, period_limit, CellEq::Exact, f)
+    }
+
+    /// Invokes the given closure on each [Cell] in the Life until the board stops changing or starts repeating itself, with generations compared by `eq`.
+    ///
+    /// This works like [`play_until_stable`][Life::play_until_stable], but generations whose [Cells][Cell] only differ in ways `eq` ignores count as repetitions.
+    ///
+    /// # Examples
+    ///
+    /// A blinker whose cells get darker every generation only repeats when the colors are ignored:
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let rule = |this: Cell, others: [Cell; 8], _, _| match (this.is_alive(), others.alive()) {
+    ///     (true, 2 | 3) => Cell::all(this.r.saturating_sub(1).max(1)),
+    ///     (false, 3) => Cell::all(200),
+    ///     _ => Cell::dead(),
+    /// };
+    ///
+    /// let mut life = Life::<5, 5>::default();
+    /// for x in 1..4 {
+    ///     life.set(x, 2, Cell::all(200));
+    /// }
+    ///
+    /// assert_eq!(life.play_until_stable(10, 4, rule).stability, Stability::StepLimit);
+    /// assert_eq!(life.play_until_stable_with(10, 4, CellEq::Alive, rule).stability, Stability::Oscillator { period: 2 });
+    /// ```
+    pub fn play_until_stable_with(&mut self, max_steps: u32, period_limit: u32, eq: CellEq, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) -> Stabilization {
+        run_until_stable(self, max_steps, period_limit, |life| hash_cells(life.cells.as_flattened(), eq, life.aliveness), |life| life.play(&mut f))
+    }
+
+    /// Returns true if all [Cells][Cell] of both Lifes are the same according to `eq`.
+    ///
+    /// This is meant for comparing a board with an expected one in tests when colors may differ slightly.
+    #[inline]
+    pub fn eq_by(&self, other: &Self, eq: CellEq) -> bool {
+        boards_match(self.cells.as_flattened(), self.aliveness, other.cells.as_flattened(), other.aliveness, eq)
+    }
+}
+
+impl DynLife {
+    /// Invokes the given closure on each [Cell] in the DynLife until the board stops changing or starts repeating itself.
+    ///
+    /// This works exactly like [`Life::play_until_stable`].
+    #[inline]
+    pub fn play_until_stable(&mut self, max_steps: u32, period_limit: u32, f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) -> Stabilization {
+        self.play_until_stable_with(max_steps, period_limit, CellEq::Exact, f)
+    }
+
+    /// Invokes the given closure on each [Cell] in the DynLife until the board stops changing or starts repeating itself, with generations compared by `eq`.
+    ///
+    /// This works exactly like [`Life::play_until_stable_with`].
+    pub fn play_until_stable_with(&mut self, max_steps: u32, period_limit: u32, eq: CellEq, mut f: impl FnMut(Cell, [Cell; 8], usize, usize) -> Cell) -> Stabilization {
+        run_until_stable(self, max_steps, period_limit, |life| hash_cells(life.cells(), eq, life.aliveness), |life| life.play(&mut f))
+    }
+
+    /// Returns true if both DynLifes have the same size and all of their [Cells][Cell] are the same according to `eq`.
+    #[inline]
+    pub fn eq_by(&self, other: &Self, eq: CellEq) -> bool {
+        self.width() == other.width() && boards_match(self.cells(), self.aliveness, other.cells(), other.aliveness, eq)
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{Cell, DynLife, Grid, Life, Point};
+
+/// One of the 8 ways to rotate or flip a pattern while stamping it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Transform {
+    /// Leaves the pattern as it is.
+    #[default]
+    Identity,
+    /// Rotates the pattern by 90° clockwise.
+    Rotate90,
+    /// Rotates the pattern by 180°.
+    Rotate180,
+    /// Rotates the pattern by 270° clockwise, or 90° counterclockwise.
+    Rotate270,
+    /// Mirrors the pattern left to right.
+    FlipHorizontal,
+    /// Mirrors the pattern top to bottom.
+    FlipVertical,
+    /// Mirrors the pattern along the diagonal from the top left to the bottom right.
+    Transpose,
+    /// Mirrors the pattern along the diagonal from the top right to the bottom left.
+    AntiTranspose,
+}
+
+impl Transform {
+    /// All 8 transforms.
+    pub const ALL: [Self; 8] = [Self::Identity, Self::Rotate90, Self::Rotate180, Self::Rotate270, Self::FlipHorizontal, Self::FlipVertical, Self::Transpose, Self::AntiTranspose];
+
+    /// Returns the `(width, height)` of a `width` × `height` pattern after the transform.
+    #[inline]
+    pub const fn size(self, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Self::Identity | Self::Rotate180 | Self::FlipHorizontal | Self::FlipVertical => (width, height),
+            Self::Rotate90 | Self::Rotate270 | Self::Transpose | Self::AntiTranspose => (height, width),
+        }
+    }
+
+    /// Returns where the [Cell] at `point` of a `width` × `height` pattern ends up after the transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// // the top left corner of a 3 × 2 pattern turned clockwise is its top right corner
+    /// assert_eq!(Transform::Rotate90.apply(Point::new(0, 0), 3, 2), Point::new(1, 0));
+    /// assert_eq!(Transform::Rotate90.size(3, 2), (2, 3));
+    /// ```
+    #[inline]
+    pub const fn apply(self, point: Point, width: usize, height: usize) -> Point {
+        let Point { x, y } = point;
+
+        match self {
+            Self::Identity => Point::new(x, y),
+            Self::Rotate90 => Point::new(height - 1 - y, x),
+            Self::Rotate180 => Point::new(width - 1 - x, height - 1 - y),
+            Self::Rotate270 => Point::new(y, width - 1 - x),
+            Self::FlipHorizontal => Point::new(width - 1 - x, y),
+            Self::FlipVertical => Point::new(x, height - 1 - y),
+            Self::Transpose => Point::new(y, x),
+            Self::AntiTranspose => Point::new(height - 1 - y, width - 1 - x),
+        }
+    }
+}
+
+/// What happens to the parts of a stamped pattern beyond the edges of the board.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Edges {
+    /// The parts beyond the edges are cut off.
+    #[default]
+    Clip,
+    /// The parts beyond the edges wrap around to the opposite edge, like on a torus.
+    Wrap,
+}
+
+/// How [`Life::stamp_with`] copies a pattern onto a board.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StampOptions {
+    /// Whether the dead [Cells][Cell] of the pattern, according to [`Grid::is_alive_at`], leave the board unchanged instead of overwriting it.
+    pub transparent_dead: bool,
+    /// What happens to the parts of the pattern beyond the edges of the board.
+    pub edges: Edges,
+    /// How the pattern is rotated or flipped before being stamped.
+    pub transform: Transform,
+}
+
+/// Copies `pattern` onto a `width` × `height` board with its top left corner at `(x, y)`, calling `set` for every [Cell] to change.
+pub(crate) fn stamp_onto(width: usize, height: usize, mut set: impl FnMut(Point, Cell), pattern: &impl Grid, x: isize, y: isize, options: &StampOptions) {
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    let (pattern_width, pattern_height) = (pattern.width(), pattern.height());
+
+    for py in 0..pattern_height {
+        for px in 0..pattern_width {
+            let cell = pattern.cell(Point::new(px, py));
+
+            if options.transparent_dead && !pattern.is_alive_at(Point::new(px, py)) {
+                continue;
+            }
+
+            let transformed = options.transform.apply(Point::new(px, py), pattern_width, pattern_height);
+
+            let target = match options.edges {
+                Edges::Clip => match (x.checked_add_unsigned(transformed.x), y.checked_add_unsigned(transformed.y)) {
+                    (Some(tx), Some(ty)) if tx >= 0 && ty >= 0 && (tx as usize) < width && (ty as usize) < height => Point::new(tx as usize, ty as usize),
+                    _ => continue,
+                },
+                Edges::Wrap => Point::new(
+                    (x.rem_euclid(width as isize) as usize + transformed.x % width) % width,
+                    (y.rem_euclid(height as isize) as usize + transformed.y % height) % height,
+                ),
+            };
+
+            set(target, cell);
+        }
+    }
+}
+
+/// Copies `pattern` onto a `width` × `height` board with its top left corner at `at`, like [`stamp_onto`].
+///
+/// A position beyond [`isize::MAX`] lies far beyond the board, so the pattern is cut off entirely, unless it wraps around the edges.
+fn stamp_onto_point(width: usize, height: usize, set: impl FnMut(Point, Cell), pattern: &impl Grid, at: Point, options: &StampOptions) {
+    let position = match options.edges {
+        // reducing first keeps the position in range, the board repeats at this width anyway
+        Edges::Wrap if width != 0 && height != 0 => Some(((at.x % width) as isize, (at.y % height) as isize)),
+        Edges::Wrap => None,
+        Edges::Clip => isize::try_from(at.x).ok().zip(isize::try_from(at.y).ok()),
+    };
+
+    if let Some((x, y)) = position {
+        stamp_onto(width, height, set, pattern, x, y, options);
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Copies all [Cells][Cell] of `pattern` onto the Life with its top left corner at `at`, cutting off what doesn't fit.
+    ///
+    /// The pattern can be any [Grid], like a smaller Life or a [DynLife]. Use [`stamp_with`][Life::stamp_with] for more control.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let glider = Life::<3, 3>::from_plaintext(".O.\n..O\nOOO\n").unwrap();
+    ///
+    /// let mut life = Life::<4, 5>::default();
+    /// life.stamp(&glider, Point::new(1, 1));
+    ///
+    /// assert_eq!(life.to_plaintext(), ".....\n..O..\n...O.\n.OOO.\n");
+    /// ```
+    #[inline]
+    pub fn stamp(&mut self, pattern: &impl Grid, at: Point) {
+        self.stamp_with(pattern, at, &StampOptions::default());
+    }
+
+    /// Copies `pattern` onto the Life with its top left corner at `at`, as described by `options`.
+    ///
+    /// The position is that of the top left corner after the [Transform].
+    ///
+    /// # Examples
+    ///
+    /// A glider turned to fly up and to the left, with its dead [Cells][Cell] leaving the board unchanged and wrapping around the edges:
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let glider = SmallPattern::from_rows(&[".O.", "..O", "OOO"]).unwrap();
+    ///
+    /// let mut life = Life::<4, 4>::from([[true, false, false, false]; 4]);
+    /// life.stamp_with(&glider, Point::new(2, 0), &StampOptions {
+    ///     transparent_dead: true,
+    ///     edges: Edges::Wrap,
+    ///     transform: Transform::Rotate180,
+    /// });
+    ///
+    /// assert_eq!(life.to_plaintext(), "O.OO\nO.O.\nO..O\nO...\n");
+    ///
+    /// // far beyond the bottom right corner, nothing is stamped
+    /// let before = life;
+    /// life.stamp_with(&glider, Point::new(usize::MAX, 0), &StampOptions::default());
+    /// assert_eq!(life, before);
+    /// ```
+    pub fn stamp_with(&mut self, pattern: &impl Grid, at: Point, options: &StampOptions) {
+        stamp_onto_point(WIDTH, HEIGHT, |point, cell| self.cells[point.y][point.x] = cell, pattern, at, options);
+    }
+
+    /// Copies all [Cells][Cell] of `pattern` onto the Life with its top left corner at `(x, y)`, cutting off what doesn't fit.
+    ///
+    /// The position may be negative, so patterns can slide in from beyond the left and top edges, and centering a pattern larger than the board just works.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let block = Life::<2, 2>::from([[true; 2]; 2]);
+    ///
+    /// let mut life = Life::<2, 2>::default();
+    /// life.insert_clipped(&block, -1, 1);
+    ///
+    /// assert_eq!(life.to_plaintext(), "..\nO.\n");
+    /// ```
+    #[inline]
+    pub fn insert_clipped(&mut self, pattern: &impl Grid, x: isize, y: isize) {
+        self.stamp_at(pattern, x, y, &StampOptions::default());
+    }
+
+    /// Copies `pattern` onto the Life with its top left corner at `(x, y)`, which may be negative, as described by `options`.
+    ///
+    /// With [`Edges::Wrap`], a negative position wraps around to the right and bottom edges.
+    pub fn stamp_at(&mut self, pattern: &impl Grid, x: isize, y: isize, options: &StampOptions) {
+        stamp_onto(WIDTH, HEIGHT, |point, cell| self.cells[point.y][point.x] = cell, pattern, x, y, options);
+    }
+}
+
+/// Returns the position of the top left corner of something `len` [Cells][Cell] long centered on an axis `space` [Cells][Cell] long, rounding towards the top left.
+fn centered_start(space: usize, len: usize) -> isize {
+    (space as isize - len as isize).div_euclid(2)
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Creates a Life with `pattern` in its middle, cutting off what doesn't fit.
+    ///
+    /// If the pattern can't be centered exactly, it's placed half a [Cell] towards the top left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let blinker = SmallPattern::from_rows(&["OOO"]).unwrap();
+    ///
+    /// assert_eq!(Life::<3, 5>::centered(&blinker).to_plaintext(), ".....\n.OOO.\n.....\n");
+    /// ```
+    pub fn centered(pattern: &impl Grid) -> Self {
+        let mut life = Self::default();
+        life.insert_clipped(pattern, centered_start(WIDTH, pattern.width()), centered_start(HEIGHT, pattern.height()));
+        life
+    }
+
+    /// Shifts the board so the living [Cells][Cell] are in its middle.
+    ///
+    /// The living [Cells][Cell] are the ones the [`aliveness`] of the board says are alive. [Cells][Cell] shifted beyond the edges are dropped and the uncovered [Cells][Cell] become the [dead Cell][crate::Aliveness::dead] of the [`aliveness`], regardless of the [`boundary`] mode. A board without living [Cells][Cell] is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<3, 3>::from([[true, true, false], [false; 3], [false; 3]]);
+    /// life.recenter();
+    ///
+    /// assert_eq!(life.to_plaintext(), "...\nOO.\n...\n");
+    ///
+    /// // on a white board, the uncovered cells stay white
+    /// let white = Cell::white();
+    /// let mut life = Life::<1, 3>::from([[Cell::black(), white, white]]);
+    /// life.aliveness = Aliveness::Background(white);
+    /// life.recenter();
+    ///
+    /// assert_eq!(life.cells, [[white, Cell::black(), white]]);
+    /// ```
+    ///
+    /// [`aliveness`]: struct.Life.html#structfield.aliveness
+    /// [`boundary`]: struct.Life.html#structfield.boundary
+    pub fn recenter(&mut self) {
+        let Some(bounds) = self.bounding_box() else {
+            return;
+        };
+
+        let dx = centered_start(WIDTH, bounds.width) - bounds.x as isize;
+        let dy = centered_start(HEIGHT, bounds.height) - bounds.y as isize;
+        let previous = *self;
+
+        self.cells = [[self.aliveness.dead(); WIDTH]; HEIGHT];
+        self.insert_clipped(&previous, dx, dy);
+    }
+}
+
+impl DynLife {
+    /// Creates a `width` × `height` DynLife with `pattern` in its middle, cutting off what doesn't fit.
+    ///
+    /// This works exactly like [`Life::centered`].
+    pub fn centered(width: usize, height: usize, pattern: &impl Grid) -> Self {
+        let mut life = Self::new(width, height);
+        life.insert_clipped(pattern, centered_start(width, pattern.width()), centered_start(height, pattern.height()));
+        life
+    }
+
+    /// Shifts the board so the living [Cells][Cell] are in its middle.
+    ///
+    /// This works exactly like [`Life::recenter`].
+    pub fn recenter(&mut self) {
+        let Some(bounds) = self.bounding_box() else {
+            return;
+        };
+
+        let dx = centered_start(self.width(), bounds.width) - bounds.x as isize;
+        let dy = centered_start(self.height(), bounds.height) - bounds.y as isize;
+        let previous = self.clone();
+
+        let dead = self.aliveness.dead();
+        self.cells_mut().fill(dead);
+        self.insert_clipped(&previous, dx, dy);
+    }
+
+    /// Copies all [Cells][Cell] of `pattern` onto the DynLife with its top left corner at `at`, cutting off what doesn't fit.
+    ///
+    /// This works exactly like [`Life::stamp`].
+    #[inline]
+    pub fn stamp(&mut self, pattern: &impl Grid, at: Point) {
+        self.stamp_with(pattern, at, &StampOptions::default());
+    }
+
+    /// Copies `pattern` onto the DynLife with its top left corner at `at`, as described by `options`.
+    ///
+    /// This works exactly like [`Life::stamp_with`].
+    pub fn stamp_with(&mut self, pattern: &impl Grid, at: Point, options: &StampOptions) {
+        let (width, height) = (self.width(), self.height());
+        stamp_onto_point(width, height, |point, cell| self.set(point.x, point.y, cell), pattern, at, options);
+    }
+
+    /// Copies all [Cells][Cell] of `pattern` onto the DynLife with its top left corner at `(x, y)`, which may be negative, cutting off what doesn't fit.
+    ///
+    /// This works exactly like [`Life::insert_clipped`].
+    #[inline]
+    pub fn insert_clipped(&mut self, pattern: &impl Grid, x: isize, y: isize) {
+        self.stamp_at(pattern, x, y, &StampOptions::default());
+    }
+
+    /// Copies `pattern` onto the DynLife with its top left corner at `(x, y)`, which may be negative, as described by `options`.
+    ///
+    /// This works exactly like [`Life::stamp_at`].
+    pub fn stamp_at(&mut self, pattern: &impl Grid, x: isize, y: isize, options: &StampOptions) {
+        let (width, height) = (self.width(), self.height());
+        stamp_onto(width, height, |point, cell| self.set(point.x, point.y, cell), pattern, x, y, options);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{Cell, CellLike, Grid, Life, Rect};
+
+/// A summary of a board in one generation, as returned by [`Life::stats`].
+///
+/// [Cells][Cell] are alive according to the [`aliveness`][Life#structfield.aliveness] of the board, and the colors of other [CellLike] types are the ones returned by [`CellLike::to_rgb`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// The number of living [Cells][Cell].
+    pub population: usize,
+    /// The number of [Cells][Cell] that are alive but were dead in the previous generation, or 0 if there is none.
+    pub births: usize,
+    /// The number of [Cells][Cell] that are dead but were alive in the previous generation, or 0 if there is none.
+    pub deaths: usize,
+    /// The smallest [Rect] containing all living [Cells][Cell], or `None` if there are none.
+    pub bounding_box: Option<Rect>,
+    /// The number of living [Cells][Cell] of each color.
+    pub colors: BTreeMap<Cell, usize>,
+}
+
+impl fmt::Display for Stats {
+    /// Writes a one-line summary of the Stats for logging, like `population 3, 2 births, 2 deaths, 1x3 at (1, 0), 1 color`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let before = Life::<3, 3>::from([[false, true, false]; 3]);
+    /// let mut after = before;
+    /// after.play_rule(&Rule::CONWAY);
+    ///
+    /// assert_eq!(after.stats_since(&before).to_string(), "population 3, 2 births, 2 deaths, 3x1 at (0, 1), 1 color");
+    /// assert_eq!(Life::<3, 3>::default().stats().to_string(), "population 0, 0 births, 0 deaths, no living cells, 0 colors");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+
+        write!(f, "population {}, {} birth{}, {} death{}, ", self.population, self.births, plural(self.births), self.deaths, plural(self.deaths))?;
+
+        match self.bounding_box {
+            Some(bounds) => write!(f, "{bounds}")?,
+            None => write!(f, "no living cells")?,
+        }
+
+        write!(f, ", {} color{}", self.colors.len(), plural(self.colors.len()))
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Life<HEIGHT, WIDTH, C> {
+    /// Returns the [Stats] of the Life, without births and deaths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<3, 3>::from([
+    ///     [Cell::dead(), Cell::red(), Cell::dead()],
+    ///     [Cell::dead(), Cell::red(), Cell::blue()],
+    ///     [Cell::dead(), Cell::dead(), Cell::dead()],
+    /// ]);
+    /// let stats = life.stats();
+    ///
+    /// assert_eq!(stats.population, 3);
+    /// assert_eq!(stats.bounding_box, Some(Rect::new(1, 0, 2, 2)));
+    /// assert_eq!(stats.colors[&Cell::red()], 2);
+    /// assert_eq!(stats.colors[&Cell::blue()], 1);
+    /// ```
+    pub fn stats(&self) -> Stats {
+        let mut stats = Stats {
+            bounding_box: self.bounding_box(),
+            ..Stats::default()
+        };
+
+        for &cell in self.iter() {
+            if !cell.is_alive_by(self.aliveness) {
+                continue;
+            }
+
+            stats.population += 1;
+            *stats.colors.entry(cell.to_rgb()).or_insert(0) += 1;
+        }
+
+        stats
+    }
+
+    /// Returns the [Stats] of the Life, with the births and deaths since `previous`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let before = Life::<3, 3>::from([[false, true, false]; 3]);
+    /// let mut after = before;
+    /// after.play_rule(&Rule::CONWAY);
+    ///
+    /// let stats = after.stats_since(&before);
+    ///
+    /// assert_eq!((stats.population, stats.births, stats.deaths), (3, 2, 2));
+    /// ```
+    pub fn stats_since(&self, previous: &Self) -> Stats {
+        let mut stats = self.stats();
+
+        for (&is, &was) in self.iter().zip(previous.iter()) {
+            match (was.is_alive_by(self.aliveness), is.is_alive_by(self.aliveness)) {
+                (false, true) => stats.births += 1,
+                (true, false) => stats.deaths += 1,
+                _ => {}
+            }
+        }
+
+        stats
+    }
+
+    /// Steps the Life `n` times like [`play_for`][Life::play_for] and returns the [Stats] of each new generation, with the births and deaths since the one before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<5, 5>::default();
+    /// for x in 1..4 {
+    ///     life.set(x, 2, Cell::alive());
+    /// }
+    ///
+    /// let populations: Vec<_> = life.play_for_with_stats(4, rules::life_like(Rule::CONWAY, rules::ColorPolicy::White)).into_iter().map(|stats| stats.population).collect();
+    ///
+    /// assert_eq!(populations, [3, 3, 3, 3]);
+    /// ```
+    pub fn play_for_with_stats(&mut self, n: u32, f: impl FnMut(C, [C; 8], usize, usize) -> C) -> Vec<Stats> {
+        let mut stats = Vec::with_capacity(n as usize);
+        self.play_for_inspecting(n, f, |_, generation| stats.push(generation.clone()));
+        stats
+    }
+
+    /// Steps the Life `n` times like [`play_for`][Life::play_for] and calls `inspect` with the number of each new generation, starting at 1, and its [Stats].
+    ///
+    /// Unlike [`play_for_with_stats`][Life::play_for_with_stats], the [Stats] aren't kept, so this can log every generation of a long run, for example over a debug probe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<5, 5>::default();
+    /// for x in 1..4 {
+    ///     life.set(x, 2, Cell::alive());
+    /// }
+    ///
+    /// let mut log = Vec::new();
+    /// life.play_for_inspecting(2, rules::life_like(Rule::CONWAY, rules::ColorPolicy::White), |generation, stats| log.push(format!("{generation}: {stats}")));
+    ///
+    /// assert_eq!(log, [
+    ///     "1: population 3, 2 births, 2 deaths, 1x3 at (2, 1), 1 color",
+    ///     "2: population 3, 2 births, 2 deaths, 3x1 at (1, 2), 1 color",
+    /// ]);
+    /// ```
+    pub fn play_for_inspecting(&mut self, n: u32, mut f: impl FnMut(C, [C; 8], usize, usize) -> C, mut inspect: impl FnMut(u32, &Stats)) {
+        for generation in 1..=n {
+            let previous = *self;
+            self.play(&mut f);
+            inspect(generation, &self.stats_since(&previous));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Aliveness, Cell, DynLife, Grid, Life, Point};
+
+/// Writes a [Grid] as text, one line per row, with a glyph for each living and each dead [Cell].
+///
+/// This is returned by [`Life::display`] and [`DynLife::display`]. By default, living [Cells][Cell] are written as `O` and dead ones as `.`, like in the plaintext format, and rows are separated by newlines without one after the last row.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let life = Life::<2, 3>::from([[false, true, false], [true, true, true]]);
+///
+/// assert_eq!(life.to_string(), ".O.\nOOO");
+/// assert_eq!(life.display().glyphs('#', ' ').to_string(), " # \n###");
+/// assert_eq!(Life::<1, 1>::from([[Cell::red()]]).display().colored().to_string(), "\x1b[38;2;255;0;0mO\x1b[0m");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextDisplay<'a, G> {
+    grid: &'a G,
+    aliveness: Aliveness,
+    alive: char,
+    dead: char,
+    colored: bool,
+}
+
+impl<'a, G: Grid> TextDisplay<'a, G> {
+    /// Creates a TextDisplay of `grid`, where [Cells][Cell] are alive according to `aliveness`.
+    #[inline]
+    pub fn new(grid: &'a G, aliveness: Aliveness) -> Self {
+        Self {
+            grid,
+            aliveness,
+            alive: 'O',
+            dead: '.',
+            colored: false,
+        }
+    }
+
+    /// Writes living [Cells][Cell] as `alive` and dead ones as `dead`.
+    #[inline]
+    pub fn glyphs(self, alive: char, dead: char) -> Self {
+        Self {
+            alive,
+            dead,
+            ..self
+        }
+    }
+
+    /// Writes living [Cells][Cell] in their colors, using the 24-bit color escape codes of ANSI terminals.
+    #[inline]
+    pub fn colored(self) -> Self {
+        Self {
+            colored: true,
+            ..self
+        }
+    }
+}
+
+impl<G: Grid> fmt::Display for TextDisplay<'_, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.grid.height() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+
+            let mut colored = false;
+
+            for x in 0..self.grid.width() {
+                let cell = self.grid.cell(Point::new(x, y));
+
+                match self.aliveness.is_alive(cell) {
+                    true if self.colored => {
+                        write!(f, "\x1b[38;2;{};{};{}m{}", cell.r, cell.g, cell.b, self.alive)?;
+                        colored = true;
+                    }
+                    true => write!(f, "{}", self.alive)?,
+                    false if colored => {
+                        write!(f, "\x1b[0m{}", self.dead)?;
+                        colored = false;
+                    }
+                    false => write!(f, "{}", self.dead)?,
+                }
+            }
+
+            if colored {
+                write!(f, "\x1b[0m")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The error returned when a board can't be parsed from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LifeParseError {
+    /// The text doesn't have the number of rows of the board.
+    WrongHeight {
+        /// The number of rows of the board.
+        expected: usize,
+        /// The number of rows in the text.
+        found: usize,
+    },
+    /// A row doesn't have the number of columns of the board.
+    WrongWidth {
+        /// The line of the row, starting at 1.
+        line: usize,
+        /// The number of columns of the board.
+        expected: usize,
+        /// The number of columns in the row.
+        found: usize,
+    },
+    /// A character is neither a living nor a dead [Cell].
+    InvalidCharacter {
+        /// The line of the character, starting at 1.
+        line: usize,
+        /// The character.
+        character: char,
+    },
+}
+
+impl fmt::Display for LifeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::WrongHeight { expected, found } => write!(f, "expected {expected} rows but found {found}"),
+            Self::WrongWidth { line, expected, found } => write!(f, "expected {expected} columns on line {line} but found {found}"),
+            Self::InvalidCharacter { line, character } => write!(f, "invalid character {character:?} on line {line}"),
+        }
+    }
+}
+
+impl std::error::Error for LifeParseError {}
+
+/// Parses the rows of a board, with `O` or `*` for living and `.` for dead [Cells][Cell], returning the width of the rows, or of the first row if `width` is `None`.
+fn parse(text: &str, width: Option<usize>, mut push: impl FnMut(Cell)) -> Result<(usize, usize), LifeParseError> {
+    let mut width = width;
+    let mut height = 0;
+
+    for (i, row) in text.lines().enumerate() {
+        let line = i + 1;
+        let mut found = 0;
+
+        for character in row.chars() {
+            match character {
+                'O' | '*' => push(Cell::alive()),
+                '.' => push(Cell::dead()),
+                character => return Err(LifeParseError::InvalidCharacter { line, character }),
+            }
+            found += 1;
+        }
+
+        match width {
+            Some(expected) if expected != found => return Err(LifeParseError::WrongWidth { line, expected, found }),
+            _ => width = Some(found),
+        }
+        height += 1;
+    }
+
+    Ok((width.unwrap_or(0), height))
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Returns a [TextDisplay] of the Life, to write it with other glyphs or in color.
+    ///
+    /// The [`aliveness`][Life#structfield.aliveness] of the Life decides which [Cells][Cell] are written as living.
+    #[inline]
+    pub fn display(&self) -> TextDisplay<'_, Self> {
+        TextDisplay::new(self, self.aliveness)
+    }
+}
+
+/// Writes the Life as text, like its [`display`][Life::display] with the default glyphs.
+impl<const HEIGHT: usize, const WIDTH: usize> fmt::Display for Life<HEIGHT, WIDTH> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display().fmt(f)
+    }
+}
+
+/// Parses a Life from text with one line per row, with `O` or `*` for [`Cell::alive`] and `.` for [`Cell::dead`].
+///
+/// The text must have exactly `HEIGHT` rows of `WIDTH` [Cells][Cell]. A newline after the last row is allowed.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let life: Life<3, 3> = ".O.\nOOO\n.O.".parse().unwrap();
+///
+/// assert_eq!(life, Life::from([[false, true, false], [true; 3], [false, true, false]]));
+/// assert_eq!(life.to_string().parse(), Ok(life));
+/// assert_eq!("OO\nOO".parse::<Life<3, 2>>(), Err(LifeParseError::WrongHeight { expected: 3, found: 2 }));
+/// ```
+impl<const HEIGHT: usize, const WIDTH: usize> FromStr for Life<HEIGHT, WIDTH> {
+    type Err = LifeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut life = Self::default();
+        let mut cells = life.cells.as_flattened_mut().iter_mut();
+
+        let (_, height) = parse(text, Some(WIDTH), |cell| if let Some(target) = cells.next() {
+            *target = cell;
+        })?;
+
+        match height == HEIGHT {
+            true => Ok(life),
+            false => Err(LifeParseError::WrongHeight { expected: HEIGHT, found: height }),
+        }
+    }
+}
+
+impl DynLife {
+    /// Returns a [TextDisplay] of the DynLife, to write it with other glyphs or in color.
+    ///
+    /// The [`aliveness`][DynLife#structfield.aliveness] of the DynLife decides which [Cells][Cell] are written as living.
+    #[inline]
+    pub fn display(&self) -> TextDisplay<'_, Self> {
+        TextDisplay::new(self, self.aliveness)
+    }
+}
+
+/// Writes the DynLife as text, like its [`display`][DynLife::display] with the default glyphs.
+impl fmt::Display for DynLife {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display().fmt(f)
+    }
+}
+
+/// Parses a DynLife from text like a [Life], with the size taken from the text. All rows must be as long as the first one.
+impl FromStr for DynLife {
+    type Err = LifeParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::new();
+        let (width, height) = parse(text, None, |cell| cells.push(cell))?;
+
+        // parse checked that every row has the same width
+        Ok(Self::from_cells(width, height, cells).expect("the rows have the same width"))
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{DynLife, Life, Rect};
+
+/// A part of a larger [Life], as returned by [`Life::split`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tile<const HEIGHT: usize, const WIDTH: usize> {
+    /// The x-position of the top left [Cell][crate::Cell] of the tile in the larger Life.
+    pub x: usize,
+    /// The y-position of the top left [Cell][crate::Cell] of the tile in the larger Life.
+    pub y: usize,
+    /// The [Cells][crate::Cell] of the tile.
+    pub life: Life<HEIGHT, WIDTH>,
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Tile<HEIGHT, WIDTH> {
+    /// Returns the area of the larger Life the tile stands for. Tiles on the right and bottom edges may reach past the edges of the Life.
+    #[inline]
+    pub const fn rect(&self) -> Rect {
+        Rect::new(self.x, self.y, WIDTH, HEIGHT)
+    }
+}
+
+impl<const HEIGHT: usize, const WIDTH: usize> Life<HEIGHT, WIDTH> {
+    /// Returns a Life with `other` placed to the right of this Life.
+    ///
+    /// The width of the returned Life must be the sum of both widths, which is checked at compile time. The [`boundary`] mode is taken from this Life.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let left = Life::<2, 1>::from([[true], [false]]);
+    /// let right = Life::<2, 2>::from([[false, true], [true, false]]);
+    ///
+    /// let both: Life<2, 3> = left.hconcat(&right);
+    ///
+    /// assert_eq!(both, Life::from([[true, false, true], [false, true, false]]));
+    /// ```
+    ///
+    /// A mismatching width doesn't compile:
+    ///
+    /// ```compile_fail
+    /// # use your_game_of_life::*;
+    /// let both: Life<2, 4> = Life::<2, 1>::default().hconcat(&Life::<2, 2>::default());
+    /// ```
+    ///
+    /// [`boundary`]: struct.Life.html#structfield.boundary
+    pub fn hconcat<const OTHER_WIDTH: usize, const NEW_WIDTH: usize>(&self, other: &Life<HEIGHT, OTHER_WIDTH>) -> Life<HEIGHT, NEW_WIDTH> {
+        const { assert!(NEW_WIDTH == WIDTH + OTHER_WIDTH, "the new width must be the sum of both widths") };
+
+        let mut life = Life {
+            boundary: self.boundary,
+            aliveness: self.aliveness,
+            ..Life::default()
+        };
+
+        for ((row, left), right) in life.cells.iter_mut().zip(&self.cells).zip(&other.cells) {
+            row[..WIDTH].copy_from_slice(left);
+            row[WIDTH..].copy_from_slice(right);
+        }
+
+        life
+    }
+
+    /// Returns a Life with `other` placed below this Life.
+    ///
+    /// The height of the returned Life must be the sum of both heights, which is checked at compile time. The [`boundary`] mode is taken from this Life.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let top = Life::<1, 2>::from([[true, false]]);
+    /// let bottom = Life::<1, 2>::from([[false, true]]);
+    ///
+    /// let both: Life<2, 2> = top.vconcat(&bottom);
+    ///
+    /// assert_eq!(both, Life::from([[true, false], [false, true]]));
+    /// ```
+    ///
+    /// [`boundary`]: struct.Life.html#structfield.boundary
+    pub fn vconcat<const OTHER_HEIGHT: usize, const NEW_HEIGHT: usize>(&self, other: &Life<OTHER_HEIGHT, WIDTH>) -> Life<NEW_HEIGHT, WIDTH> {
+        const { assert!(NEW_HEIGHT == HEIGHT + OTHER_HEIGHT, "the new height must be the sum of both heights") };
+
+        let mut life = Life {
+            boundary: self.boundary,
+            aliveness: self.aliveness,
+            ..Life::default()
+        };
+
+        life.cells[..HEIGHT].copy_from_slice(&self.cells);
+        life.cells[HEIGHT..].copy_from_slice(&other.cells);
+
+        life
+    }
+
+    /// Splits this Life into tiles of `TILE_HEIGHT` × `TILE_WIDTH` [Cells][crate::Cell], row by row.
+    ///
+    /// Tiles on the right and bottom edges that reach past this Life are filled up with the [`BoundaryMode::outside`][crate::BoundaryMode::outside] [Cell][crate::Cell], and every tile takes over the [`boundary`] mode of this Life. Both tile dimensions must be non-zero, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<3, 4>::from([
+    ///     [true, false, false, true],
+    ///     [false, true, true, false],
+    ///     [true, true, true, true],
+    /// ]);
+    ///
+    /// let tiles = life.split::<2, 2>();
+    ///
+    /// assert_eq!(tiles.len(), 4);
+    /// assert_eq!((tiles[1].x, tiles[1].y), (2, 0));
+    /// assert_eq!(tiles[1].life, Life::from([[false, true], [true, false]]));
+    /// assert_eq!(tiles[3].life, Life::from([[true, true], [false, false]]));
+    /// ```
+    ///
+    /// [`boundary`]: struct.Life.html#structfield.boundary
+    pub fn split<const TILE_HEIGHT: usize, const TILE_WIDTH: usize>(&self) -> Vec<Tile<TILE_HEIGHT, TILE_WIDTH>> {
+        const { assert!(TILE_HEIGHT != 0 && TILE_WIDTH != 0, "tiles must not be empty") };
+
+        let mut tiles = Vec::with_capacity(HEIGHT.div_ceil(TILE_HEIGHT) * WIDTH.div_ceil(TILE_WIDTH));
+
+        for y in (0..HEIGHT).step_by(TILE_HEIGHT) {
+            for x in (0..WIDTH).step_by(TILE_WIDTH) {
+                let mut life = Life {
+                    cells: [[self.boundary.outside(); TILE_WIDTH]; TILE_HEIGHT],
+                    boundary: self.boundary,
+                    aliveness: self.aliveness,
+                };
+
+                for (row, source) in life.cells.iter_mut().zip(&self.cells[y..]) {
+                    let source = &source[x..(x + TILE_WIDTH).min(WIDTH)];
+                    row[..source.len()].copy_from_slice(source);
+                }
+
+                tiles.push(Tile { x, y, life });
+            }
+        }
+
+        tiles
+    }
+
+    /// Copies the [Cells][crate::Cell] of `tile` back to its position in this Life.
+    ///
+    /// [Cells][crate::Cell] of the tile that lie outside of this Life are ignored, so splitting a Life and setting all of its tiles gives back the same Life.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<3, 3>::from([[true, false, true], [false, true, false], [true, false, true]]);
+    /// let mut copy = Life::<3, 3>::default();
+    ///
+    /// for tile in life.split::<2, 2>() {
+    ///     copy.set_tile(&tile);
+    /// }
+    ///
+    /// assert_eq!(copy, life);
+    /// ```
+    pub fn set_tile<const TILE_HEIGHT: usize, const TILE_WIDTH: usize>(&mut self, tile: &Tile<TILE_HEIGHT, TILE_WIDTH>) {
+        if tile.x >= WIDTH {
+            return;
+        }
+
+        for (row, source) in self.cells.iter_mut().skip(tile.y).zip(&tile.life.cells) {
+            let len = TILE_WIDTH.min(WIDTH - tile.x);
+            row[tile.x..tile.x + len].copy_from_slice(&source[..len]);
+        }
+    }
+}
+
+impl DynLife {
+    /// Returns a DynLife with `other` placed to the right of this DynLife, like [`Life::hconcat`].
+    ///
+    /// The [`boundary`][DynLife#structfield.boundary] mode is taken from this DynLife.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the heights of the boards differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let left = DynLife::from([[true], [false]]);
+    /// let right = DynLife::from([[false, true], [true, false]]);
+    ///
+    /// assert_eq!(left.hconcat(&right), DynLife::from([[true, false, true], [false, true, false]]));
+    ///
+    /// // a board without columns adds nothing
+    /// assert_eq!(DynLife::new(0, 2).hconcat(&left), left);
+    /// ```
+    #[track_caller]
+    pub fn hconcat(&self, other: &Self) -> Self {
+        assert_eq!(self.height(), other.height(), "the heights of both boards must be equal");
+
+        let cells = self.rows().zip(other.rows()).flat_map(|(left, right)| left.iter().chain(right)).copied().collect();
+        let mut life = Self::from_cells(self.width() + other.width(), self.height(), cells).expect("the number of cells overflows a usize");
+        life.boundary = self.boundary;
+        life.aliveness = self.aliveness;
+
+        life
+    }
+
+    /// Returns a DynLife with `other` placed below this DynLife, like [`Life::vconcat`].
+    ///
+    /// The [`boundary`][DynLife#structfield.boundary] mode is taken from this DynLife.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widths of the boards differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let top = DynLife::from([[true, false]]);
+    /// let bottom = DynLife::from([[false, true], [true, true]]);
+    ///
+    /// assert_eq!(top.vconcat(&bottom), DynLife::from([[true, false], [false, true], [true, true]]));
+    ///
+    /// // boards without columns still stack their rows
+    /// let empty = DynLife::new(0, 2).vconcat(&DynLife::new(0, 3));
+    /// assert_eq!((empty.width(), empty.height()), (0, 5));
+    /// ```
+    #[track_caller]
+    pub fn vconcat(&self, other: &Self) -> Self {
+        assert_eq!(self.width(), other.width(), "the widths of both boards must be equal");
+
+        let cells = self.cells().iter().chain(other.cells()).copied().collect();
+        let mut life = Self::from_cells(self.width(), self.height() + other.height(), cells).expect("the number of cells overflows a usize");
+        life.boundary = self.boundary;
+        life.aliveness = self.aliveness;
+
+        life
+    }
+}
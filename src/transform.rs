@@ -0,0 +1,297 @@
+use crate::{Cell, CellLike, DynLife, Edges, Life, Point, Transform};
+
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Life<HEIGHT, WIDTH, C> {
+    /// Returns an empty Life of any size with the [`boundary`][Life#structfield.boundary] mode and [`aliveness`][Life#structfield.aliveness] of this one.
+    fn with_settings<const NEW_HEIGHT: usize, const NEW_WIDTH: usize>(&self) -> Life<NEW_HEIGHT, NEW_WIDTH, C> {
+        Life {
+            boundary: self.boundary,
+            aliveness: self.aliveness,
+            ..Life::default()
+        }
+    }
+
+    /// Moves every cell to where `transform` puts it, into a Life of the transformed size.
+    fn transformed<const NEW_HEIGHT: usize, const NEW_WIDTH: usize>(&self, transform: Transform) -> Life<NEW_HEIGHT, NEW_WIDTH, C> {
+        let mut life = self.with_settings();
+
+        for (x, y, &cell) in self.enumerate_cells() {
+            let Point { x, y } = transform.apply(Point::new(x, y), WIDTH, HEIGHT);
+            life.cells[y][x] = cell;
+        }
+
+        life
+    }
+
+    /// Returns the Life rotated by 90° clockwise, which swaps its width and height.
+    ///
+    /// The [`boundary`][Life#structfield.boundary] mode and [`aliveness`][Life#structfield.aliveness] are kept, like for all transforms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<2, 3>::from([[true, true, true], [false, false, true]]);
+    ///
+    /// assert_eq!(life.rotate90(), Life::<3, 2>::from([[false, true], [false, true], [true, true]]));
+    /// assert_eq!(life.rotate90().rotate270(), life);
+    /// ```
+    #[inline]
+    pub fn rotate90(&self) -> Life<WIDTH, HEIGHT, C> {
+        self.transformed(Transform::Rotate90)
+    }
+
+    /// Returns the Life rotated by 180°.
+    #[inline]
+    pub fn rotate180(&self) -> Self {
+        self.transformed(Transform::Rotate180)
+    }
+
+    /// Returns the Life rotated by 270° clockwise, or 90° counterclockwise, which swaps its width and height.
+    #[inline]
+    pub fn rotate270(&self) -> Life<WIDTH, HEIGHT, C> {
+        self.transformed(Transform::Rotate270)
+    }
+
+    /// Returns the Life mirrored left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<2, 2>::from([[true, false], [true, true]]);
+    ///
+    /// assert_eq!(life.flip_horizontal(), Life::from([[false, true], [true, true]]));
+    /// assert_eq!(life.flip_vertical(), Life::from([[true, true], [true, false]]));
+    /// ```
+    #[inline]
+    pub fn flip_horizontal(&self) -> Self {
+        self.transformed(Transform::FlipHorizontal)
+    }
+
+    /// Returns the Life mirrored top to bottom.
+    #[inline]
+    pub fn flip_vertical(&self) -> Self {
+        self.transformed(Transform::FlipVertical)
+    }
+
+    /// Returns the Life with every cell moved `dx` columns to the right and `dy` rows down.
+    ///
+    /// With [`Edges::Wrap`], cells moved beyond an edge come back in on the opposite one. With [`Edges::Clip`], they are dropped, and the cells that were vacated are filled with the [`BoundaryMode::outside`][crate::BoundaryMode::outside] cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<1, 4>::from([[true, true, false, false]]);
+    ///
+    /// assert_eq!(life.shift(3, 0, Edges::Wrap), Life::from([[true, false, false, true]]));
+    /// assert_eq!(life.shift(-1, 0, Edges::Clip), Life::from([[true, false, false, false]]));
+    /// ```
+    pub fn shift(&self, dx: isize, dy: isize, edges: Edges) -> Self {
+        let mut life = self.with_settings();
+        let outside = C::from(self.boundary.outside());
+
+        for (x, y, cell) in life.enumerate_cells_mut() {
+            // a wide integer can't overflow for any shift
+            let (from_x, from_y) = (x as i128 - dx as i128, y as i128 - dy as i128);
+
+            *cell = match edges {
+                Edges::Wrap => self.cells[from_y.rem_euclid(HEIGHT as i128) as usize][from_x.rem_euclid(WIDTH as i128) as usize],
+                Edges::Clip => match (usize::try_from(from_x), usize::try_from(from_y)) {
+                    (Ok(from_x), Ok(from_y)) if from_x < WIDTH && from_y < HEIGHT => self.cells[from_y][from_x],
+                    _ => outside,
+                },
+            };
+        }
+
+        life
+    }
+
+    /// Returns the `NEW_HEIGHT` × `NEW_WIDTH` part of the Life with its top left corner at `x` and `y`.
+    ///
+    /// Parts reaching beyond the Life are filled with the [`BoundaryMode::outside`][crate::BoundaryMode::outside] cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<3, 3>::from([[false, false, false], [false, true, true], [false, true, false]]);
+    ///
+    /// assert_eq!(life.crop::<2, 2>(1, 1), Life::from([[true, true], [true, false]]));
+    /// assert_eq!(life.crop::<2, 2>(2, 2), Life::from([[false, false], [false, false]]));
+    /// ```
+    pub fn crop<const NEW_HEIGHT: usize, const NEW_WIDTH: usize>(&self, x: usize, y: usize) -> Life<NEW_HEIGHT, NEW_WIDTH, C> {
+        let mut life = self.with_settings();
+        let outside = C::from(self.boundary.outside());
+
+        for (dx, dy, cell) in life.enumerate_cells_mut() {
+            *cell = match (x.checked_add(dx), y.checked_add(dy)) {
+                (Some(x), Some(y)) if x < WIDTH && y < HEIGHT => self.cells[y][x],
+                _ => outside,
+            };
+        }
+
+        life
+    }
+
+    /// Returns a `NEW_HEIGHT` × `NEW_WIDTH` Life with this one placed with its top left corner at `x` and `y`, for example to grow a board during a run.
+    ///
+    /// The other cells are the default cell, and parts reaching beyond the new Life are cut off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<1, 2>::from([[true, true]]);
+    ///
+    /// assert_eq!(life.embed_into::<2, 3>(1, 1), Life::from([[false, false, false], [false, true, true]]));
+    /// assert_eq!(life.embed_into::<2, 3>(2, 0), Life::from([[false, false, true], [false, false, false]]));
+    /// ```
+    pub fn embed_into<const NEW_HEIGHT: usize, const NEW_WIDTH: usize>(&self, x: usize, y: usize) -> Life<NEW_HEIGHT, NEW_WIDTH, C> {
+        let mut life = self.with_settings();
+
+        for (dx, dy, &cell) in self.enumerate_cells() {
+            if let (Some(x), Some(y)) = (x.checked_add(dx), y.checked_add(dy)) {
+                if x < NEW_WIDTH && y < NEW_HEIGHT {
+                    life.cells[y][x] = cell;
+                }
+            }
+        }
+
+        life
+    }
+}
+
+/// The transforms of a [Life] whose sizes are only known at runtime, with the same names.
+///
+/// A [Life] has to spell out the size of a cropped or embedded board as const generics. These take the new width and height as arguments instead, so boards can be reshaped by sizes computed while running.
+impl DynLife {
+    /// Returns an empty DynLife of `width` × `height` [Cells][Cell] with the [`boundary`][DynLife#structfield.boundary] mode and [`aliveness`][DynLife#structfield.aliveness] of this one.
+    #[track_caller]
+    fn with_settings(&self, width: usize, height: usize, fill: Cell) -> Self {
+        let mut life = Self::filled(width, height, fill);
+        life.boundary = self.boundary;
+        life.aliveness = self.aliveness;
+
+        life
+    }
+
+    /// Moves every [Cell] to where `transform` puts it, into a DynLife of the transformed size.
+    fn transformed(&self, transform: Transform) -> Self {
+        let (width, height) = transform.size(self.width(), self.height());
+        let mut life = self.with_settings(width, height, Cell::default());
+
+        for (i, &cell) in self.cells().iter().enumerate() {
+            let Point { x, y } = transform.apply(Point::new(i % self.width(), i / self.width()), self.width(), self.height());
+            life.set(x, y, cell);
+        }
+
+        life
+    }
+
+    /// Returns the DynLife rotated by 90° clockwise, which swaps its width and height, like [`Life::rotate90`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = DynLife::from([[true, true, true], [false, false, true]]);
+    ///
+    /// assert_eq!(life.rotate90(), DynLife::from([[false, true], [false, true], [true, true]]));
+    /// assert_eq!(life.rotate90().rotate270(), life);
+    /// ```
+    #[inline]
+    pub fn rotate90(&self) -> Self {
+        self.transformed(Transform::Rotate90)
+    }
+
+    /// Returns the DynLife rotated by 180°, like [`Life::rotate180`].
+    #[inline]
+    pub fn rotate180(&self) -> Self {
+        self.transformed(Transform::Rotate180)
+    }
+
+    /// Returns the DynLife rotated by 270° clockwise, which swaps its width and height, like [`Life::rotate270`].
+    #[inline]
+    pub fn rotate270(&self) -> Self {
+        self.transformed(Transform::Rotate270)
+    }
+
+    /// Returns the DynLife mirrored left to right, like [`Life::flip_horizontal`].
+    #[inline]
+    pub fn flip_horizontal(&self) -> Self {
+        self.transformed(Transform::FlipHorizontal)
+    }
+
+    /// Returns the DynLife mirrored top to bottom, like [`Life::flip_vertical`].
+    #[inline]
+    pub fn flip_vertical(&self) -> Self {
+        self.transformed(Transform::FlipVertical)
+    }
+
+    /// Returns the `width` × `height` part of the DynLife with its top left corner at `x` and `y`, like [`Life::crop`].
+    ///
+    /// Parts reaching beyond the DynLife are filled with the [`BoundaryMode::outside`][crate::BoundaryMode::outside] [Cell].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of [Cells][Cell] overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = DynLife::from([[false, false, false], [false, true, true], [false, true, false]]);
+    ///
+    /// assert_eq!(life.crop(1, 1, 2, 2), DynLife::from([[true, true], [true, false]]));
+    /// assert_eq!(life.crop(2, 2, 2, 1), DynLife::from([[false, false]]));
+    /// ```
+    #[track_caller]
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        let mut life = self.with_settings(width, height, self.boundary.outside());
+
+        for (i, cell) in life.cells_mut().iter_mut().enumerate() {
+            if let (Some(x), Some(y)) = (x.checked_add(i % width), y.checked_add(i / width)) {
+                if let Some(source) = self.try_get(x, y) {
+                    *cell = source;
+                }
+            }
+        }
+
+        life
+    }
+
+    /// Returns a `width` × `height` DynLife with this one placed with its top left corner at `x` and `y`, like [`Life::embed_into`].
+    ///
+    /// The arguments are in the same order as for [`crop`][DynLife::crop]: the position first, then the size.
+    ///
+    /// The other [Cells][Cell] are [`Cell::default`], and parts reaching beyond the new DynLife are cut off. Embedding at `(0, 0)` resizes the board while keeping its [Cells][Cell] in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of [Cells][Cell] overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = DynLife::from([[true, true]]);
+    ///
+    /// assert_eq!(life.embed_into(1, 1, 3, 2), DynLife::from([[false, false, false], [false, true, true]]));
+    /// assert_eq!(life.embed_into(0, 0, 1, 1), DynLife::from([[true]]));
+    /// ```
+    #[track_caller]
+    pub fn embed_into(&self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        let mut life = self.with_settings(width, height, Cell::default());
+
+        for (i, &cell) in self.cells().iter().enumerate() {
+            if let (Some(x), Some(y)) = (x.checked_add(i % self.width()), y.checked_add(i / self.width())) {
+                if let Some(target) = life.try_get_mut(x, y) {
+                    *target = cell;
+                }
+            }
+        }
+
+        life
+    }
+}
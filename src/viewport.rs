@@ -0,0 +1,185 @@
+#[cfg(any(feature = "minifb", feature = "pixels", feature = "sdl2"))]
+use crate::embedded::{check_len, frame_len, BufferTooSmall};
+#[cfg(any(feature = "minifb", feature = "pixels", feature = "sdl2"))]
+use crate::{Cell, CellLike, Life};
+
+/// The part of a board shown in a frame of pixels, for frontends that pan across a board or zoom into it.
+///
+/// The [Cell][crate::Cell] at column `x` and row `y` of the board is drawn in the top left corner of a frame of `width` × `height` pixels. The position may lie beyond the edges of the board, where the [`boundary`][crate::Life#structfield.boundary] mode decides what is shown, so a wrapping board can be scrolled endlessly.
+///
+/// # Examples
+///
+/// ```
+/// # use your_game_of_life::*;
+/// let viewport = Viewport::new(0, 0, 640, 480).pan(-2, 3);
+///
+/// assert_eq!(viewport, Viewport::new(-2, 3, 640, 480));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Viewport {
+    /// The column of the board shown at the left edge of the frame.
+    pub x: isize,
+    /// The row of the board shown at the top edge of the frame.
+    pub y: isize,
+    /// The width of the frame in pixels.
+    pub width: usize,
+    /// The height of the frame in pixels.
+    pub height: usize,
+}
+
+impl Viewport {
+    /// Creates a Viewport showing the board from column `x` and row `y` in a frame of `width` × `height` pixels.
+    #[inline]
+    pub const fn new(x: isize, y: isize, width: usize, height: usize) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Returns the Viewport moved by `dx` columns and `dy` rows of the board.
+    #[inline]
+    pub const fn pan(self, dx: isize, dy: isize) -> Self {
+        Self::new(self.x.saturating_add(dx), self.y.saturating_add(dy), self.width, self.height)
+    }
+
+    /// Returns the number of pixels of the frame, or [`usize::MAX`] if it overflows a [usize], which no buffer can hold.
+    #[inline]
+    pub const fn pixels(self) -> usize {
+        self.width.saturating_mul(self.height)
+    }
+}
+
+#[cfg(any(feature = "minifb", feature = "pixels", feature = "sdl2"))]
+impl<const HEIGHT: usize, const WIDTH: usize, C: CellLike> Life<HEIGHT, WIDTH, C> {
+    /// Invokes `pixel` on every pixel of the frame shown by `viewport` with every [Cell] drawn as a block of `scale` × `scale` pixels, row by row.
+    #[track_caller]
+    fn draw_pixels(&self, viewport: Viewport, scale: usize, mut pixel: impl FnMut(Cell)) {
+        assert!(scale != 0, "the scale must not be 0");
+
+        let outside = self.boundary.outside();
+        let resolve = |start: isize, pixel: usize, len| self.boundary.resolve(start.saturating_add((pixel / scale) as isize), len);
+        let columns: Vec<_> = (0..viewport.width).map(|x| resolve(viewport.x, x, WIDTH)).collect();
+
+        for y in 0..viewport.height {
+            let row = resolve(viewport.y, y, HEIGHT).map(|y| &self.cells[y]);
+
+            for &x in &columns {
+                pixel(match (row, x) {
+                    (Some(row), Some(x)) => row[x].to_rgb(),
+                    _ => outside,
+                });
+            }
+        }
+    }
+
+    /// Draws the part of the board shown by `viewport` into `frame`, a frame buffer of the `pixels` crate, with every [Cell] drawn as a block of `scale` × `scale` pixels.
+    ///
+    /// The frame holds the pixels row by row as bytes in RGBA order, like the one returned by `Pixels::frame_mut`, and must be at least [`viewport.pixels()`][Viewport::pixels] × 4 bytes long. Every alpha is 255, and the [Cells][Cell] of other [CellLike] types are drawn as returned by [`CellLike::to_rgb`]. Returns the number of bytes written, or nothing is written if the frame is too small.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let mut life = Life::<2, 2>::from([[Cell::red(), Cell::blue()], [Cell::green(), Cell::white()]]);
+    /// life.boundary = BoundaryMode::Wrap;
+    ///
+    /// // a 3 × 2 pixel window starting one column left of the board, at 2 pixels per cell
+    /// let mut frame = [0; 3 * 2 * 4];
+    /// life.draw_into(&mut frame, Viewport::new(-1, 0, 3, 2), 2).unwrap();
+    ///
+    /// assert_eq!(frame[..12], [0, 0, 255, 255, 0, 0, 255, 255, 255, 0, 0, 255]);
+    /// assert_eq!(frame[..12], frame[12..]);
+    /// ```
+    #[cfg(feature = "pixels")]
+    #[track_caller]
+    pub fn draw_into(&self, frame: &mut [u8], viewport: Viewport, scale: usize) -> Result<usize, BufferTooSmall> {
+        let len = frame_len(&[viewport.width, viewport.height, 4]);
+        check_len(len, frame.len())?;
+
+        let mut pixels = frame[..len].chunks_exact_mut(4);
+        self.draw_pixels(viewport, scale, |cell| {
+            if let Some(pixel) = pixels.next() {
+                pixel.copy_from_slice(&[cell.r, cell.g, cell.b, 255]);
+            }
+        });
+
+        Ok(len)
+    }
+
+    /// Draws the part of the board shown by `viewport` into `buffer`, a window buffer of the `minifb` crate, like [`draw_into`][Life::draw_into].
+    ///
+    /// The buffer holds one [u32] per pixel in `0RGB` order, row by row, as taken by `Window::update_with_buffer`, and must be at least [`viewport.pixels()`][Viewport::pixels] long. Returns the number of pixels written, or nothing is written if the buffer is too small.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<1, 2>::from([[Cell::red(), Cell::blue()]]);
+    /// let mut buffer = [0; 2];
+    ///
+    /// assert_eq!(life.draw_into_minifb(&mut buffer, Viewport::new(0, 0, 2, 1), 1), Ok(2));
+    /// assert_eq!(buffer, [0xFF0000, 0x0000FF]);
+    /// ```
+    #[cfg(feature = "minifb")]
+    #[track_caller]
+    pub fn draw_into_minifb(&self, buffer: &mut [u32], viewport: Viewport, scale: usize) -> Result<usize, BufferTooSmall> {
+        let len = viewport.pixels();
+        check_len(len, buffer.len())?;
+
+        let mut pixels = buffer[..len].iter_mut();
+        self.draw_pixels(viewport, scale, |cell| {
+            if let Some(pixel) = pixels.next() {
+                *pixel = (cell.r as u32) << 16 | (cell.g as u32) << 8 | cell.b as u32;
+            }
+        });
+
+        Ok(len)
+    }
+
+    /// Draws the part of the board shown by `viewport` into `buffer`, the locked pixels of an SDL2 texture, like [`draw_into`][Life::draw_into].
+    ///
+    /// The texture must have the `RGB24` pixel format, and `buffer` and `pitch` are the ones passed to the closure of `Texture::with_lock`: every row starts `pitch` bytes after the one before and holds three bytes per pixel in RGB order. Bytes between the rows are left unchanged. Returns the number of bytes from the start of the first row to the end of the last one, or nothing is written if the buffer is too small.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is 0 or if `pitch` is too small to hold a row of the frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use your_game_of_life::*;
+    /// let life = Life::<1, 1>::from([[Cell::red()]]);
+    ///
+    /// // rows padded to 4 bytes
+    /// let mut buffer = [9; 8];
+    /// assert_eq!(life.draw_into_sdl2(&mut buffer, 4, Viewport::new(0, 0, 1, 2), 2), Ok(7));
+    /// assert_eq!(buffer, [255, 0, 0, 9, 255, 0, 0, 9]);
+    /// ```
+    #[cfg(feature = "sdl2")]
+    #[track_caller]
+    pub fn draw_into_sdl2(&self, buffer: &mut [u8], pitch: usize, viewport: Viewport, scale: usize) -> Result<usize, BufferTooSmall> {
+        let row = frame_len(&[viewport.width, 3]);
+        assert!(pitch >= row, "the pitch of {pitch} bytes can't hold a row of {row} bytes");
+
+        let len = match viewport.height {
+            0 => 0,
+            height => pitch.checked_mul(height - 1).and_then(|len| len.checked_add(row)).unwrap_or(usize::MAX),
+        };
+        check_len(len, buffer.len())?;
+
+        let mut pixels = buffer.chunks_mut(pitch.max(1)).flat_map(|line| line[..row].chunks_exact_mut(3));
+        self.draw_pixels(viewport, scale, |cell| {
+            if let Some(pixel) = pixels.next() {
+                pixel.copy_from_slice(&[cell.r, cell.g, cell.b]);
+            }
+        });
+
+        Ok(len)
+    }
+}